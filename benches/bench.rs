@@ -1,48 +1,71 @@
-#![feature(test)]
-
-extern crate bip39;
-extern crate test;
-
-use test::Bencher;
+use criterion::{criterion_group, criterion_main, Criterion};
 
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 
-#[bench]
-fn validate(b: &mut Bencher) {
+fn validate(c: &mut Criterion) {
     let phrase =
         "silly laptop awake length nature thunder category claim reveal supply attitude drip";
 
-    b.iter(|| {
-        let _ = Mnemonic::validate(phrase, Language::English);
+    c.bench_function("validate", |b| {
+        b.iter(|| {
+            let _ = Mnemonic::validate(phrase, Language::English);
+        })
     });
 }
 
-#[bench]
-fn from_entropy(b: &mut Bencher) {
+fn from_entropy(c: &mut Criterion) {
     let phrase =
         "silly laptop awake length nature thunder category claim reveal supply attitude drip";
     let m = Mnemonic::from_phrase(phrase, Language::English).unwrap();
     let entropy = m.entropy();
 
-    b.iter(|| {
-        let _ = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+    c.bench_function("from_entropy", |b| {
+        b.iter(|| {
+            let _ = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+        })
+    });
+}
+
+fn new_mnemonic(c: &mut Criterion) {
+    c.bench_function("new_mnemonic", |b| {
+        b.iter(|| {
+            let _ = Mnemonic::new(MnemonicType::Words12, Language::English);
+        })
     });
 }
 
-#[bench]
-fn new_mnemonic(b: &mut Bencher) {
-    b.iter(|| {
-        let _ = Mnemonic::new(MnemonicType::Words12, Language::English);
+fn new_seed(c: &mut Criterion) {
+    let phrase =
+        "silly laptop awake length nature thunder category claim reveal supply attitude drip";
+    let m = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+    c.bench_function("new_seed", |b| {
+        b.iter(|| {
+            let _ = Seed::new(&m, "");
+        })
     });
 }
 
-#[bench]
-fn new_seed(b: &mut Bencher) {
+fn new_seed_batch(c: &mut Criterion) {
     let phrase =
         "silly laptop awake length nature thunder category claim reveal supply attitude drip";
     let m = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    let passwords: Vec<String> = (0..64).map(|i| i.to_string()).collect();
+    let pairs: Vec<_> = passwords.iter().map(|p| (&m, p.as_str())).collect();
 
-    b.iter(|| {
-        let _ = Seed::new(&m, "");
+    c.bench_function("new_seed_batch", |b| {
+        b.iter(|| {
+            let _ = Seed::new_batch(&pairs);
+        })
     });
 }
+
+criterion_group!(
+    benches,
+    validate,
+    from_entropy,
+    new_mnemonic,
+    new_seed,
+    new_seed_batch
+);
+criterion_main!(benches);
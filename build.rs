@@ -0,0 +1,116 @@
+//! Generates the built-in word lists as arrays, along with their words in sorted order, and the
+//! word maps from their words to their indices, so looking words up needs no allocation, or any
+//! setup at runtime
+//!
+//! The word maps are perfect hash maps, or with the `binary-search` feature, the indices of the
+//! words in sorted order, which are a tenth of the size. With the `constant-time` feature, they
+//! are the words themselves, all compared to the word looked up.
+//!
+//! With the `compressed-wordlists` feature, the word lists other than English are only embedded
+//! deflated, and their word lists and word maps are built on first use instead.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// The word list files in `src/langs`, the names of their constants and the features enabling them
+const LANGUAGES: &[(&str, &str, Option<&str>)] = &[
+    ("english.txt", "ENGLISH", None),
+    (
+        "chinese_simplified.txt",
+        "CHINESE_SIMPLIFIED",
+        Some("chinese-simplified"),
+    ),
+    (
+        "chinese_traditional.txt",
+        "CHINESE_TRADITIONAL",
+        Some("chinese-traditional"),
+    ),
+    ("czech.txt", "CZECH", Some("czech")),
+    ("french.txt", "FRENCH", Some("french")),
+    ("italian.txt", "ITALIAN", Some("italian")),
+    ("japanese.txt", "JAPANESE", Some("japanese")),
+    ("korean.txt", "KOREAN", Some("korean")),
+    ("portuguese.txt", "PORTUGUESE", Some("portuguese")),
+    ("spanish.txt", "SPANISH", Some("spanish")),
+];
+
+fn main() {
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("wordlists.rs");
+    let mut out = fs::File::create(out_path).unwrap();
+    let binary_search = env::var_os("CARGO_FEATURE_BINARY_SEARCH").is_some();
+    let constant_time = env::var_os("CARGO_FEATURE_CONSTANT_TIME").is_some();
+    let compressed = env::var_os("CARGO_FEATURE_COMPRESSED_WORDLISTS").is_some();
+
+    for &(file, name, feature) in LANGUAGES {
+        let path = Path::new("src/langs").join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let words = fs::read_to_string(&path).unwrap();
+        let words: Vec<&str> = words.split_whitespace().collect();
+        assert_eq!(words.len(), 2048, "{} doesn't have 2048 words", file);
+
+        let cfg = match feature {
+            Some(feature) => format!("#[cfg(feature = \"{}\")]\n", feature),
+            None => String::new(),
+        };
+
+        // English is always embedded as is, it's the one nearly every phrase is in
+        if compressed && feature.is_some() {
+            let deflated = miniz_oxide::deflate::compress_to_vec(words.join("\n").as_bytes(), 10);
+
+            writeln!(
+                out,
+                "{cfg}static DEFLATED_{name}: &[u8] = &{:?};\n\
+                 {cfg}static TEXT_{name}: Lazy<String> = Lazy::new(|| inflate(DEFLATED_{name}));\n\
+                 {cfg}static WORDS_{name}: Lazy<CustomWordlist> = \
+                 Lazy::new(|| from_text(&TEXT_{name}));\n\
+                 {cfg}pub static WORDLIST_{name}: LazyWordList = LazyWordList(&WORDS_{name});\n\
+                 {cfg}pub static WORDMAP_{name}: LazyWordMap = LazyWordMap(&WORDS_{name});",
+                deflated,
+                cfg = cfg,
+                name = name,
+            )
+            .unwrap();
+
+            continue;
+        }
+
+        let mut sorted = words.clone();
+        sorted.sort_unstable();
+
+        writeln!(
+            out,
+            "{}pub static WORDLIST_{}: WordList = WordList {{ inner: {:?}, sorted: {:?} }};",
+            cfg, name, words, sorted
+        )
+        .unwrap();
+
+        let wordmap = if constant_time {
+            format!("WordMapInner::Scan(WORDLIST_{}.inner)", name)
+        } else if binary_search {
+            let mut sorted: Vec<u16> = (0..2048).collect();
+            sorted.sort_by_key(|&index| words[index as usize]);
+
+            format!("WordMapInner::Sorted(&WORDLIST_{}, &{:?})", name, sorted)
+        } else {
+            let mut map = phf_codegen::Map::new();
+
+            for (index, word) in words.iter().enumerate() {
+                map.entry(*word, &index.to_string());
+            }
+
+            format!("WordMapInner::Static(&{})", map.build())
+        };
+
+        writeln!(
+            out,
+            "{}pub static WORDMAP_{}: WordMap = WordMap {{ inner: {} }};",
+            cfg, name, wordmap
+        )
+        .unwrap();
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,100 @@
+#ifndef BIP39_H
+#define BIP39_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Success
+ */
+#define BIP39_OK 0
+
+/**
+ * A required pointer was `NULL`, or a string wasn't valid UTF-8
+ */
+#define BIP39_INVALID_ARGUMENT 1
+
+/**
+ * The language code isn't known, or the language isn't enabled
+ */
+#define BIP39_UNKNOWN_LANGUAGE 2
+
+/**
+ * The phrase or the requested mnemonic doesn't have a standard number of words
+ */
+#define BIP39_INVALID_WORD_COUNT 3
+
+/**
+ * A word of the phrase isn't in the word list
+ */
+#define BIP39_INVALID_WORD 4
+
+/**
+ * The checksum of the phrase doesn't match
+ */
+#define BIP39_INVALID_CHECKSUM 5
+
+/**
+ * The operating system random number generator failed
+ */
+#define BIP39_RANDOM_SOURCE 6
+
+/**
+ * Any other error
+ */
+#define BIP39_ERROR 7
+
+/**
+ * Length of the seed written by [`bip39_to_seed()`][bip39_to_seed]
+ *
+ * [bip39_to_seed]: ./fn.bip39_to_seed.html
+ */
+#define BIP39_SEED_LENGTH 64
+
+/**
+ * Generate a new mnemonic phrase with `words` words, using the operating system CSPRNG
+ *
+ * On success, `*phrase_out` points to a NUL terminated phrase, which must be released with
+ * [`bip39_free_phrase()`][bip39_free_phrase]. On failure, it is set to `NULL`.
+ *
+ * # Safety
+ *
+ * `lang` must be `NULL` or a valid NUL terminated string, and `phrase_out` a valid pointer.
+ *
+ * [bip39_free_phrase]: ./fn.bip39_free_phrase.html
+ */
+int bip39_generate(size_t words, const char *lang, char **phrase_out);
+
+/**
+ * Validate a mnemonic phrase, returning `BIP39_OK` if it is valid, checksum included
+ *
+ * # Safety
+ *
+ * `phrase` must be a valid NUL terminated string, and `lang` either `NULL` or one too.
+ */
+int bip39_validate(const char *phrase, const char *lang);
+
+/**
+ * Validate a mnemonic phrase and write its seed into the `BIP39_SEED_LENGTH` bytes at `seed_out`
+ *
+ * `passphrase` may be `NULL` for an empty passphrase.
+ *
+ * # Safety
+ *
+ * `phrase` must be a valid NUL terminated string, `passphrase` and `lang` either `NULL` or one
+ * too, and `seed_out` must be valid for writes of `BIP39_SEED_LENGTH` bytes.
+ */
+int bip39_to_seed(const char *phrase, const char *passphrase, const char *lang, uint8_t *seed_out);
+
+/**
+ * Wipe and free a phrase returned by this library, does nothing for `NULL`
+ *
+ * # Safety
+ *
+ * `phrase` must be `NULL` or a pointer returned by this library, which hasn't been freed yet.
+ */
+void bip39_free_phrase(char *phrase);
+
+#endif /* BIP39_H */
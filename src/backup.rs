@@ -0,0 +1,312 @@
+//! The three-card backup of a [`Mnemonic`][Mnemonic], where any two cards give back the phrase
+//!
+//! The words are split into thirds, and each [`Card`][Card] holds two of them, with the other
+//! left blank: the first card lacks the last third, the second card the middle one, and the third
+//! card the first one. Any two cards hold every word between them, so one card can be lost.
+//!
+//! ```
+//! use bip39::{backup, Language, Mnemonic};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! let [first, _, third] = backup::split(&mnemonic);
+//! assert_eq!(first.to_string().lines().nth(8), Some(" 9. ____"));
+//!
+//! assert_eq!(backup::combine(&[first, third]).unwrap(), mnemonic);
+//! ```
+//!
+//! **Note:** unlike SLIP-0039 shares or SeedXOR parts, a card reveals most of the phrase.
+//! Finding the four missing words of a 12 word phrase takes 2<sup>44</sup> guesses, which is
+//! within reach of a determined attacker, so each card must still be kept as safe as the
+//! mnemonic itself. The scheme protects against losing a card, not against one being stolen.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [Card]: ./struct.Card.html
+
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use zeroize::Zeroizing;
+
+/// One of the three cards of a backup, holding two thirds of the words of the phrase
+#[derive(Clone, PartialEq, Eq)]
+pub struct Card {
+    number: u8,
+    words: Vec<Option<&'static str>>,
+    lang: Language,
+}
+
+impl Card {
+    /// Create card `number`, from 1 to 3, out of the words written on it, with `None` for blanks
+    ///
+    /// This is for entering a card by hand, to [`combine()`][combine] it with another one.
+    /// Returns `Error::InvalidShare` if `number` isn't 1, 2 or 3, or if the blanks aren't exactly
+    /// the third of the words that card lacks, `Error::InvalidWordCount` without 12, 15, 18, 21
+    /// or 24 positions in total, and `Error::InvalidWord` for a word that isn't in the word list
+    /// of `lang`.
+    ///
+    /// [combine]: ./fn.combine.html
+    pub fn new(number: u8, words: &[Option<&str>], lang: Language) -> Result<Card, Error> {
+        if !(1..=3).contains(&number) {
+            return Err(Error::InvalidShare);
+        }
+
+        MnemonicType::for_word_count(words.len())?;
+        let missing = missing(number, words.len());
+
+        let words = words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| match *word {
+                None if missing.contains(&index) => Ok(None),
+                Some(word) if !missing.contains(&index) => lang
+                    .index_of(word)
+                    .and_then(|word_index| lang.wordlist().get(word_index))
+                    .map(Some)
+                    .ok_or_else(|| Error::InvalidWord {
+                        word: SecretWord::new(word, Some(lang)),
+                        index,
+                    }),
+                _ => Err(Error::InvalidShare),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Card {
+            number,
+            words,
+            lang,
+        })
+    }
+
+    /// The number of the card, from 1 to 3
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// The words of the card, in the order of the phrase, with `None` for the blanks
+    pub fn words(&self) -> &[Option<&'static str>] {
+        &self.words
+    }
+
+    /// The positions of the blank words, counting from 0
+    pub fn missing(&self) -> Range<usize> {
+        missing(self.number, self.words.len())
+    }
+
+    /// The language of the words
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+}
+
+/// The positions of the words card `number` lacks, out of `word_count`
+fn missing(number: u8, word_count: usize) -> Range<usize> {
+    let third = word_count / 3;
+    let start = (3 - usize::from(number)) * third;
+
+    start..start + third
+}
+
+/// One line per word, numbered from 1, with `____` for the blanks, to write the card out
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, word) in self.words.iter().enumerate() {
+            writeln!(f, "{:>2}. {}", index + 1, word.unwrap_or("____"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The words are a secret, so they're never printed, use `Display` to get them explicitly.
+impl fmt::Debug for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Card({}, ****)", self.number)
+    }
+}
+
+/// Split `mnemonic` into the three cards of a backup
+pub fn split(mnemonic: &Mnemonic) -> [Card; 3] {
+    let words: Vec<&'static str> = mnemonic
+        .to_indices()
+        .iter()
+        .filter_map(|&index| mnemonic.language().wordlist().get(index))
+        .collect();
+
+    [1, 2, 3].map(|number| {
+        let missing = missing(number, words.len());
+
+        Card {
+            number,
+            words: (0..words.len())
+                .map(|index| Some(words[index]).filter(|_| !missing.contains(&index)))
+                .collect(),
+            lang: mnemonic.language(),
+        }
+    })
+}
+
+/// Get the [`Mnemonic`][Mnemonic] back from two or three cards of a backup
+///
+/// The cards can be in any order. Returns `Error::NotEnoughShares` with the number of different
+/// cards if there are fewer than two, `Error::MismatchedShares` if they aren't all in the same
+/// language with as many words, or have different words in the same position, and
+/// `Error::InvalidChecksum` if the phrase they make isn't valid.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+pub fn combine(cards: &[Card]) -> Result<Mnemonic, Error> {
+    let first = cards
+        .first()
+        .ok_or(Error::NotEnoughShares { got: 0, needed: 2 })?;
+    let word_count = first.words.len();
+
+    if cards
+        .iter()
+        .any(|card| card.lang != first.lang || card.words.len() != word_count)
+    {
+        return Err(Error::MismatchedShares);
+    }
+
+    let different = (1..=3)
+        .filter(|&number| cards.iter().any(|card| card.number == number))
+        .count();
+    if different < 2 {
+        return Err(Error::NotEnoughShares {
+            got: different,
+            needed: 2,
+        });
+    }
+
+    let mut phrase = Zeroizing::new(String::new());
+
+    for index in 0..word_count {
+        let mut words = cards.iter().filter_map(|card| card.words[index]);
+        let word = words.next().ok_or(Error::MismatchedShares)?;
+
+        if words.any(|other| other != word) {
+            return Err(Error::MismatchedShares);
+        }
+
+        if index > 0 {
+            phrase.push_str(first.lang.separator());
+        }
+        phrase.push_str(word);
+    }
+
+    Mnemonic::from_phrase(&phrase, first.lang)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn split_and_combine() {
+        let mnemonics = [
+            Mnemonic::from_phrase(PHRASE, Language::English).unwrap(),
+            Mnemonic::from_entropy(&[0xff; 20], Language::English).unwrap(),
+            Mnemonic::from_entropy(&[0; 32], Language::English).unwrap(),
+        ];
+
+        for mnemonic in mnemonics {
+            let cards = split(&mnemonic);
+            let third = mnemonic.word_count() / 3;
+
+            for (card, missing) in cards.iter().zip([2, 1, 0]) {
+                assert_eq!(card.missing(), missing * third..(missing + 1) * third);
+                assert_eq!(
+                    card.words().iter().filter(|word| word.is_none()).count(),
+                    third
+                );
+            }
+
+            for pair in [[0, 1], [0, 2], [1, 2], [2, 0]] {
+                let pair = pair.map(|index| cards[index].clone());
+                assert_eq!(combine(&pair), Ok(mnemonic.clone()));
+            }
+            assert_eq!(combine(&cards), Ok(mnemonic));
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn card_from_words() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let [first, _, third] = split(&mnemonic);
+
+        let words: Vec<Option<&str>> = third.words().to_vec();
+        let typed = Card::new(3, &words, Language::English).unwrap();
+        assert_eq!(typed, third);
+        assert_eq!(combine(&[first.clone(), typed]), Ok(mnemonic));
+
+        assert_eq!(
+            Card::new(4, &words, Language::English),
+            Err(Error::InvalidShare)
+        );
+        assert_eq!(
+            Card::new(1, &words, Language::English),
+            Err(Error::InvalidShare)
+        );
+        assert_eq!(
+            Card::new(3, &words[..9], Language::English),
+            Err(Error::InvalidWordCount(9))
+        );
+
+        let mut misspelled = words.clone();
+        misspelled[5] = Some("spel");
+        assert!(matches!(
+            Card::new(3, &misspelled, Language::English),
+            Err(Error::InvalidWord { index: 5, .. })
+        ));
+
+        assert_eq!(format!("{:?}", first), "Card(1, ****)");
+        assert_eq!(first.to_string().lines().next(), Some(" 1. park"));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn combine_errors() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let [first, second, third] = split(&mnemonic);
+
+        assert_eq!(
+            combine(&[]),
+            Err(Error::NotEnoughShares { got: 0, needed: 2 })
+        );
+        assert_eq!(
+            combine(&[first.clone(), first.clone()]),
+            Err(Error::NotEnoughShares { got: 1, needed: 2 }),
+        );
+
+        let other = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+        let [_, other_second, _] = split(&other);
+        assert_eq!(
+            combine(&[first.clone(), other_second]),
+            Err(Error::MismatchedShares)
+        );
+
+        let longer = Mnemonic::from_entropy(&[0; 32], Language::English).unwrap();
+        let [_, _, longer_third] = split(&longer);
+        assert_eq!(
+            combine(&[second, longer_third]),
+            Err(Error::MismatchedShares)
+        );
+
+        let mut words = third.words().to_vec();
+        words[11] = Some("zoo");
+        let wrong = Card::new(3, &words, Language::English).unwrap();
+        assert_eq!(combine(&[first, wrong]), Err(Error::InvalidChecksum));
+    }
+}
@@ -0,0 +1,180 @@
+//! The `bip39` command line tool, enabled with the `cli` feature
+//!
+//! Phrases and passphrases are never taken as arguments, where they would end up in the shell
+//! history and be visible to other processes. They are read from stdin instead, one per line, or
+//! prompted for without echo when stdin is a terminal.
+
+use bip39::{Error, Language, Mnemonic, MnemonicType};
+use clap::{Parser, Subcommand};
+use std::io::{self, BufRead, IsTerminal};
+use std::process::ExitCode;
+use zeroize::Zeroizing;
+
+/// Language names accepted by `--lang`, next to the language codes
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("english", "en"),
+    ("chinese-simplified", "zh-hans"),
+    ("chinese-traditional", "zh-hant"),
+    ("czech", "cs"),
+    ("french", "fr"),
+    ("italian", "it"),
+    ("japanese", "ja"),
+    ("korean", "ko"),
+    ("portuguese", "pt"),
+    ("spanish", "es"),
+];
+
+#[derive(Parser)]
+#[command(version, about = "Generate, validate and use BIP39 mnemonic phrases")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a new mnemonic phrase, using the operating system CSPRNG
+    Generate {
+        /// Number of words, one of 12, 15, 18, 21 or 24
+        #[arg(long, default_value_t = 24)]
+        words: usize,
+        /// Language name or code
+        #[arg(long, default_value = "english", value_parser = parse_language)]
+        lang: Language,
+    },
+    /// Validate the phrase read from stdin, exiting with a non-zero status if it's invalid
+    Validate {
+        /// Language name or code
+        #[arg(long, default_value = "english", value_parser = parse_language)]
+        lang: Language,
+    },
+    /// Print the hex encoded seed of the phrase read from stdin
+    Seed {
+        /// Also read a passphrase, after the phrase
+        #[arg(long)]
+        passphrase: bool,
+        /// Language name or code
+        #[arg(long, default_value = "english", value_parser = parse_language)]
+        lang: Language,
+    },
+    /// Print the hex encoded entropy of the phrase read from stdin
+    Entropy {
+        /// Language name or code
+        #[arg(long, default_value = "english", value_parser = parse_language)]
+        lang: Language,
+    },
+}
+
+fn parse_language(name: &str) -> Result<Language, String> {
+    let name = name.to_ascii_lowercase();
+    let code = LANGUAGE_NAMES
+        .iter()
+        .find(|(lang, _)| *lang == name)
+        .map_or(&name[..], |(_, code)| code);
+
+    Language::from_language_code(code)
+        .ok_or_else(|| format!("unknown or disabled language {:?}", name))
+}
+
+/// Read one line from stdin, without the line ending, prompting for it on a terminal
+fn read_secret(prompt: &str) -> io::Result<Zeroizing<String>> {
+    let stdin = io::stdin();
+
+    if stdin.is_terminal() {
+        return rpassword::prompt_password(prompt).map(Zeroizing::new);
+    }
+
+    let mut line = Zeroizing::new(String::new());
+
+    stdin.lock().read_line(&mut line)?;
+
+    let len = line.trim_end_matches(['\r', '\n']).len();
+    line.truncate(len);
+
+    Ok(line)
+}
+
+fn read_mnemonic(lang: Language) -> Result<Mnemonic, Box<dyn std::error::Error>> {
+    let phrase = read_secret("Mnemonic phrase: ")?;
+
+    Ok(Mnemonic::from_phrase(phrase.trim(), lang)?)
+}
+
+fn run(command: Command) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Generate { words, lang } => {
+            let mtype = MnemonicType::for_word_count(words)?;
+            let mnemonic = Mnemonic::try_new(mtype, lang)?;
+
+            println!("{}", mnemonic.phrase());
+        }
+        Command::Validate { lang } => {
+            read_mnemonic(lang)?;
+
+            println!("valid");
+        }
+        Command::Seed { passphrase, lang } => {
+            let mnemonic = read_mnemonic(lang)?;
+            let passphrase = if passphrase {
+                read_secret("Passphrase: ")?
+            } else {
+                Zeroizing::new(String::new())
+            };
+
+            println!("{:x}", mnemonic.to_seed(&passphrase));
+        }
+        Command::Entropy { lang } => {
+            let mnemonic = read_mnemonic(lang)?;
+
+            println!("{}", Zeroizing::new(mnemonic.entropy_hex()).as_str());
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            let suggestions = err.downcast_ref::<Error>().map(Error::suggestions);
+
+            match suggestions {
+                Some(suggestions) if !suggestions.is_empty() => {
+                    eprintln!("error: {}, did you mean {}?", err, suggestions.join(" or "));
+                }
+                _ => eprintln!("error: {}", err),
+            }
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn language_names_and_codes() {
+        assert_eq!(parse_language("english"), Ok(Language::English));
+        assert_eq!(parse_language("EN"), Ok(Language::English));
+        assert!(parse_language("klingon").is_err());
+
+        for (name, code) in LANGUAGE_NAMES {
+            assert_eq!(
+                parse_language(name).ok(),
+                Language::from_language_code(code)
+            );
+        }
+    }
+
+    #[test]
+    fn cli_arguments() {
+        use clap::CommandFactory;
+
+        Cli::command().debug_assert();
+    }
+}
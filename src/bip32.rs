@@ -0,0 +1,563 @@
+//! BIP32 extended keys, enabled with the `bip32` feature
+//!
+//! This covers the way from a [`Seed`][Seed] to private keys: the master key, serialized as an
+//! `xprv` with [`Seed::to_root_xprv()`][Seed::to_root_xprv()], and the keys along a derivation
+//! path, such as the BIP44 ones, with [`Seed::derive_path()`][Seed::derive_path()]. The ed25519
+//! keys of SLIP-0010, used by Solana, Stellar and others, are derived from the same seed with
+//! [`Seed::derive_ed25519()`][Seed::derive_ed25519()].
+//!
+//! ```
+//! use bip39::{Language, Mnemonic};
+//!
+//! let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! // The first receiving address of the first Bitcoin account
+//! let key = mnemonic.to_seed("").derive_path("m/44'/0'/0'/0/0").unwrap();
+//!
+//! assert_eq!(key.private_key().len(), 32);
+//! ```
+//!
+//! [Seed]: ../seed/struct.Seed.html
+//! [Seed::to_root_xprv()]: ../seed/struct.Seed.html#method.to_root_xprv
+//! [Seed::derive_path()]: ../seed/struct.Seed.html#method.derive_path
+//! [Seed::derive_ed25519()]: ../seed/struct.Seed.html#method.derive_ed25519
+
+use crate::crypto::Kdf;
+use crate::encoding;
+use crate::error::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{FieldBytes, ProjectivePoint, Scalar};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+/// The bit that marks an index as hardened
+pub(crate) const HARDENED: u32 = 0x8000_0000;
+
+/// The network an extended key is serialized for, which sets its version bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Network {
+    /// Bitcoin mainnet, with `xprv` keys
+    #[default]
+    Mainnet,
+    /// Bitcoin testnet, signet and regtest, with `tprv` keys
+    Testnet,
+}
+
+impl Network {
+    fn xprv_version(&self) -> [u8; 4] {
+        match *self {
+            Network::Mainnet => [0x04, 0x88, 0xad, 0xe4],
+            Network::Testnet => [0x04, 0x35, 0x83, 0x94],
+        }
+    }
+}
+
+/// A BIP32 derivation path, such as `m/44'/0'/0'/0/0`
+///
+/// Hardened indices are marked with `'`, `h` or `H`, and every index must be lower than 2^31.
+/// Parsing returns `Error::InvalidDerivationPath` for anything else, or if the path doesn't start
+/// with `m`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// The indices along the path, with the hardened ones offset by 2^31
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self, Error> {
+        let mut components = path.split('/');
+
+        if components.next() != Some("m") {
+            return Err(Error::InvalidDerivationPath);
+        }
+
+        let indices = components
+            .map(|component| {
+                let (digits, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                    Some(digits) => (digits, HARDENED),
+                    None => (component, 0),
+                };
+
+                if !digits.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(Error::InvalidDerivationPath);
+                }
+
+                match digits.parse::<u32>() {
+                    Ok(index) if index & HARDENED == 0 => Ok(index | hardened),
+                    _ => Err(Error::InvalidDerivationPath),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DerivationPath { indices })
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("m")?;
+
+        for &index in &self.indices {
+            if index & HARDENED == 0 {
+                write!(f, "/{}", index)?;
+            } else {
+                write!(f, "/{}'", index & !HARDENED)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The fingerprint of a BIP32 key, the first 4 bytes of the HASH160 of its public key
+///
+/// Wallets show the fingerprint of the master key, from
+/// [`Seed::fingerprint()`][Seed::fingerprint()], to confirm the right phrase and passphrase were
+/// entered, and PSBTs use it to tell which keys belong to a wallet. It's formatted as 8 lowercase hex digits, e.g. `73c5da0a`.
+///
+/// [Seed::fingerprint()]: ../seed/struct.Seed.html#method.fingerprint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint([u8; 4]);
+
+impl Fingerprint {
+    /// The 4 bytes of the fingerprint
+    pub fn as_bytes(&self) -> &[u8; 4] {
+        &self.0
+    }
+}
+
+impl From<Fingerprint> for u32 {
+    fn from(fingerprint: Fingerprint) -> u32 {
+        u32::from_be_bytes(fingerprint.0)
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08x}", u32::from(*self))
+    }
+}
+
+impl fmt::UpperHex for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08X}", u32::from(*self))
+    }
+}
+
+/// A secp256k1 private key and its chain code, derived with
+/// [`Seed::derive_path()`][Seed::derive_path()]
+///
+/// [Seed::derive_path()]: ../seed/struct.Seed.html#method.derive_path
+#[derive(Clone)]
+pub struct ExtendedPrivKey {
+    pub(crate) key: Zeroizing<[u8; 32]>,
+    pub(crate) chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl ExtendedPrivKey {
+    /// Derive the master key of `seed`
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    pub(crate) fn new_master<B: Kdf + ?Sized>(
+        seed: &[u8],
+        backend: &B,
+    ) -> Result<ExtendedPrivKey, Error> {
+        let (key, chain_code) = hmac_sha512_split(backend, b"Bitcoin seed", &[seed]);
+
+        match to_scalar(&key) {
+            Some(scalar) if !bool::from(scalar.is_zero()) => {
+                Ok(ExtendedPrivKey { key, chain_code })
+            }
+            _ => Err(Error::InvalidDerivedKey),
+        }
+    }
+
+    /// Serialize the key as the master key of `network`, at depth 0 and without a parent
+    pub(crate) fn to_master_xprv(&self, network: Network) -> Zeroizing<String> {
+        let mut payload = Zeroizing::new(Vec::with_capacity(74));
+        // The depth, the parent fingerprint and the child number
+        payload.extend_from_slice(&[0; 9]);
+        payload.extend_from_slice(&self.chain_code[..]);
+        payload.push(0);
+        payload.extend_from_slice(&self.key[..]);
+
+        encoding::to_base58check(&network.xprv_version(), &payload)
+    }
+
+    /// The 32 byte private key, a secp256k1 scalar
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The chain code, which derives the children of the key along with it
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// The fingerprint of the secp256k1 public key of the key
+    pub fn fingerprint(&self) -> Fingerprint {
+        let hash = Ripemd160::digest(Sha256::digest(self.public_key()));
+
+        Fingerprint([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    /// The compressed secp256k1 public key of the key
+    fn public_key(&self) -> [u8; 33] {
+        let scalar = to_scalar(&self.key).expect("the key of an extended key is a valid scalar");
+        let point = (ProjectivePoint::GENERATOR * scalar)
+            .to_affine()
+            .to_encoded_point(true);
+
+        let mut public_key = [0; 33];
+        public_key.copy_from_slice(point.as_bytes());
+
+        public_key
+    }
+
+    /// The uncompressed secp256k1 public key of the key, without its `0x04` prefix
+    #[cfg(feature = "ethereum")]
+    pub(crate) fn uncompressed_public_key(&self) -> [u8; 64] {
+        let scalar = to_scalar(&self.key).expect("the key of an extended key is a valid scalar");
+        let point = (ProjectivePoint::GENERATOR * scalar)
+            .to_affine()
+            .to_encoded_point(false);
+
+        let mut public_key = [0; 64];
+        public_key.copy_from_slice(&point.as_bytes()[1..]);
+
+        public_key
+    }
+
+    /// Derive the key at the end of `path`, from this key
+    pub(crate) fn derive_path<B: Kdf + ?Sized>(
+        &self,
+        path: &DerivationPath,
+        backend: &B,
+    ) -> Result<ExtendedPrivKey, Error> {
+        let mut key = self.clone();
+        for &index in path.indices() {
+            key = key.derive_child(index, backend)?;
+        }
+
+        Ok(key)
+    }
+
+    /// Derive the hardened child `index`, which must not have the hardened bit set
+    ///
+    /// Returns `Error::InvalidDerivationPath` if it does.
+    #[cfg(feature = "bip85")]
+    pub(crate) fn derive_hardened<B: Kdf + ?Sized>(
+        &self,
+        index: u32,
+        backend: &B,
+    ) -> Result<ExtendedPrivKey, Error> {
+        if index & HARDENED != 0 {
+            return Err(Error::InvalidDerivationPath);
+        }
+
+        self.derive_child(index | HARDENED, backend)
+    }
+
+    /// Derive the child `index`, which is hardened if it has the hardened bit set
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 indices that have no valid child
+    /// key, which BIP32 says to skip.
+    fn derive_child<B: Kdf + ?Sized>(
+        &self,
+        index: u32,
+        backend: &B,
+    ) -> Result<ExtendedPrivKey, Error> {
+        let parent = to_scalar(&self.key).expect("the key of an extended key is a valid scalar");
+
+        let (tweak, chain_code) = if index & HARDENED != 0 {
+            hmac_sha512_split(
+                backend,
+                &self.chain_code[..],
+                &[&[0], &self.key[..], &index.to_be_bytes()],
+            )
+        } else {
+            hmac_sha512_split(
+                backend,
+                &self.chain_code[..],
+                &[&self.public_key(), &index.to_be_bytes()],
+            )
+        };
+
+        let child = to_scalar(&tweak).map(|tweak| tweak + parent);
+
+        match child {
+            Some(child) if !bool::from(child.is_zero()) => {
+                let mut key = Zeroizing::new([0u8; 32]);
+                key.copy_from_slice(&child.to_repr());
+
+                Ok(ExtendedPrivKey { key, chain_code })
+            }
+            _ => Err(Error::InvalidDerivedKey),
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedPrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ExtendedPrivKey(****)")
+    }
+}
+
+/// An ed25519 private key and its chain code, derived along a SLIP-0010 path with
+/// [`Seed::derive_ed25519()`][derive_ed25519]
+///
+/// [derive_ed25519]: ../seed/struct.Seed.html#method.derive_ed25519
+#[derive(Clone)]
+pub struct Ed25519PrivKey {
+    key: Zeroizing<[u8; 32]>,
+    chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl Ed25519PrivKey {
+    /// Derive the SLIP-0010 ed25519 master key of `seed`, which is always valid
+    pub(crate) fn new_master<B: Kdf + ?Sized>(seed: &[u8], backend: &B) -> Ed25519PrivKey {
+        let (key, chain_code) = hmac_sha512_split(backend, b"ed25519 seed", &[seed]);
+
+        Ed25519PrivKey { key, chain_code }
+    }
+
+    /// Derive the key at the end of `path`, from this key
+    ///
+    /// Returns `Error::InvalidDerivationPath` if an index of the path isn't hardened, since ed25519
+    /// keys can only be derived with hardened indices.
+    pub(crate) fn derive_path<B: Kdf + ?Sized>(
+        &self,
+        path: &DerivationPath,
+        backend: &B,
+    ) -> Result<Ed25519PrivKey, Error> {
+        let mut key = self.clone();
+
+        for &index in path.indices() {
+            if index & HARDENED == 0 {
+                return Err(Error::InvalidDerivationPath);
+            }
+
+            let (child, chain_code) = hmac_sha512_split(
+                backend,
+                &key.chain_code[..],
+                &[&[0], &key.key[..], &index.to_be_bytes()],
+            );
+
+            key = Ed25519PrivKey {
+                key: child,
+                chain_code,
+            };
+        }
+
+        Ok(key)
+    }
+
+    /// The 32 byte ed25519 secret key, which the public key is computed from
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The chain code, which derives the children of the key along with it
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+}
+
+impl fmt::Debug for Ed25519PrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Ed25519PrivKey(****)")
+    }
+}
+
+/// The scalar of `bytes`, if they are lower than the group order
+fn to_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    Scalar::from_repr(FieldBytes::clone_from_slice(bytes)).into()
+}
+
+/// HMAC-SHA512 of the concatenated `data`, split into its two halves
+fn hmac_sha512_split<B: Kdf + ?Sized>(
+    backend: &B,
+    key: &[u8],
+    data: &[&[u8]],
+) -> (Zeroizing<[u8; 32]>, Zeroizing<[u8; 32]>) {
+    let bytes = Zeroizing::new(backend.hmac_sha512(key, data));
+
+    let mut left = Zeroizing::new([0u8; 32]);
+    let mut right = Zeroizing::new([0u8; 32]);
+    left.copy_from_slice(&bytes[..32]);
+    right.copy_from_slice(&bytes[32..]);
+
+    (left, right)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crypto::DefaultCrypto;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    // Test vectors 1 and 2 from BIP32
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn master_xprv() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivKey::new_master(&seed, &DefaultCrypto).unwrap();
+
+        assert_eq!(&master.to_master_xprv(Network::Mainnet)[..], "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi");
+        assert_eq!(&master.to_master_xprv(Network::Testnet)[..], "tprv8ZgxMBicQKsPeDgjzdC36fs6bMjGApWDNLR9erAXMs5skhMv36j9MV5ecvfavji5khqjWaWSFhN3YcCUUdiKH6isR4Pwy3U5y5egddBr16m");
+
+        let seed = hex::decode("fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542").unwrap();
+        let master = ExtendedPrivKey::new_master(&seed, &DefaultCrypto).unwrap();
+
+        assert_eq!(&master.to_master_xprv(Network::Mainnet)[..], "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn derive_path() {
+        let vectors = [
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m/0'",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m/0H/1/2H/2/1000000000",
+                "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+            ),
+            (
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "m/0/2147483647'/1/2147483646'/2",
+                "bb7d39bdb83ecf58f2fd82b6d918341cbef428661ef01ab97c28a4842125ac23",
+                "9452b549be8cea3ecb7a84bec10dcfd94afe4d129ebfd3b3cb58eedf394ed271",
+            ),
+        ];
+
+        for (seed, path, private_key, chain_code) in vectors {
+            let seed = hex::decode(seed).unwrap();
+            let master = ExtendedPrivKey::new_master(&seed, &DefaultCrypto).unwrap();
+            let key = master
+                .derive_path(&path.parse().unwrap(), &DefaultCrypto)
+                .unwrap();
+
+            assert_eq!(hex::encode(key.private_key()), private_key, "{}", path);
+            assert_eq!(hex::encode(key.chain_code()), chain_code, "{}", path);
+        }
+    }
+
+    // The key identifiers of test vector 1 from BIP32
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn fingerprint() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivKey::new_master(&seed, &DefaultCrypto).unwrap();
+        let fingerprint = master.fingerprint();
+
+        assert_eq!(fingerprint.as_bytes(), &[0x34, 0x42, 0x19, 0x3e]);
+        assert_eq!(u32::from(fingerprint), 0x3442_193e);
+        assert_eq!(fingerprint.to_string(), "3442193e");
+        assert_eq!(format!("{:X}", fingerprint), "3442193E");
+
+        let child = master
+            .derive_path(&"m/0'".parse().unwrap(), &DefaultCrypto)
+            .unwrap();
+        assert_eq!(child.fingerprint().to_string(), "5c1bd648");
+    }
+
+    // Test vector 1 for ed25519 from SLIP-0010
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn derive_ed25519() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = Ed25519PrivKey::new_master(&seed, &DefaultCrypto);
+
+        let vectors = [
+            (
+                "m",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+            ),
+            (
+                "m/0'",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+            ),
+            (
+                "m/0'/1'/2'/2'/1000000000'",
+                "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+                "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+            ),
+        ];
+
+        for (path, private_key, chain_code) in vectors {
+            let key = master
+                .derive_path(&path.parse().unwrap(), &DefaultCrypto)
+                .unwrap();
+
+            assert_eq!(hex::encode(key.private_key()), private_key, "{}", path);
+            assert_eq!(hex::encode(key.chain_code()), chain_code, "{}", path);
+        }
+
+        assert_eq!(
+            master
+                .derive_path(&"m/0'/1".parse().unwrap(), &DefaultCrypto)
+                .map(|_| ()),
+            Err(Error::InvalidDerivationPath)
+        );
+        assert_eq!(format!("{:?}", master), "Ed25519PrivKey(****)");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn parse_path() {
+        let path: DerivationPath = "m/44'/0h/0H/0/1".parse().unwrap();
+
+        assert_eq!(path.indices(), [HARDENED | 44, HARDENED, HARDENED, 0, 1]);
+        assert_eq!(path.to_string(), "m/44'/0'/0'/0/1");
+        assert_eq!("m".parse::<DerivationPath>().unwrap().indices(), [0u32; 0]);
+
+        let invalid_paths = [
+            "",
+            "44'/0'",
+            "m/",
+            "m/-1",
+            "m/+1",
+            "m/1''",
+            "m/2147483648",
+            "m/0x1",
+            "M/0",
+        ];
+
+        for invalid in invalid_paths {
+            assert_eq!(
+                invalid.parse::<DerivationPath>(),
+                Err(Error::InvalidDerivationPath)
+            );
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! BIP85 deterministic entropy, enabled with the `bip85` feature
+//!
+//! BIP85 derives any number of independent secrets from one master seed, along hardened BIP32
+//! paths starting with `m/83696968'`. Backing up the master seed backs up all of them, while none
+//! of them reveal anything about it, or about each other.
+//!
+//! ```
+//! use bip39::bip85::Bip85;
+//! use bip39::{Language, Mnemonic, MnemonicType, Seed};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let master = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! let bip85 = Bip85::new(&Seed::new(&master, "")).unwrap();
+//!
+//! // A new 24 word mnemonic for the first device, and a password for a web site
+//! let device = bip85.mnemonic(Language::English, MnemonicType::Words24, 0).unwrap();
+//! let password = bip85.password(20, 0).unwrap();
+//!
+//! assert_eq!(device.word_count(), 24);
+//! assert_eq!(password.len(), 20);
+//! ```
+
+use crate::bip32::ExtendedPrivKey;
+use crate::crypto::{CryptoBackend, DefaultCrypto};
+use crate::encoding;
+use crate::error::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use alloc::string::String;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+/// The purpose of BIP85 paths, the first index after the master key
+const PURPOSE: u32 = 83_696_968;
+
+/// The application numbers of the supported BIP85 applications
+const APP_BIP39: u32 = 39;
+const APP_HEX: u32 = 128_169;
+const APP_PWD_BASE64: u32 = 707_764;
+
+/// Derives BIP85 child secrets from the master key of a seed
+///
+/// The HMACs of BIP32 and BIP85 are run by the [`CryptoBackend`][CryptoBackend] `B`, which is
+/// [`DefaultCrypto`][DefaultCrypto] unless the `Bip85` is made with
+/// [`Bip85::new_with_backend()`][Bip85::new_with_backend()].
+///
+/// [CryptoBackend]: ../trait.CryptoBackend.html
+/// [DefaultCrypto]: ../struct.DefaultCrypto.html
+/// [Bip85::new_with_backend()]: ./struct.Bip85.html#method.new_with_backend
+#[derive(Clone)]
+pub struct Bip85<B = DefaultCrypto> {
+    root: ExtendedPrivKey,
+    backend: B,
+}
+
+impl Bip85 {
+    /// Derive the BIP32 master key of `seed`
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    pub fn new(seed: &Seed) -> Result<Bip85, Error> {
+        Bip85::new_with_backend(seed, DefaultCrypto)
+    }
+}
+
+impl<B: CryptoBackend> Bip85<B> {
+    /// Derive the BIP32 master key of `seed`, with every HMAC run by `backend`
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    pub fn new_with_backend(seed: &Seed, backend: B) -> Result<Bip85<B>, Error> {
+        Ok(Bip85 {
+            root: ExtendedPrivKey::new_master(seed.as_bytes(), &backend)?,
+            backend,
+        })
+    }
+
+    /// Derive the child mnemonic `index` in `lang` with the number of words of `mtype`, along
+    /// `m/83696968'/39'/{language}'/{words}'/{index}'`
+    ///
+    /// BIP85 only has test vectors for 12, 18 and 24 words, which are what most wallets support.
+    /// Returns `Error::InvalidDerivationPath` if `index` is 2^31 or more, or if `lang` is a custom
+    /// word list, since only the languages of BIP39 have a code.
+    pub fn mnemonic(
+        &self,
+        lang: Language,
+        mtype: MnemonicType,
+        index: u32,
+    ) -> Result<Mnemonic, Error> {
+        let path = [
+            PURPOSE,
+            APP_BIP39,
+            language_code(lang)?,
+            mtype.word_count() as u32,
+            index,
+        ];
+        let entropy = self.derive(&path)?;
+
+        Mnemonic::from_entropy_with_backend(&entropy[..mtype.entropy_len()], lang, &self.backend)
+    }
+
+    /// Derive `len` bytes of child entropy `index`, along `m/83696968'/128169'/{len}'/{index}'`
+    ///
+    /// Returns `Error::InvalidDerivationPath` if `len` isn't between 16 and 64, or if `index` is
+    /// 2^31 or more.
+    pub fn entropy(&self, len: usize, index: u32) -> Result<Zeroizing<Vec<u8>>, Error> {
+        if !(16..=64).contains(&len) {
+            return Err(Error::InvalidDerivationPath);
+        }
+
+        let entropy = self.derive(&[PURPOSE, APP_HEX, len as u32, index])?;
+
+        Ok(Zeroizing::new(entropy[..len].to_vec()))
+    }
+
+    /// Derive the base64 child password `index` of `len` characters, along
+    /// `m/83696968'/707764'/{len}'/{index}'`
+    ///
+    /// Returns `Error::InvalidDerivationPath` if `len` isn't between 20 and 86, or if `index` is
+    /// 2^31 or more.
+    pub fn password(&self, len: usize, index: u32) -> Result<Zeroizing<String>, Error> {
+        if !(20..=86).contains(&len) {
+            return Err(Error::InvalidDerivationPath);
+        }
+
+        let entropy = self.derive(&[PURPOSE, APP_PWD_BASE64, len as u32, index])?;
+
+        let mut password = encoding::to_base64(&entropy[..]);
+        password.truncate(len);
+
+        Ok(password)
+    }
+
+    /// Derive the hardened `path` from the master key, and the entropy from its private key
+    fn derive(&self, path: &[u32]) -> Result<Zeroizing<[u8; 64]>, Error> {
+        let mut key = self.root.clone();
+        for &index in path {
+            key = key.derive_hardened(index, &self.backend)?;
+        }
+
+        Ok(Zeroizing::new(
+            self.backend
+                .hmac_sha512(b"bip-entropy-from-k", &[&key.key[..]]),
+        ))
+    }
+}
+
+/// The BIP85 code of `lang`
+fn language_code(lang: Language) -> Result<u32, Error> {
+    match lang {
+        Language::English => Ok(0),
+        #[cfg(feature = "japanese")]
+        Language::Japanese => Ok(1),
+        #[cfg(feature = "korean")]
+        Language::Korean => Ok(2),
+        #[cfg(feature = "spanish")]
+        Language::Spanish => Ok(3),
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified => Ok(4),
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional => Ok(5),
+        #[cfg(feature = "french")]
+        Language::French => Ok(6),
+        #[cfg(feature = "italian")]
+        Language::Italian => Ok(7),
+        #[cfg(feature = "czech")]
+        Language::Czech => Ok(8),
+        #[cfg(feature = "portuguese")]
+        Language::Portuguese => Ok(9),
+        Language::Custom(_) => Err(Error::InvalidDerivationPath),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    // Test vectors from BIP85, all derived from the master key of
+    // xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb
+    fn bip85() -> Bip85 {
+        let mut key = Zeroizing::new([0u8; 32]);
+        let mut chain_code = Zeroizing::new([0u8; 32]);
+
+        hex::decode_to_slice(
+            "3f15e5d852dc2e9ba5e9fe189a8dd2e1547badef5b563bbe6579fc6807d80ed9",
+            &mut key[..],
+        )
+        .unwrap();
+        hex::decode_to_slice(
+            "1b67969d1ec69bdfeeae43213da8460ba34b92d0788c8f7bfcfa44906e8a589c",
+            &mut chain_code[..],
+        )
+        .unwrap();
+
+        Bip85 {
+            root: ExtendedPrivKey { key, chain_code },
+            backend: DefaultCrypto,
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn derive() {
+        let entropy = bip85().derive(&[PURPOSE, 0, 0]).unwrap();
+        assert_eq!(hex::encode(&entropy[..]), "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7");
+
+        let entropy = bip85().derive(&[PURPOSE, 0, 1]).unwrap();
+        assert_eq!(hex::encode(&entropy[..]), "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonics() {
+        let vectors = [
+            (
+                MnemonicType::Words12,
+                "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+            ),
+            (
+                MnemonicType::Words18,
+                "near account window bike charge season chef number sketch tomorrow excuse sniff \
+                 circle vital hockey outdoor supply token",
+            ),
+            (
+                MnemonicType::Words24,
+                "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget \
+                 divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+            ),
+        ];
+
+        for (mtype, phrase) in vectors {
+            let mnemonic = bip85().mnemonic(Language::English, mtype, 0).unwrap();
+
+            assert_eq!(mnemonic.phrase(), phrase);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy_and_password() {
+        let entropy = bip85().entropy(64, 0).unwrap();
+        assert_eq!(hex::encode(&entropy[..]), "492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c");
+
+        assert_eq!(
+            &bip85().password(21, 0).unwrap()[..],
+            "dKLoepugzdVJvdL56ogNV"
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid() {
+        assert_eq!(
+            bip85().entropy(15, 0).map(|_| ()),
+            Err(Error::InvalidDerivationPath)
+        );
+        assert_eq!(
+            bip85().password(87, 0).map(|_| ()),
+            Err(Error::InvalidDerivationPath)
+        );
+        assert_eq!(
+            bip85()
+                .mnemonic(Language::English, MnemonicType::Words12, 1 << 31)
+                .map(|_| ()),
+            Err(Error::InvalidDerivationPath)
+        );
+    }
+}
@@ -0,0 +1,214 @@
+#[cfg(feature = "rand")]
+use crate::crypto::gen_os_random_bytes;
+use crate::crypto::gen_random_bytes;
+use crate::error::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+/// A builder for creating a [`Mnemonic`][Mnemonic] with all of the options in one place
+///
+/// By default it creates a 12 word English mnemonic, with entropy from the operating system
+/// CSPRNG. The entropy can come from a supplied RNG with
+/// [`MnemonicBuilder::rng()`][MnemonicBuilder::rng()] instead, or be given directly with
+/// [`MnemonicBuilder::entropy()`][MnemonicBuilder::entropy()], which takes precedence over any RNG.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Language, MnemonicBuilder};
+///
+/// let (mnemonic, seed) = MnemonicBuilder::new()
+///     .language(Language::English)
+///     .word_count(24)
+///     .passphrase("correct horse battery staple")
+///     .build_with_seed()
+///     .unwrap();
+///
+/// assert_eq!(mnemonic.word_count(), 24);
+/// assert_eq!(seed.as_bytes().len(), 64);
+/// ```
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [MnemonicBuilder::rng()]: ./struct.MnemonicBuilder.html#method.rng
+/// [MnemonicBuilder::entropy()]: ./struct.MnemonicBuilder.html#method.entropy
+#[derive(Default)]
+pub struct MnemonicBuilder<'a> {
+    lang: Language,
+    word_count: Option<usize>,
+    entropy: Option<&'a [u8]>,
+    rng: Option<&'a mut dyn CryptoRngCore>,
+    passphrase: &'a str,
+}
+
+impl<'a> MnemonicBuilder<'a> {
+    /// Start building a 12 word English mnemonic, with entropy from the operating system CSPRNG
+    /// and an empty passphrase
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The language of the phrase (English by default)
+    pub fn language(mut self, lang: Language) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// The number of words of the phrase, 12, 15, 18, 21 or 24 (12 by default)
+    ///
+    /// Any other number makes the build fail with `Error::InvalidWordCount`.
+    pub fn word_count(mut self, word_count: usize) -> Self {
+        self.word_count = Some(word_count);
+        self
+    }
+
+    /// The number of words of the phrase, as a [`MnemonicType`][MnemonicType]
+    ///
+    /// [MnemonicType]: ./mnemonic_type/enum.MnemonicType.html
+    pub fn mnemonic_type(self, mtype: MnemonicType) -> Self {
+        self.word_count(mtype.word_count())
+    }
+
+    /// Use `entropy` as it is, instead of generating it
+    ///
+    /// Without a word count, it's derived from the length of `entropy`. With one, the build fails
+    /// with `Error::InvalidEntropyLength` if they don't match.
+    pub fn entropy(mut self, entropy: &'a [u8]) -> Self {
+        self.entropy = Some(entropy);
+        self
+    }
+
+    /// Generate the entropy with `rng`, instead of the operating system CSPRNG
+    ///
+    /// Without the `rand` feature, either this or [`MnemonicBuilder::entropy()`][entropy] is
+    /// required, the build fails with `Error::RandomSource` otherwise.
+    ///
+    /// [entropy]: ./struct.MnemonicBuilder.html#method.entropy
+    pub fn rng(mut self, rng: &'a mut dyn CryptoRngCore) -> Self {
+        self.rng = Some(rng);
+        self
+    }
+
+    /// The BIP39 password used by [`MnemonicBuilder::build_with_seed()`][build_with_seed] (empty
+    /// by default)
+    ///
+    /// [build_with_seed]: ./struct.MnemonicBuilder.html#method.build_with_seed
+    pub fn passphrase(mut self, passphrase: &'a str) -> Self {
+        self.passphrase = passphrase;
+        self
+    }
+
+    /// Create the [`Mnemonic`][Mnemonic]
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn build(self) -> Result<Mnemonic, Error> {
+        let mtype = match (self.word_count, self.entropy) {
+            (Some(word_count), _) => MnemonicType::for_word_count(word_count)?,
+            (None, Some(entropy)) => MnemonicType::for_entropy_len(entropy.len())?,
+            (None, None) => MnemonicType::default(),
+        };
+
+        let entropy = match (self.entropy, self.rng) {
+            (Some(entropy), _) if entropy.len() != mtype.entropy_len() => {
+                return Err(Error::InvalidEntropyLength {
+                    got: entropy.len() * 8,
+                    expected: mtype,
+                })
+            }
+            (Some(entropy), _) => Zeroizing::new(entropy.to_vec()),
+            (None, Some(mut rng)) => gen_random_bytes(&mut rng, mtype.entropy_len())?,
+            #[cfg(feature = "rand")]
+            (None, None) => gen_os_random_bytes(mtype.entropy_len())?,
+            #[cfg(not(feature = "rand"))]
+            (None, None) => return Err(Error::RandomSource(crate::error::RngError::default())),
+        };
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, self.lang))
+    }
+
+    /// Create the [`Mnemonic`][Mnemonic] and derive its [`Seed`][Seed] with the passphrase
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed]: ./seed/struct.Seed.html
+    pub fn build_with_seed(self) -> Result<(Mnemonic, Seed), Error> {
+        let passphrase = self.passphrase;
+        let mnemonic = self.build()?;
+        let seed = Seed::new(&mnemonic, passphrase);
+
+        Ok((mnemonic, seed))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "rand")]
+    fn defaults() {
+        let mnemonic = MnemonicBuilder::new().build().unwrap();
+
+        assert_eq!(mnemonic.word_count(), 12);
+        assert_eq!(mnemonic.language(), Language::English);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn rng() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mnemonic = MnemonicBuilder::new()
+            .word_count(18)
+            .rng(&mut rng)
+            .build()
+            .unwrap();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let expected = Mnemonic::new_with_rng(&mut rng, MnemonicType::Words18, Language::English);
+
+        assert_eq!(mnemonic, expected);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy() {
+        let (mnemonic, seed) = MnemonicBuilder::new()
+            .entropy(&[0; 32])
+            .passphrase("password")
+            .build_with_seed()
+            .unwrap();
+
+        assert_eq!(
+            mnemonic,
+            Mnemonic::from_entropy(&[0; 32], Language::English).unwrap()
+        );
+        assert_eq!(seed, Seed::new(&mnemonic, "password"));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid() {
+        assert_eq!(
+            MnemonicBuilder::new().word_count(13).build().map(|_| ()),
+            Err(Error::InvalidWordCount(13))
+        );
+        assert_eq!(
+            MnemonicBuilder::new()
+                .entropy(&[0; 16])
+                .word_count(24)
+                .build()
+                .map(|_| ()),
+            Err(Error::InvalidEntropyLength {
+                got: 128,
+                expected: MnemonicType::Words24
+            })
+        );
+    }
+}
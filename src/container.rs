@@ -0,0 +1,207 @@
+//! Versioned backups of a mnemonic or a seed encrypted under a password, enabled with the `seal`
+//! feature
+//!
+//! A backup is a [`Contents`][Contents], either a [`Mnemonic`][Mnemonic] or a [`Seed`][Seed],
+//! written with [`export()`][export] and read back with [`import()`][import]. It's laid out like
+//! a sealed mnemonic, see [`Mnemonic::seal()`][Mnemonic::seal()], with its own magic and the kind
+//! of contents in front of the plaintext:
+//!
+//! | bytes | field                                          |
+//! |-------|------------------------------------------------|
+//! | 4     | magic, `B39C`                                  |
+//! | 1     | format version, `1`                            |
+//! | 1     | key derivation function, `1` for Argon2id      |
+//! | 12    | Argon2id `m_cost`, `t_cost` and `p_cost`, BE   |
+//! | 16    | salt                                           |
+//! | 24    | nonce                                          |
+//! | rest  | XChaCha20-Poly1305 ciphertext and tag          |
+//!
+//! The plaintext is `1` followed by the length of the language code, the code and the entropy
+//! of a mnemonic, or `2` followed by the 64 bytes of a seed. The whole header is authenticated as
+//! associated data.
+//!
+//! ```
+//! use bip39::container::{self, Contents};
+//! use bip39::{Language, Mnemonic};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! let backup = container::export(&Contents::Mnemonic(mnemonic.clone()), "hunter2").unwrap();
+//!
+//! assert_eq!(container::import(&backup, "hunter2"), Ok(Contents::Mnemonic(mnemonic)));
+//! ```
+//!
+//! [Contents]: ./enum.Contents.html
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [Seed]: ../seed/struct.Seed.html
+//! [export]: ./fn.export.html
+//! [import]: ./fn.import.html
+//! [Mnemonic::seal()]: ../mnemonic/struct.Mnemonic.html#method.seal
+
+use crate::crypto::SEED_BYTES;
+use crate::error::Error;
+use crate::mnemonic::Mnemonic;
+use crate::seal;
+use crate::seed::{Argon2Params, Seed};
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+/// Identifies a backup container
+const MAGIC: [u8; 4] = *b"B39C";
+
+/// The kinds of contents, the first byte of the plaintext
+const KIND_MNEMONIC: u8 = 1;
+const KIND_SEED: u8 = 2;
+
+/// What a backup container holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contents {
+    /// A mnemonic, stored as its entropy and language code
+    Mnemonic(Mnemonic),
+    /// A seed, for wallets that don't keep the mnemonic
+    Seed(Seed),
+}
+
+/// Encrypt `contents` under `password`, using entropy from the operating system CSPRNG
+///
+/// The key is stretched from the password with Argon2id and the default
+/// [`Argon2Params`][Argon2Params]. Returns `Error::UnknownLanguage` for a mnemonic with a custom
+/// word list, which has no language code.
+///
+/// [Argon2Params]: ../seed/struct.Argon2Params.html
+#[cfg(feature = "rand")]
+pub fn export(contents: &Contents, password: &str) -> Result<Vec<u8>, Error> {
+    export_with_rng(
+        &mut rand_core::OsRng,
+        contents,
+        password,
+        &Argon2Params::default(),
+    )
+}
+
+/// Encrypt `contents` under `password` with Argon2id `params`, using entropy from `rng`
+///
+/// See [`export()`][export]. Returns `Error::InvalidKdfParams` if `params` are above 1 GiB of
+/// memory, 16 iterations or 16 lanes, which [`import()`][import] would refuse.
+///
+/// [export]: ./fn.export.html
+/// [import]: ./fn.import.html
+pub fn export_with_rng<R>(
+    rng: &mut R,
+    contents: &Contents,
+    password: &str,
+    params: &Argon2Params,
+) -> Result<Vec<u8>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let mut plaintext = Zeroizing::new(Vec::new());
+
+    match contents {
+        Contents::Mnemonic(mnemonic) => {
+            plaintext.push(KIND_MNEMONIC);
+            plaintext.extend_from_slice(&seal::mnemonic_plaintext(
+                mnemonic.entropy(),
+                mnemonic.language(),
+            )?);
+        }
+        Contents::Seed(seed) => {
+            plaintext.push(KIND_SEED);
+            plaintext.extend_from_slice(seed.as_bytes());
+        }
+    }
+
+    seal::encrypt(rng, MAGIC, &plaintext, password, params)
+}
+
+/// Decrypt the contents of a backup written by [`export()`][export]
+///
+/// Returns `Error::InvalidPassphrase` if the password is wrong or the backup was altered,
+/// `Error::InvalidContainer` if it isn't a backup container, and `Error::UnsupportedVersion`
+/// for a newer version of the format. `Error::UnknownLanguage` is returned if the language of a
+/// mnemonic isn't enabled. Backups with Argon2id parameters above 1 GiB of memory, 16
+/// iterations or 16 lanes are rejected with `Error::InvalidKdfParams` before the password is
+/// stretched.
+///
+/// [export]: ./fn.export.html
+pub fn import(container: &[u8], password: &str) -> Result<Contents, Error> {
+    let plaintext = seal::decrypt(container, MAGIC, password, Error::InvalidContainer)?;
+
+    match plaintext.split_first() {
+        Some((&KIND_MNEMONIC, rest)) => {
+            let (entropy, lang) = seal::parse_mnemonic_plaintext(rest, Error::InvalidContainer)?;
+
+            Ok(Contents::Mnemonic(Mnemonic::from_entropy(&entropy, lang)?))
+        }
+        Some((&KIND_SEED, rest)) if rest.len() == SEED_BYTES => {
+            Ok(Contents::Seed(Seed::from_bytes(rest.to_vec())))
+        }
+        _ => Err(Error::InvalidContainer),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::language::Language;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    fn exported(contents: &Contents) -> Vec<u8> {
+        let params = Argon2Params::new(64, 1, 1).unwrap();
+
+        export_with_rng(&mut StdRng::seed_from_u64(0), contents, "hunter2", &params).unwrap()
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn roundtrip() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let seed = mnemonic.to_seed("TREZOR");
+
+        for contents in [Contents::Mnemonic(mnemonic), Contents::Seed(seed)] {
+            let backup = exported(&contents);
+
+            assert_eq!(&backup[..6], b"B39C\x01\x01");
+            assert_eq!(import(&backup, "hunter2"), Ok(contents));
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn errors() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let backup = exported(&Contents::Mnemonic(mnemonic.clone()));
+
+        assert_eq!(import(&backup, "hunter3"), Err(Error::InvalidPassphrase));
+
+        let mut altered = backup.clone();
+        *altered.last_mut().unwrap() ^= 1;
+        assert_eq!(import(&altered, "hunter2"), Err(Error::InvalidPassphrase));
+
+        let mut newer = backup.clone();
+        newer[4] = 2;
+        assert_eq!(import(&newer, "hunter2"), Err(Error::UnsupportedVersion(2)));
+
+        // A sealed mnemonic isn't a backup container, nor the other way around
+        let params = Argon2Params::new(64, 1, 1).unwrap();
+        let sealed = mnemonic.seal_with_rng(&mut StdRng::seed_from_u64(0), "hunter2", &params);
+        assert_eq!(
+            import(&sealed.unwrap(), "hunter2"),
+            Err(Error::InvalidContainer)
+        );
+        assert_eq!(
+            Mnemonic::unseal(&backup, "hunter2"),
+            Err(Error::InvalidSealedMnemonic)
+        );
+
+        assert_eq!(import(b"not a backup", ""), Err(Error::InvalidContainer));
+    }
+}
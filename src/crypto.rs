@@ -1,44 +1,528 @@
 //! These are internal helper functions used when creating a new [`Mnemonic`][Mnemonic], and when turning a [`Mnemonic`][Mnemonic]
 //! into a [`Seed`][Seed].
 //!
+//! The SHA-256 of the checksum, the PBKDF2 of the seed and the HMACs of key derivation go through
+//! the [`Digest`][Digest] and [`Kdf`][Kdf] traits, so that another implementation can be passed
+//! to the functions taking a [`CryptoBackend`][CryptoBackend].
+//!
 //! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 //! [Seed]: ../seed/struct.Seed.html
+//! [Digest]: ./trait.Digest.html
+//! [Kdf]: ./trait.Kdf.html
+//! [CryptoBackend]: ./trait.CryptoBackend.html
 //!
 
-use hmac::Hmac;
+use crate::error::{Error, RngError};
+use crate::seed::{SeedDerivationParams, SeedHmac, SeedKdf};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::num::NonZeroU32;
+use hmac::{Hmac, Mac};
 #[cfg(feature = "rand")]
-use rand::{thread_rng, RngCore};
-use sha2::Digest;
+use rand_core::OsRng;
+use rand_core::{CryptoRng, RngCore};
+use sha2::Digest as _;
+use zeroize::{Zeroize, Zeroizing};
 
-const PBKDF2_ROUNDS: u32 = 2048;
+pub(crate) const PBKDF2_ROUNDS: NonZeroU32 = match NonZeroU32::new(2048) {
+    Some(rounds) => rounds,
+    None => unreachable!(),
+};
 const PBKDF2_BYTES: usize = 64;
+pub(crate) const SEED_BYTES: usize = PBKDF2_BYTES;
+
+/// SHA-256, used for the checksum of mnemonics
+///
+/// See [`CryptoBackend`][CryptoBackend] for using another implementation than the one of this
+/// crate.
+///
+/// [CryptoBackend]: ./trait.CryptoBackend.html
+pub trait Digest: Send + Sync {
+    /// The SHA-256 hash of `input`
+    fn sha256(&self, input: &[u8]) -> [u8; 32];
+}
+
+/// PBKDF2, used to derive seeds from mnemonics, and HMAC-SHA512, used to derive keys from seeds
+///
+/// See [`CryptoBackend`][CryptoBackend] for using another implementation than the one of this
+/// crate.
+///
+/// [CryptoBackend]: ./trait.CryptoBackend.html
+pub trait Kdf: Send + Sync {
+    /// Fill `out` with PBKDF2 of `password` and `salt`, running `rounds` rounds of HMAC with
+    /// `hmac` for each block
+    fn pbkdf2(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+    );
+
+    /// The same as [`Kdf::pbkdf2()`][Kdf::pbkdf2()], calling `progress` with the number of
+    /// rounds done and the total number of rounds as it goes
+    ///
+    /// Unless it's implemented, `progress` is only called once, after the last round.
+    ///
+    /// [Kdf::pbkdf2()]: ./trait.Kdf.html#tymethod.pbkdf2
+    fn pbkdf2_with_progress(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+        progress: &mut dyn FnMut(u64, u64),
+    ) {
+        self.pbkdf2(hmac, password, salt, rounds, out);
+
+        let block_len = match hmac {
+            SeedHmac::Sha512 => 64,
+            SeedHmac::Sha256 => 32,
+        };
+        let total = u64::from(rounds.get()) * out.len().div_ceil(block_len) as u64;
+        progress(total, total);
+    }
+
+    /// Fill `seed` with the BIP39 seed of `phrase` and `passphrase`: PBKDF2-HMAC-SHA512 with
+    /// 2048 rounds, salted with `"mnemonic"` followed by `passphrase`
+    ///
+    /// This is what the allocation-free functions of [`fixed`][fixed] use, as the salt is never
+    /// put together in a buffer. Unless it's implemented, every round goes through
+    /// [`Kdf::hmac_sha512()`][Kdf::hmac_sha512()].
+    ///
+    /// [fixed]: ./fixed/index.html
+    /// [Kdf::hmac_sha512()]: ./trait.Kdf.html#method.hmac_sha512
+    fn bip39_seed(&self, phrase: &[u8], passphrase: &[u8], seed: &mut [u8; 64]) {
+        let salt = [&b"mnemonic"[..], passphrase, &1u32.to_be_bytes()];
+
+        let mut block = Zeroizing::new(self.hmac_sha512(phrase, &salt));
+        seed.copy_from_slice(&block[..]);
+
+        for _ in 1..PBKDF2_ROUNDS.get() {
+            *block = self.hmac_sha512(phrase, &[&block[..]]);
+
+            for (byte, block_byte) in seed.iter_mut().zip(block.iter()) {
+                *byte ^= block_byte;
+            }
+        }
+    }
+
+    /// HMAC-SHA512 of the concatenated `data` pieces with `key`, used by BIP32, SLIP-0010,
+    /// SLIP-0021 and BIP85
+    ///
+    /// Unless it's implemented, this is the HMAC of RustCrypto.
+    fn hmac_sha512(&self, key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+        let mut mac =
+            Hmac::<sha2::Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+        for piece in data {
+            mac.update(piece);
+        }
+
+        mac.finalize().into_bytes().into()
+    }
+}
+
+/// A [`Digest`][Digest] and [`Kdf`][Kdf] together, implemented for any type that is both
+///
+/// The functions ending in `_with_backend`, such as
+/// [`Mnemonic::from_phrase_with_backend()`][from_phrase_with_backend] and
+/// [`Seed::new_with_backend()`][new_with_backend], use the backend they're passed instead of
+/// [`DefaultCrypto`][DefaultCrypto], for instance a hardware accelerator or a certified library.
+/// Every other function uses `DefaultCrypto`.
+///
+/// A backend that doesn't compute SHA-256, PBKDF2 and HMAC exactly gives other mnemonics, seeds
+/// and keys than every other wallet, check it against [`test_vectors`][test_vectors] first.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{DefaultCrypto, Digest, Kdf, Language, Mnemonic, Seed, SeedDerivationParams, SeedHmac};
+/// use std::num::NonZeroU32;
+///
+/// struct Accelerator;
+///
+/// impl Digest for Accelerator {
+///     fn sha256(&self, input: &[u8]) -> [u8; 32] {
+///         DefaultCrypto.sha256(input)
+///     }
+/// }
+///
+/// impl Kdf for Accelerator {
+///     fn pbkdf2(
+///         &self,
+///         hmac: SeedHmac,
+///         password: &[u8],
+///         salt: &[u8],
+///         rounds: NonZeroU32,
+///         out: &mut [u8],
+///     ) {
+///         DefaultCrypto.pbkdf2(hmac, password, salt, rounds, out)
+///     }
+/// }
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase_with_backend(phrase, Language::English, &Accelerator).unwrap();
+/// let params = SeedDerivationParams::bip39();
+///
+/// assert_eq!(Seed::new_with_backend(&mnemonic, "", &params, &Accelerator), Seed::new(&mnemonic, ""));
+/// ```
+///
+/// [Digest]: ./trait.Digest.html
+/// [Kdf]: ./trait.Kdf.html
+/// [DefaultCrypto]: ./struct.DefaultCrypto.html
+/// [from_phrase_with_backend]: ./mnemonic/struct.Mnemonic.html#method.from_phrase_with_backend
+/// [new_with_backend]: ./seed/struct.Seed.html#method.new_with_backend
+/// [test_vectors]: ./test_vectors/index.html
+pub trait CryptoBackend: Digest + Kdf {}
+
+impl<T: Digest + Kdf> CryptoBackend for T {}
+
+/// The implementations of this crate: SHA-256 and PBKDF2 of RustCrypto, or the PBKDF2 of ring
+/// with the `ring` feature
+///
+/// ring can't report its progress, nor take the salt in pieces, so
+/// [`Kdf::pbkdf2_with_progress()`][pbkdf2_with_progress] and the allocation-free
+/// [`Kdf::bip39_seed()`][bip39_seed] always use RustCrypto.
+///
+/// [pbkdf2_with_progress]: ./trait.Kdf.html#method.pbkdf2_with_progress
+/// [bip39_seed]: ./trait.Kdf.html#method.bip39_seed
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultCrypto;
+
+impl Digest for DefaultCrypto {
+    fn sha256(&self, input: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(input).into()
+    }
+}
+
+impl Kdf for DefaultCrypto {
+    #[cfg(not(feature = "ring"))]
+    fn pbkdf2(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+    ) {
+        match hmac {
+            SeedHmac::Sha512 => {
+                pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(password, salt, rounds.get(), out)
+            }
+            SeedHmac::Sha256 => {
+                pbkdf2::pbkdf2::<Hmac<sha2::Sha256>>(password, salt, rounds.get(), out)
+            }
+        }
+    }
+
+    /// ring has assembly SHA-256 and SHA-512 for most targets
+    #[cfg(feature = "ring")]
+    fn pbkdf2(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+    ) {
+        let algorithm = match hmac {
+            SeedHmac::Sha512 => ring::pbkdf2::PBKDF2_HMAC_SHA512,
+            SeedHmac::Sha256 => ring::pbkdf2::PBKDF2_HMAC_SHA256,
+        };
+        ring::pbkdf2::derive(algorithm, rounds, salt, password, out);
+    }
+
+    fn pbkdf2_with_progress(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+        progress: &mut dyn FnMut(u64, u64),
+    ) {
+        match hmac {
+            SeedHmac::Sha512 => {
+                pbkdf2_blocks::<Hmac<sha2::Sha512>>(password, salt, rounds, out, progress)
+            }
+            SeedHmac::Sha256 => {
+                pbkdf2_blocks::<Hmac<sha2::Sha256>>(password, salt, rounds, out, progress)
+            }
+        }
+    }
+
+    /// The key of HMAC is only set up once, rather than for every round
+    fn bip39_seed(&self, phrase: &[u8], passphrase: &[u8], seed: &mut [u8; 64]) {
+        let prf =
+            Hmac::<sha2::Sha512>::new_from_slice(phrase).expect("HMAC accepts keys of any size");
+
+        let mut mac = prf.clone();
+        mac.update(b"mnemonic");
+        mac.update(passphrase);
+        mac.update(&1u32.to_be_bytes());
+
+        let mut block = mac.finalize().into_bytes();
+        seed.copy_from_slice(&block);
+
+        for _ in 1..PBKDF2_ROUNDS.get() {
+            let mut mac = prf.clone();
+            mac.update(&block);
+            block = mac.finalize().into_bytes();
+
+            for (byte, block_byte) in seed.iter_mut().zip(block.iter()) {
+                *byte ^= block_byte;
+            }
+        }
+
+        block.as_mut_slice().zeroize();
+    }
+}
 
 /// SHA256 helper function, internal to the crate
 ///
-pub(crate) fn sha256_first_byte(input: &[u8]) -> u8 {
-    sha2::Sha256::digest(input)[0]
+pub(crate) fn sha256_first_byte<B: Digest + ?Sized>(backend: &B, input: &[u8]) -> u8 {
+    backend.sha256(input)[0]
 }
 
 /// Random byte generator, used to create new mnemonics
 ///
+/// A failing `rng` is reported as an error, the bytes are never used partially filled.
+pub(crate) fn gen_random_bytes<R>(
+    rng: &mut R,
+    byte_length: usize,
+) -> Result<Zeroizing<Vec<u8>>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let mut bytes = Zeroizing::new(vec![0u8; byte_length]);
+
+    rng.try_fill_bytes(&mut bytes)
+        .map_err(|err| Error::RandomSource(RngError::new(err.code())))?;
+
+    Ok(bytes)
+}
+
+/// Random byte generator backed by the operating system CSPRNG, used by `Mnemonic::new`
+///
+/// This goes straight to `getrandom`, there is no userspace RNG in between that could be left
+/// unseeded or share state across forks.
 #[cfg(feature = "rand")]
-pub(crate) fn gen_random_bytes(byte_length: usize) -> Vec<u8> {
-    let mut rng = thread_rng();
-    let mut bytes = vec![0u8; byte_length];
+pub(crate) fn gen_os_random_bytes(byte_length: usize) -> Result<Zeroizing<Vec<u8>>, Error> {
+    gen_random_bytes(&mut OsRng, byte_length)
+}
+/// Run the key derivation function `kdf`, used to generate [`Seed`][Seed] from
+/// [`Mnemonic`][Mnemonic]
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Seed]: ../seed/struct.Seed.html
+pub(crate) fn derive_seed<B: Kdf + ?Sized>(
+    backend: &B,
+    input: &[u8],
+    salt: &str,
+    kdf: &SeedKdf,
+) -> Vec<u8> {
+    match kdf {
+        SeedKdf::Pbkdf2(params) => pbkdf2(backend, input, salt, params),
+        #[cfg(feature = "scrypt")]
+        SeedKdf::Scrypt(params) => {
+            let params = scrypt::Params::new(params.log_n, params.r, params.p, SEED_BYTES)
+                .expect("scrypt parameters are validated by `ScryptParams::new`");
+            let mut seed = vec![0u8; SEED_BYTES];
+
+            scrypt::scrypt(input, salt.as_bytes(), &params, &mut seed)
+                .expect("the seed length is valid for scrypt");
 
-    rng.fill_bytes(&mut bytes);
+            seed
+        }
+        #[cfg(feature = "argon2")]
+        SeedKdf::Argon2id(params) => {
+            use argon2::{Algorithm, Argon2, Version};
 
-    bytes
+            let params = argon2::Params::new(
+                params.m_cost,
+                params.t_cost,
+                params.p_cost,
+                Some(SEED_BYTES),
+            )
+            .expect("Argon2 parameters are validated by `Argon2Params::new`");
+            let mut seed = vec![0u8; SEED_BYTES];
+
+            // The salt is at least the 8 bytes of "mnemonic", the minimum Argon2 accepts
+            Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(input, salt.as_bytes(), &mut seed)
+                .expect("the salt and seed lengths are valid for Argon2");
+
+            seed
+        }
+    }
 }
+
 /// PBKDF2 helper, used to generate [`Seed`][Seed] from [`Mnemonic`][Mnemonic]
 ///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
 ///
-pub(crate) fn pbkdf2(input: &[u8], salt: &str) -> Vec<u8> {
+pub(crate) fn pbkdf2<B: Kdf + ?Sized>(
+    backend: &B,
+    input: &[u8],
+    salt: &str,
+    params: &SeedDerivationParams,
+) -> Vec<u8> {
+    let mut seed = vec![0u8; PBKDF2_BYTES];
+
+    backend.pbkdf2(
+        params.hmac,
+        input,
+        salt.as_bytes(),
+        params.rounds,
+        &mut seed,
+    );
+
+    seed
+}
+
+/// PBKDF2-HMAC-SHA512 helper with any number of rounds and output length, used by the Cardano
+/// Icarus derivation
+#[cfg(feature = "cardano")]
+pub(crate) fn pbkdf2_sha512(input: &[u8], salt: &[u8], rounds: NonZeroU32, out: &mut [u8]) {
+    DefaultCrypto.pbkdf2(SeedHmac::Sha512, input, salt, rounds, out);
+}
+
+/// PBKDF2-HMAC-SHA256 helper with any number of rounds and output length, used by the rounds of
+/// the SLIP-0039 encryption and by keystore files
+#[cfg(any(feature = "slip39", feature = "keystore"))]
+pub(crate) fn pbkdf2_sha256(input: &[u8], salt: &[u8], rounds: NonZeroU32, out: &mut [u8]) {
+    DefaultCrypto.pbkdf2(SeedHmac::Sha256, input, salt, rounds, out);
+}
+
+/// Report the progress of PBKDF2 after this many rounds, and after the last one
+const PROGRESS_ROUNDS: u32 = 1024;
+
+/// PBKDF2 helper calling `progress` with the number of rounds done and the total number of
+/// rounds as it goes, used to generate [`Seed`][Seed] with a progress bar
+///
+/// With SHA256, the 64 byte seed is two blocks, which both count towards the total.
+///
+/// [Seed]: ../seed/struct.Seed.html
+pub(crate) fn pbkdf2_with_progress<B: Kdf + ?Sized>(
+    backend: &B,
+    input: &[u8],
+    salt: &str,
+    params: &SeedDerivationParams,
+    progress: &mut dyn FnMut(u64, u64),
+) -> Vec<u8> {
     let mut seed = vec![0u8; PBKDF2_BYTES];
 
-    pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(input, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+    backend.pbkdf2_with_progress(
+        params.hmac,
+        input,
+        salt.as_bytes(),
+        params.rounds,
+        &mut seed,
+        progress,
+    );
 
     seed
 }
+
+/// PBKDF2 with the pseudorandom function `M`, one block of `out` after the other
+fn pbkdf2_blocks<M: Mac + hmac::digest::KeyInit + Clone>(
+    input: &[u8],
+    salt: &[u8],
+    rounds: NonZeroU32,
+    out: &mut [u8],
+    progress: &mut dyn FnMut(u64, u64),
+) {
+    let rounds = rounds.get();
+    let prf = <M as Mac>::new_from_slice(input).expect("HMAC accepts keys of any size");
+    let block_len = <M as hmac::digest::OutputSizeUser>::output_size();
+
+    let total = u64::from(rounds) * out.len().div_ceil(block_len) as u64;
+    let mut done = 0;
+
+    for (index, chunk) in out.chunks_mut(block_len).enumerate() {
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&(index as u32 + 1).to_be_bytes());
+
+        let mut block = mac.finalize().into_bytes();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+
+        for round in 1..=rounds {
+            if round > 1 {
+                let mut mac = prf.clone();
+                mac.update(&block);
+                block = mac.finalize().into_bytes();
+
+                for (byte, block_byte) in chunk.iter_mut().zip(block.iter()) {
+                    *byte ^= block_byte;
+                }
+            }
+
+            done += 1;
+            if round % PROGRESS_ROUNDS == 0 || round == rounds {
+                progress(done, total);
+            }
+        }
+
+        block.as_mut_slice().zeroize();
+    }
+}
+
+/// The rounds run to time PBKDF2, doubled until a run takes long enough to measure
+#[cfg(feature = "std")]
+const CALIBRATION_ROUNDS: NonZeroU32 = match NonZeroU32::new(1024) {
+    Some(rounds) => rounds,
+    None => unreachable!(),
+};
+
+/// The shortest run timed, long enough for the clock to be accurate
+#[cfg(feature = "std")]
+const CALIBRATION_TIME: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Find the number of PBKDF2-HMAC-SHA512 rounds that take about `target` to run on this machine
+///
+/// PBKDF2 is timed with growing numbers of rounds until a run takes long enough to measure, and
+/// the rounds are scaled from there, so this takes about as long as the shortest run measured.
+/// The result is at least the 2048 rounds of BIP39. It's meant for
+/// [`SeedDerivationParams::rounds()`][rounds] when the seed protects a secret of the
+/// application, and doesn't have to match other BIP39 wallets. The rounds must be stored along
+/// with the secret, since they depend on the machine they were calibrated on.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{calibrate_kdf, Language, Mnemonic, SeedDerivationParams};
+/// use std::time::Duration;
+///
+/// let rounds = calibrate_kdf(Duration::from_millis(50));
+/// let params = SeedDerivationParams::bip39().rounds(rounds);
+///
+/// let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+/// let seed = mnemonic.to_seed_with_params("", &params);
+/// ```
+///
+/// [rounds]: ./struct.SeedDerivationParams.html#method.rounds
+#[cfg(feature = "std")]
+pub fn calibrate_kdf(target: std::time::Duration) -> NonZeroU32 {
+    let mut rounds = CALIBRATION_ROUNDS;
+
+    loop {
+        let params = SeedDerivationParams::bip39().rounds(rounds);
+
+        let start = std::time::Instant::now();
+        pbkdf2(&DefaultCrypto, b"calibration", "mnemonic", &params);
+        let elapsed = start.elapsed();
+
+        if elapsed >= CALIBRATION_TIME.min(target) || rounds == NonZeroU32::MAX {
+            let scaled = f64::from(rounds.get()) * target.as_secs_f64() / elapsed.as_secs_f64();
+
+            // `as` saturates, and a zero `elapsed` gives an infinite `scaled`
+            return NonZeroU32::new(scaled as u32).map_or(PBKDF2_ROUNDS, |n| n.max(PBKDF2_ROUNDS));
+        }
+
+        rounds = rounds.saturating_add(rounds.get());
+    }
+}
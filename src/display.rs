@@ -0,0 +1,166 @@
+use crate::mnemonic::Mnemonic;
+use crate::util::is_word_separator;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Formats the phrase of a [`Mnemonic`][Mnemonic] for printing on a backup sheet, see
+/// [`Mnemonic::display()`][Mnemonic::display()]
+///
+/// By default it's the phrase itself. With [`PhraseDisplay::numbered()`][numbered], each word
+/// gets its position, starting from 1, and with [`PhraseDisplay::columns()`][columns], the words
+/// are laid out in columns, read from top to bottom, one row per line, so the first column of 12
+/// words in 2 columns holds words 1 to 6.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, Language};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+///
+/// let sheet = mnemonic.display().numbered().columns(2).to_string();
+///
+/// assert_eq!(sheet.lines().next(), Some(" 1. park       7. knee"));
+/// assert_eq!(sheet.lines().count(), 6);
+/// ```
+///
+/// Columns are aligned by counting characters, which lines them up in a monospace font for every
+/// language but Chinese and Japanese, whose characters are twice as wide.
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [Mnemonic::display()]: ./mnemonic/struct.Mnemonic.html#method.display
+/// [numbered]: ./struct.PhraseDisplay.html#method.numbered
+/// [columns]: ./struct.PhraseDisplay.html#method.columns
+#[derive(Clone, Copy)]
+pub struct PhraseDisplay<'a> {
+    mnemonic: &'a Mnemonic,
+    numbered: bool,
+    columns: Option<usize>,
+}
+
+impl<'a> PhraseDisplay<'a> {
+    pub(crate) fn new(mnemonic: &'a Mnemonic) -> PhraseDisplay<'a> {
+        PhraseDisplay {
+            mnemonic,
+            numbered: false,
+            columns: None,
+        }
+    }
+
+    /// Put the position of each word in front of it, as in `1. park`
+    pub fn numbered(mut self) -> Self {
+        self.numbered = true;
+        self
+    }
+
+    /// Lay the words out in `columns` aligned columns, at least one
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Write the word at `index`, after its position if numbered
+    fn write_word(&self, f: &mut fmt::Formatter, index: usize, word: &str) -> fmt::Result {
+        if self.numbered {
+            write!(f, "{:>2}. ", index + 1)?;
+        }
+
+        f.write_str(word)
+    }
+}
+
+impl fmt::Display for PhraseDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words: Vec<&str> = self.mnemonic.phrase().split(is_word_separator).collect();
+
+        let columns = match self.columns {
+            Some(columns) => columns,
+            None => {
+                for (index, word) in words.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(self.mnemonic.language().separator())?;
+                    }
+                    self.write_word(f, index, word)?;
+                }
+
+                return Ok(());
+            }
+        };
+
+        let rows = words.len().div_ceil(columns);
+        let width = words
+            .iter()
+            .map(|word| word.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for row in 0..rows {
+            if row > 0 {
+                f.write_str("\n")?;
+            }
+
+            for index in (row..words.len()).step_by(rows) {
+                let word = words[index];
+                self.write_word(f, index, word)?;
+
+                if index + rows < words.len() {
+                    let padding = width - word.chars().count() + 2;
+                    write!(f, "{:padding$}", "", padding = padding)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The phrase is a secret, so it's never printed, format the `PhraseDisplay` with `Display` to
+/// get it explicitly.
+impl fmt::Debug for PhraseDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("PhraseDisplay(****)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::language::Language;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn phrase_display() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+
+        assert_eq!(mnemonic.display().to_string(), PHRASE);
+        assert!(mnemonic
+            .display()
+            .numbered()
+            .to_string()
+            .starts_with(" 1. park  2. remain"));
+        assert_eq!(
+            mnemonic.display().columns(3).to_string(),
+            "park      mule      position\n\
+             remain    spell     rail\n\
+             person    knee      grid\n\
+             kitchen   armed     ankle",
+        );
+        assert_eq!(
+            mnemonic.display().numbered().columns(5).to_string(),
+            " 1. park       4. kitchen    7. knee      10. rail\n \
+              2. remain     5. mule       8. armed     11. grid\n \
+              3. person     6. spell      9. position  12. ankle",
+        );
+        assert_eq!(
+            mnemonic.display().columns(0).to_string().lines().count(),
+            12
+        );
+        assert_eq!(format!("{:?}", mnemonic.display()), "PhraseDisplay(****)");
+    }
+}
@@ -0,0 +1,289 @@
+//! Electrum seed phrases, enabled with the `electrum` feature
+//!
+//! Electrum uses the same English word list as BIP39, but its phrases have no checksum. Instead,
+//! the HMAC-SHA512 of the phrase, keyed with `"Seed version"`, must start with the version prefix
+//! of the [`SeedType`][SeedType]. The seed is derived like a BIP39 seed, except the salt starts
+//! with `"electrum"` instead of `"mnemonic"`, and both the phrase and the passphrase are
+//! normalized the way Electrum does: lowercased, without accents and with single spaces.
+//!
+//! ```
+//! use bip39::electrum::{self, SeedType};
+//!
+//! let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+//!
+//! assert_eq!(electrum::seed_type(phrase), Some(SeedType::Segwit));
+//!
+//! let seed = electrum::to_seed(phrase, "").unwrap();
+//! ```
+//!
+//! Only the version based seeds of Electrum 2.0 and later are supported, not the older ones.
+//!
+//! [SeedType]: ./enum.SeedType.html
+
+#[cfg(feature = "rand")]
+use crate::crypto::gen_os_random_bytes;
+use crate::crypto::{gen_random_bytes, pbkdf2, DefaultCrypto};
+use crate::error::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::seed::{Seed, SeedDerivationParams};
+use crate::util::{reserve_zeroizing, Bits11, IterExt};
+use alloc::string::String;
+use hmac::{Hmac, Mac};
+use rand_core::{CryptoRng, RngCore};
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+/// The number of words of a generated phrase, for 132 bits of entropy, as in Electrum
+const WORD_COUNT: usize = 12;
+
+/// The kind of wallet an Electrum seed is for, encoded in its version prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeedType {
+    /// Legacy P2PKH wallets, version prefix `01`
+    Standard,
+    /// Native SegWit wallets, version prefix `100`
+    Segwit,
+    /// Two-factor authenticated wallets, version prefix `101`
+    TwoFactor,
+    /// Two-factor authenticated SegWit wallets, version prefix `102`
+    TwoFactorSegwit,
+}
+
+impl SeedType {
+    const ALL: [SeedType; 4] = [
+        SeedType::Standard,
+        SeedType::Segwit,
+        SeedType::TwoFactor,
+        SeedType::TwoFactorSegwit,
+    ];
+
+    /// The version prefix, in hex, that the HMAC of the phrase starts with
+    pub fn prefix(&self) -> &'static str {
+        match *self {
+            SeedType::Standard => "01",
+            SeedType::Segwit => "100",
+            SeedType::TwoFactor => "101",
+            SeedType::TwoFactorSegwit => "102",
+        }
+    }
+
+    fn matches(&self, version: &[u8]) -> bool {
+        let hex = self.prefix().bytes().map(|c| c - b'0');
+        let nibbles = version.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]);
+
+        hex.zip(nibbles)
+            .all(|(expected, nibble)| expected == nibble)
+    }
+}
+
+/// Get the type of an Electrum seed phrase, or `None` if it isn't one
+///
+/// The phrase is normalized first, so neither case, accents or extra whitespace matter. A phrase
+/// can be both a valid Electrum seed and a valid BIP39 mnemonic, though Electrum never generates
+/// such phrases.
+pub fn seed_type(phrase: &str) -> Option<SeedType> {
+    let normalized = normalize(phrase);
+
+    let mut mac = Hmac::<sha2::Sha512>::new_from_slice(b"Seed version")
+        .expect("HMAC accepts keys of any size");
+    mac.update(normalized.as_bytes());
+    let version = mac.finalize().into_bytes();
+
+    SeedType::ALL
+        .iter()
+        .copied()
+        .find(|seed_type| seed_type.matches(&version))
+}
+
+/// Derive the seed of an Electrum seed phrase
+///
+/// Returns `Error::InvalidChecksum` if the phrase isn't an Electrum seed, since the version prefix
+/// is what Electrum has instead of a checksum.
+pub fn to_seed(phrase: &str, passphrase: &str) -> Result<Seed, Error> {
+    if seed_type(phrase).is_none() {
+        return Err(Error::InvalidChecksum);
+    }
+
+    let params = SeedDerivationParams::bip39().salt_prefix("electrum");
+
+    let normalized_phrase = normalize(phrase);
+
+    let mut salt = Zeroizing::new(String::from(params.salt_prefix));
+    salt.push_str(&normalize(passphrase));
+
+    let bytes = pbkdf2(&DefaultCrypto, normalized_phrase.as_bytes(), &salt, &params);
+
+    Ok(Seed::from_bytes(bytes))
+}
+
+/// Generate a new 12 word Electrum seed phrase of `seed_type`, using the operating system CSPRNG
+#[cfg(feature = "rand")]
+pub fn generate(seed_type: SeedType) -> Result<Zeroizing<String>, Error> {
+    let entropy = gen_os_random_bytes(17)?;
+
+    Ok(generate_from(&entropy, seed_type))
+}
+
+/// Generate a new 12 word Electrum seed phrase of `seed_type`, using entropy from `rng`
+pub fn generate_with_rng<R>(rng: &mut R, seed_type: SeedType) -> Result<Zeroizing<String>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let entropy = gen_random_bytes(rng, 17)?;
+
+    Ok(generate_from(&entropy, seed_type))
+}
+
+/// Like Electrum, count up from the random words until the phrase has the right version prefix,
+/// skipping phrases that are valid BIP39 mnemonics as well
+fn generate_from(entropy: &[u8], seed_type: SeedType) -> Zeroizing<String> {
+    let wordlist = Language::English.wordlist();
+
+    let mut indices = Zeroizing::new([0u16; WORD_COUNT]);
+    for (index, bits) in indices.iter_mut().zip(entropy.iter().bits::<Bits11>()) {
+        *index = bits.into();
+    }
+
+    loop {
+        let phrase: Zeroizing<String> = Zeroizing::new(
+            indices
+                .iter()
+                .map(|&index| wordlist.get_word(index.into()))
+                .join(" "),
+        );
+
+        let is_bip39 = Mnemonic::validate(&phrase, Language::English).is_ok();
+
+        if !is_bip39 && self::seed_type(&phrase) == Some(seed_type) {
+            return phrase;
+        }
+
+        // Electrum's words are the digits of a little endian base 2048 number
+        for index in indices.iter_mut() {
+            *index = (*index + 1) % 2048;
+
+            if *index != 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// Normalize text the way Electrum does: NFKD, lowercase, without combining marks, with single
+/// spaces between words and no spaces between CJK characters
+fn normalize(text: &str) -> Zeroizing<String> {
+    let mut stripped = Zeroizing::new(String::new());
+
+    let chars = text
+        .nfkd()
+        .flat_map(char::to_lowercase)
+        .filter(|&c| canonical_combining_class(c) == 0);
+
+    for c in chars {
+        reserve_zeroizing(&mut stripped, c.len_utf8());
+        stripped.push(c);
+    }
+
+    let mut normalized = Zeroizing::new(String::with_capacity(stripped.len()));
+    let mut last: Option<char> = None;
+    let mut pending_space = false;
+
+    for c in stripped.chars() {
+        if c.is_whitespace() {
+            pending_space = last.is_some();
+            continue;
+        }
+
+        if pending_space && !(last.is_some_and(is_cjk) && is_cjk(c)) {
+            normalized.push(' ');
+        }
+
+        normalized.push(c);
+        last = Some(c);
+        pending_space = false;
+    }
+
+    normalized
+}
+
+/// The main CJK blocks, Electrum has a longer list of rarely used ones
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x11FF
+            | 0x2E80..=0x2FDF
+            | 0x2FF0..=0x31FF
+            | 0x3200..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA960..=0xA97F
+            | 0xAC00..=0xD7FF
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFFEF
+            | 0x20000..=0x2FA1F
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    // Test vectors from Electrum's `tests/test_mnemonic.py`
+    const PHRASE: &str =
+        "wild father tree among universe such mobile favorite target dynamic credit identify";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn electrum_seed() {
+        assert_eq!(seed_type(PHRASE), Some(SeedType::Segwit));
+
+        let seed = to_seed(PHRASE, "").unwrap();
+        assert_eq!(format!("{:x}", seed), "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756");
+
+        let seed = to_seed(
+            PHRASE,
+            "Did you ever hear the tragedy of Darth Plagueis the Wise?",
+        );
+        assert_eq!(format!("{:x}", seed.unwrap()), "4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn normalization() {
+        assert_eq!(&normalize("  Wild\tFATHER  tree ")[..], "wild father tree");
+        assert_eq!(&normalize("Café")[..], "cafe");
+        assert_eq!(&normalize("漢 字 and 漢字")[..], "漢字 and 漢字");
+
+        let messy = PHRASE.to_uppercase().replace(' ', "   ");
+        assert_eq!(seed_type(&messy), Some(SeedType::Segwit));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn not_electrum() {
+        let bip39 = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+        assert_eq!(seed_type(bip39), None);
+        assert_eq!(to_seed(bip39, "").map(|_| ()), Err(Error::InvalidChecksum));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for seed_type in SeedType::ALL {
+            let phrase = generate_with_rng(&mut rng, seed_type).unwrap();
+
+            assert_eq!(phrase.split(' ').count(), 12);
+            assert_eq!(self::seed_type(&phrase), Some(seed_type));
+            assert!(Mnemonic::validate(&phrase, Language::English).is_err());
+        }
+    }
+}
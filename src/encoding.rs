@@ -0,0 +1,481 @@
+//! Base58Check and bech32, the text encodings of Bitcoin keys and addresses, and the hex and
+//! base64 of interchange files
+
+use crate::error::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use sha2::Digest;
+use zeroize::Zeroizing;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The number of checksum characters at the end of a bech32 string
+const BECH32_CHECKSUM_LEN: usize = 6;
+
+/// Encode `version` followed by `payload` with Base58Check, as in Bitcoin addresses and `xprv`s
+pub(crate) fn to_base58check(version: &[u8], payload: &[u8]) -> Zeroizing<String> {
+    let mut bytes = Zeroizing::new(Vec::with_capacity(version.len() + payload.len() + 4));
+    bytes.extend_from_slice(version);
+    bytes.extend_from_slice(payload);
+
+    let checksum = sha256d(&bytes);
+    bytes.extend_from_slice(&checksum[..4]);
+
+    base58(&bytes)
+}
+
+/// Decode a Base58Check `string` that starts with `version`, and return the payload after it
+///
+/// Returns `Error::InvalidEncoding` if `string` isn't Base58, or the version or checksum don't
+/// match.
+pub(crate) fn from_base58check(string: &str, version: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let bytes = from_base58(string)?;
+
+    if bytes.len() < version.len() + 4 || !bytes.starts_with(version) {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let (data, checksum) = bytes.split_at(bytes.len() - 4);
+    if sha256d(data)[..4] != *checksum {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(Zeroizing::new(data[version.len()..].to_vec()))
+}
+
+/// Encode `data` with bech32, as in BIP173, under the human readable part `hrp`
+///
+/// The output is lowercase. BIP173 limits strings to 90 characters, which 64 byte seeds don't fit
+/// in, so there's no limit on the length. Returns `Error::InvalidEncoding` if `hrp` is empty, or
+/// has characters outside of the printable ASCII range or uppercase ones.
+pub(crate) fn to_bech32(hrp: &str, data: &[u8]) -> Result<Zeroizing<String>, Error> {
+    if hrp.is_empty() || !hrp.bytes().all(|byte| (33..=126).contains(&byte)) {
+        return Err(Error::InvalidEncoding);
+    }
+    if hrp.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let mut values = Zeroizing::new(Vec::with_capacity((data.len() * 8).div_ceil(5)));
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    for &byte in data {
+        acc = (acc << 8 | byte as u32) & 0xfff;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            values.push((acc >> bits & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        values.push((acc << (5 - bits) & 0x1f) as u8);
+    }
+
+    let checksum = bech32_polymod(hrp, &values, &[0; BECH32_CHECKSUM_LEN]) ^ 1;
+
+    let len = hrp.len() + 1 + values.len() + BECH32_CHECKSUM_LEN;
+    let mut encoded = Zeroizing::new(String::with_capacity(len));
+    encoded.push_str(hrp);
+    encoded.push('1');
+    encoded.extend(
+        values
+            .iter()
+            .map(|&value| BECH32_CHARSET[value as usize] as char),
+    );
+    encoded.extend((0..BECH32_CHECKSUM_LEN).map(|i| {
+        BECH32_CHARSET[(checksum >> (5 * (BECH32_CHECKSUM_LEN - 1 - i)) & 0x1f) as usize] as char
+    }));
+
+    Ok(encoded)
+}
+
+/// Decode a bech32 `string` with the human readable part `hrp`, and return its data
+///
+/// Both all lowercase and all uppercase strings are accepted. Returns `Error::InvalidEncoding` if
+/// `string` isn't bech32, its checksum or padding is invalid, or it has another human readable
+/// part.
+pub(crate) fn from_bech32(string: &str, hrp: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let has_lower = string.bytes().any(|byte| byte.is_ascii_lowercase());
+    let has_upper = string.bytes().any(|byte| byte.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let string = Zeroizing::new(string.to_ascii_lowercase());
+    let separator = string.rfind('1').ok_or(Error::InvalidEncoding)?;
+    let (string_hrp, rest) = (&string[..separator], &string[separator + 1..]);
+
+    if string_hrp != hrp || rest.len() < BECH32_CHECKSUM_LEN {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let values = rest
+        .bytes()
+        .map(|byte| {
+            BECH32_CHARSET
+                .iter()
+                .position(|&c| c == byte)
+                .map(|value| value as u8)
+        })
+        .collect::<Option<Vec<u8>>>()
+        .map(Zeroizing::new)
+        .ok_or(Error::InvalidEncoding)?;
+
+    let (values, checksum) = values.split_at(values.len() - BECH32_CHECKSUM_LEN);
+    if bech32_polymod(hrp, values, checksum) != 1 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let mut data = Zeroizing::new(Vec::with_capacity(values.len() * 5 / 8));
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    for &value in values {
+        acc = (acc << 5 | value as u32) & 0xfff;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            data.push((acc >> bits) as u8);
+        }
+    }
+
+    // At most 4 bits of padding, all zero
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(data)
+}
+
+/// The bech32 checksum polynomial over the expanded `hrp`, `values` and `checksum`
+fn bech32_polymod(hrp: &str, values: &[u8], checksum: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let expanded = hrp
+        .bytes()
+        .map(|byte| byte >> 5)
+        .chain(Some(0))
+        .chain(hrp.bytes().map(|byte| byte & 0x1f));
+
+    expanded
+        .chain(values.iter().copied())
+        .chain(checksum.iter().copied())
+        .fold(1, |chk, value| {
+            let top = chk >> 25;
+            let chk = (chk & 0x1ff_ffff) << 5 ^ value as u32;
+
+            GENERATOR
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| top >> i & 1 == 1)
+                .fold(chk, |chk, (_, generator)| chk ^ generator)
+        })
+}
+
+/// SHA256 applied twice
+fn sha256d(bytes: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(sha2::Sha256::digest(bytes)).into()
+}
+
+/// Base58 with the Bitcoin alphabet, leading zero bytes are encoded as `1`s
+fn base58(bytes: &[u8]) -> Zeroizing<String> {
+    // Little endian base 58 digits
+    let mut digits = Zeroizing::new(Vec::with_capacity(bytes.len() * 138 / 100 + 1));
+
+    for &byte in bytes {
+        let mut carry = byte as u32;
+
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+    let mut encoded = Zeroizing::new(String::with_capacity(zeros + digits.len()));
+    encoded.extend(core::iter::repeat_n('1', zeros));
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| BASE58_ALPHABET[digit as usize] as char),
+    );
+
+    encoded
+}
+
+/// The inverse of [`base58()`][base58], returns `Error::InvalidEncoding` for any character
+/// outside of the alphabet
+///
+/// [base58]: ./fn.base58.html
+fn from_base58(string: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    // Little endian bytes
+    let mut bytes = Zeroizing::new(Vec::with_capacity(string.len() * 733 / 1000 + 1));
+
+    for c in string.bytes() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|&digit| digit == c)
+            .ok_or(Error::InvalidEncoding)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = string.bytes().take_while(|&c| c == b'1').count();
+
+    let mut decoded = Zeroizing::new(Vec::with_capacity(zeros + bytes.len()));
+    decoded.resize(zeros, 0);
+    decoded.extend(bytes.iter().rev());
+
+    Ok(decoded)
+}
+
+/// Encode `data` as lowercase hex
+pub(crate) fn to_hex(data: &[u8]) -> Zeroizing<String> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut string = Zeroizing::new(String::with_capacity(data.len() * 2));
+
+    for &byte in data {
+        string.push(DIGITS[usize::from(byte >> 4)] as char);
+        string.push(DIGITS[usize::from(byte & 0xf)] as char);
+    }
+
+    string
+}
+
+/// Decode a hex `string`, in lowercase or uppercase
+///
+/// Returns `Error::InvalidEncoding` for an odd number of digits, or anything else than hex
+/// digits, including whitespace and a `0x` prefix.
+pub(crate) fn from_hex(string: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let digit = |byte: u8| (byte as char).to_digit(16).map(|digit| digit as u8);
+
+    if !string.len().is_multiple_of(2) {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let mut bytes = Zeroizing::new(Vec::with_capacity(string.len() / 2));
+
+    for pair in string.as_bytes().chunks(2) {
+        match (digit(pair[0]), digit(pair[1])) {
+            (Some(high), Some(low)) => bytes.push(high << 4 | low),
+            _ => return Err(Error::InvalidEncoding),
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Encode `data` with the standard base64 alphabet of RFC 4648, with padding
+pub(crate) fn to_base64(data: &[u8]) -> Zeroizing<String> {
+    let mut string = Zeroizing::new(String::with_capacity(data.len().div_ceil(3) * 4));
+
+    for chunk in data.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (index, &byte)| {
+                group | u32::from(byte) << (16 - 8 * index)
+            });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let value = (group >> (18 - 6 * index)) & 0x3f;
+                string.push(BASE64_ALPHABET[value as usize] as char);
+            } else {
+                string.push('=');
+            }
+        }
+    }
+
+    string
+}
+
+/// Decode a base64 `string` with the standard alphabet of RFC 4648, which must be padded
+///
+/// Returns `Error::InvalidEncoding` for anything else than the canonical encoding of some bytes,
+/// as `to_base64()` gives it, so there's only one string for the same bytes.
+pub(crate) fn from_base64(string: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let bytes = string.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let padding = bytes.iter().rev().take_while(|&&byte| byte == b'=').count();
+    if padding > 2 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let mut data = Zeroizing::new(Vec::with_capacity(bytes.len() / 4 * 3));
+    let value = |byte: u8| {
+        BASE64_ALPHABET
+            .iter()
+            .position(|&c| c == byte)
+            .map(|v| v as u32)
+    };
+
+    for (index, quad) in bytes.chunks(4).enumerate() {
+        let is_last = index == bytes.len() / 4 - 1;
+        let len = if is_last { 4 - padding } else { 4 };
+
+        let mut group = 0u32;
+        for (position, &byte) in quad[..len].iter().enumerate() {
+            group |= value(byte).ok_or(Error::InvalidEncoding)? << (18 - 6 * position);
+        }
+
+        // The bits of the last character that don't make a whole byte must be zero
+        let decoded = len * 6 / 8;
+        if group & (0xff_ffff >> (8 * decoded)) != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        data.extend(group.to_be_bytes()[1..=decoded].iter());
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn base58_leading_zeros() {
+        assert_eq!(&base58(&[])[..], "");
+        assert_eq!(&base58(&[0, 0, 1])[..], "112");
+        assert_eq!(&base58(&[0xff])[..], "5Q");
+
+        assert_eq!(&from_base58("112").unwrap()[..], [0, 0, 1]);
+        assert_eq!(&from_base58("5Q").unwrap()[..], [0xff]);
+        assert_eq!(from_base58("0OIl").map(|_| ()), Err(Error::InvalidEncoding));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn base58check() {
+        // The P2PKH address of the all zero public key hash
+        let address = "1111111111111111111114oLvT2";
+
+        assert_eq!(&to_base58check(&[0], &[0; 20])[..], address);
+        assert_eq!(&from_base58check(address, &[0]).unwrap()[..], [0; 20]);
+        assert_eq!(
+            from_base58check(address, &[5]).map(|_| ()),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            from_base58check("1111111111111111111114oLvT3", &[0]).map(|_| ()),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    // Valid strings from BIP173
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn bech32() {
+        let string = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+        let data = hex::decode("00443214c74254b635cf84653a56d7c675be77df").unwrap();
+
+        assert_eq!(&to_bech32("abcdef", &data).unwrap()[..], string);
+        assert_eq!(&from_bech32(string, "abcdef").unwrap()[..], &data[..]);
+        assert_eq!(
+            &from_bech32(&string.to_uppercase(), "abcdef").unwrap()[..],
+            &data[..]
+        );
+
+        assert_eq!(&to_bech32("a", &[]).unwrap()[..], "a12uel5l");
+        assert!(from_bech32("a12uel5l", "a").unwrap().is_empty());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_bech32() {
+        let invalid = |string, hrp| from_bech32(string, hrp).map(|_| ());
+
+        assert_eq!(invalid("a12uel5l", "b"), Err(Error::InvalidEncoding));
+        assert_eq!(invalid("a12uel5m", "a"), Err(Error::InvalidEncoding));
+        assert_eq!(invalid("A12uel5l", "a"), Err(Error::InvalidEncoding));
+        assert_eq!(invalid("a1uel5l", "a"), Err(Error::InvalidEncoding));
+        assert_eq!(invalid("a12ubl5l", "a"), Err(Error::InvalidEncoding));
+
+        assert_eq!(to_bech32("", &[]).map(|_| ()), Err(Error::InvalidEncoding));
+        assert_eq!(
+            to_bech32("Abc", &[]).map(|_| ()),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn hex() {
+        assert_eq!(&from_hex("00ff7A").unwrap()[..], [0x00, 0xff, 0x7a]);
+        assert_eq!(&to_hex(&[0x00, 0xff, 0x7a])[..], "00ff7a");
+        assert!(to_hex(&[]).is_empty());
+        assert!(from_hex("").unwrap().is_empty());
+
+        for invalid in ["0", "0x00", "0g", "+f", " 00", "\u{e9}0"] {
+            assert_eq!(
+                from_hex(invalid).map(|_| ()),
+                Err(Error::InvalidEncoding),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    // Test vectors from RFC 4648
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn base64() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (data, string) in vectors {
+            assert_eq!(&to_base64(data.as_bytes())[..], string);
+            assert_eq!(&from_base64(string).unwrap()[..], data.as_bytes());
+        }
+
+        assert_eq!(&to_base64(&[0xfb, 0xff])[..], "+/8=");
+
+        for invalid in [
+            "Zg", "Zg=", "Zh==", "Zg===", "Z===", "Zm9v\n", "Zg==Zg==", "Zm-v",
+        ] {
+            let result = from_base64(invalid).map(|_| ());
+            assert_eq!(result, Err(Error::InvalidEncoding), "{:?}", invalid);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Turn physical randomness, such as dice rolls or coin flips, or bytes from a reader, into
+//! entropy for a [`Mnemonic`][Mnemonic]
+//!
+//! This lets you create a mnemonic on an air-gapped machine without trusting its random number
+//! generator. Pass the result to [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()]:
+//!
+//! ```
+//! use bip39::{entropy, Language, Mnemonic, MnemonicType};
+//!
+//! let rolls = [3, 6, 1, 4, 2, 5].repeat(20);
+//!
+//! let entropy = entropy::from_dice_rolls(&rolls, MnemonicType::Words12).unwrap();
+//! let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+//!
+//! assert_eq!(mnemonic.phrase().split(" ").count(), 12);
+//! ```
+//!
+//! Both dice and coin conversions are unbiased, so the entropy is exactly as good as the input, but only if
+//! every roll or flip is independent of the others. Extra input, beyond what's needed for the
+//! requested [`MnemonicType`][MnemonicType], is ignored.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+//! [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+
+use crate::crypto::{sha256_first_byte, DefaultCrypto};
+use crate::error::Error;
+use crate::mnemonic_type::MnemonicType;
+use crate::util::checksum;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+use zeroize::Zeroizing;
+
+/// Convert rolls of a six-sided die, numbered `1` to `6`, into entropy for `mtype`
+///
+/// Rolls of `1` to `4` give two bits each, rolls of `5` and `6` give one bit each, which keeps
+/// every bit uniform for a fair die. That's about 1.67 bits per roll, so a 12 word mnemonic takes
+/// around 77 rolls and a 24 word mnemonic around 154.
+///
+/// Returns `Error::InvalidDiceRoll` with the index of the first roll that isn't between `1` and
+/// `6`, or `Error::NotEnoughEntropy` if there aren't enough rolls.
+pub fn from_dice_rolls(rolls: &[u8], mtype: MnemonicType) -> Result<Zeroizing<Vec<u8>>, Error> {
+    if let Some(idx) = rolls.iter().position(|roll| !(1..=6).contains(roll)) {
+        return Err(Error::InvalidDiceRoll(idx));
+    }
+
+    let mut writer = EntropyWriter::new(mtype);
+
+    for &roll in rolls {
+        match roll {
+            1..=4 => {
+                writer.push((roll - 1) & 2 != 0);
+                writer.push((roll - 1) & 1 != 0);
+            }
+            _ => writer.push(roll == 6),
+        }
+    }
+
+    writer.finish()
+}
+
+/// Convert coin flips into entropy for `mtype`, `true` being heads
+///
+/// The flips are taken in pairs, heads then tails gives a `1` bit, tails then heads a `0` bit, and
+/// two of the same are discarded. This removes any bias of the coin, at the cost of needing at
+/// least four flips per bit on average, so a 12 word mnemonic takes around 512 flips.
+///
+/// Returns `Error::NotEnoughEntropy` if there aren't enough flips.
+pub fn from_coin_flips(flips: &[bool], mtype: MnemonicType) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut writer = EntropyWriter::new(mtype);
+
+    for pair in flips.chunks_exact(2) {
+        if pair[0] != pair[1] {
+            writer.push(pair[0]);
+        }
+    }
+
+    writer.finish()
+}
+
+/// Read exactly the entropy needed for `mtype` from `reader`, e.g. a hardware RNG device or a file
+/// of pre-generated randomness
+///
+/// The bytes are used as they are, nothing is done to debias them. Reads that are interrupted are
+/// retried, other I/O errors are returned as `Error::Io`, and running out of input before there
+/// is enough entropy returns `Error::NotEnoughEntropy`.
+#[cfg(feature = "std")]
+pub fn from_reader<R: Read>(
+    mut reader: R,
+    mtype: MnemonicType,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut bytes = Zeroizing::new(vec![0; mtype.entropy_len()]);
+    let mut read = 0;
+
+    while read < bytes.len() {
+        match reader.read(&mut bytes[read..]) {
+            Ok(0) => {
+                return Err(Error::NotEnoughEntropy {
+                    got: read * 8,
+                    needed: bytes.len() * 8,
+                })
+            }
+            Ok(n) => read += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(Error::Io(err.kind())),
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Compute the BIP39 checksum of `entropy`, returning its value and its length in bits
+///
+/// The checksum is the first bits of the SHA-256 hash of the entropy, one for every 32 bits of
+/// entropy, and makes up the last bits of the last word of the phrase. This is the computation
+/// [`Mnemonic`][Mnemonic] uses, so tools that work out a phrase by hand, for instance from dice
+/// rolls, can check their own math against it. Returns `Error::InvalidKeysize` if `entropy`
+/// isn't 16, 20, 24, 28 or 32 bytes long.
+///
+/// # Example
+///
+/// ```
+/// use bip39::entropy;
+///
+/// // "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+/// assert_eq!(entropy::checksum_bits(&[0; 16]).unwrap(), (0b0011, 4));
+/// ```
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+pub fn checksum_bits(entropy: &[u8]) -> Result<(u8, usize), Error> {
+    let mtype = MnemonicType::for_entropy_len(entropy.len())?;
+    let bits = mtype.checksum_bits();
+
+    Ok((
+        checksum(sha256_first_byte(&DefaultCrypto, entropy), bits),
+        bits as usize,
+    ))
+}
+
+/// Collects bits until there are enough for a `MnemonicType`, ignoring the rest
+struct EntropyWriter {
+    bytes: Zeroizing<Vec<u8>>,
+    written: usize,
+}
+
+impl EntropyWriter {
+    fn new(mtype: MnemonicType) -> Self {
+        EntropyWriter {
+            bytes: Zeroizing::new(vec![0; mtype.entropy_len()]),
+            written: 0,
+        }
+    }
+
+    fn needed(&self) -> usize {
+        self.bytes.len() * 8
+    }
+
+    fn push(&mut self, bit: bool) {
+        if self.written == self.needed() {
+            return;
+        }
+
+        if bit {
+            self.bytes[self.written / 8] |= 0x80 >> (self.written % 8);
+        }
+
+        self.written += 1;
+    }
+
+    fn finish(self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        if self.written < self.needed() {
+            return Err(Error::NotEnoughEntropy {
+                got: self.written,
+                needed: self.needed(),
+            });
+        }
+
+        Ok(self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn dice_rolls() {
+        // 1 = 00, 2 = 01, 3 = 10, 4 = 11, 5 = 0, 6 = 1
+        let rolls = [1, 2, 3, 4, 5, 6, 6, 5, 4, 4].repeat(13);
+
+        let entropy = from_dice_rolls(&rolls, MnemonicType::Words12).unwrap();
+
+        assert_eq!(&entropy[..], &[0b0001_1011, 0b0110_1111].repeat(8)[..]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_dice_rolls() {
+        assert_eq!(
+            from_dice_rolls(&[1, 2, 7, 3], MnemonicType::Words12).map(|_| ()),
+            Err(Error::InvalidDiceRoll(2))
+        );
+        assert_eq!(
+            from_dice_rolls(&[0], MnemonicType::Words12).map(|_| ()),
+            Err(Error::InvalidDiceRoll(0))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn not_enough_dice_rolls() {
+        assert_eq!(
+            from_dice_rolls(&[4; 63], MnemonicType::Words12).map(|_| ()),
+            Err(Error::NotEnoughEntropy {
+                got: 126,
+                needed: 128
+            })
+        );
+        assert!(from_dice_rolls(&[4; 64], MnemonicType::Words12).is_ok());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn checksum_bits_of_last_word() {
+        use crate::language::Language;
+        use crate::mnemonic::Mnemonic;
+
+        for mtype in MnemonicType::all() {
+            let entropy: Vec<u8> = (0..mtype.entropy_len() as u8)
+                .map(|i| i.wrapping_mul(37))
+                .collect();
+            let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+            let last = *mnemonic.to_indices().last().unwrap();
+
+            let (value, bits) = checksum_bits(&entropy).unwrap();
+            assert_eq!(bits, mtype.checksum_bits() as usize);
+            assert_eq!(value as u16, last & ((1 << bits) - 1));
+        }
+
+        assert_eq!(checksum_bits(&[0; 17]), Err(Error::InvalidKeysize(136)));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn coin_flips() {
+        // HT = 1, TH = 0, HH and TT are discarded
+        let flips = [true, false, true, true, false, true, false, false].repeat(256);
+
+        let entropy = from_coin_flips(&flips, MnemonicType::Words24).unwrap();
+
+        assert_eq!(&entropy[..], &[0b1010_1010; 32][..]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn reader() {
+        let input: Vec<u8> = (0..40).collect();
+
+        let entropy = from_reader(&input[..], MnemonicType::Words15).unwrap();
+
+        assert_eq!(&entropy[..], &input[..20]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn not_enough_from_reader() {
+        assert_eq!(
+            from_reader(&[0u8; 31][..], MnemonicType::Words24).map(|_| ()),
+            Err(Error::NotEnoughEntropy {
+                got: 248,
+                needed: 256
+            })
+        );
+    }
+
+    /// Only returns one byte per read, after being interrupted, and then fails
+    #[cfg(feature = "std")]
+    struct FlakyReader {
+        reads: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl Read for FlakyReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+
+            match self.reads {
+                1 => Err(io::ErrorKind::Interrupted.into()),
+                2..=10 => {
+                    buf[0] = 0xFF;
+                    Ok(1)
+                }
+                _ => Err(io::ErrorKind::PermissionDenied.into()),
+            }
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn failing_reader() {
+        assert_eq!(
+            from_reader(FlakyReader { reads: 0 }, MnemonicType::Words12).map(|_| ()),
+            Err(Error::Io(io::ErrorKind::PermissionDenied))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn not_enough_coin_flips() {
+        let flips = [true, true].repeat(1000);
+
+        assert_eq!(
+            from_coin_flips(&flips, MnemonicType::Words12).map(|_| ()),
+            Err(Error::NotEnoughEntropy {
+                got: 0,
+                needed: 128
+            })
+        );
+    }
+}
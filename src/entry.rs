@@ -0,0 +1,243 @@
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use alloc::vec::Vec;
+use core::fmt;
+use zeroize::Zeroizing;
+
+/// The most words a phrase can have
+const MAX_WORDS: usize = 24;
+
+/// Validates a phrase word by word as it's entered, for recovery screens giving feedback on each
+/// word instead of validating the whole phrase on every keystroke
+///
+/// Each word is checked as it's pushed, and abbreviations of at least four characters matching a
+/// single word are completed, as with
+/// [`ValidationOptions::expand_prefixes()`][expand_prefixes]. Once all the words are in,
+/// [`PhraseEntry::finish()`][finish] verifies the checksum.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Language, MnemonicType, PhraseEntry};
+///
+/// let mut entry = PhraseEntry::with_word_count(Language::English, MnemonicType::Words12);
+///
+/// assert_eq!(entry.push_word("park").unwrap(), "park");
+/// assert_eq!(entry.push_word("rema").unwrap(), "remain");
+/// assert!(entry.push_word("persn").is_err());
+/// assert_eq!(entry.remaining(), 10);
+///
+/// for word in "person kitchen mule spell knee armed position rail grid ankle".split(' ') {
+///     entry.push_word(word).unwrap();
+/// }
+///
+/// let mnemonic = entry.finish().unwrap();
+/// assert!(mnemonic.phrase().starts_with("park remain person"));
+/// ```
+///
+/// [expand_prefixes]: ./validation/struct.ValidationOptions.html#method.expand_prefixes
+/// [finish]: ./struct.PhraseEntry.html#method.finish
+#[derive(Clone)]
+pub struct PhraseEntry {
+    lang: Language,
+    mtype: Option<MnemonicType>,
+    indices: Zeroizing<Vec<u16>>,
+}
+
+impl PhraseEntry {
+    /// Start entering a phrase in `lang`, of any standard number of words
+    pub fn new(lang: Language) -> PhraseEntry {
+        PhraseEntry {
+            lang,
+            mtype: None,
+            indices: Zeroizing::new(Vec::with_capacity(MAX_WORDS)),
+        }
+    }
+
+    /// Start entering a phrase in `lang` of the number of words of `mtype`
+    pub fn with_word_count(lang: Language, mtype: MnemonicType) -> PhraseEntry {
+        PhraseEntry {
+            mtype: Some(mtype),
+            ..PhraseEntry::new(lang)
+        }
+    }
+
+    /// Check the next word, and add it to the phrase, returning it as spelled in the word list
+    ///
+    /// The word is normalized the same way as in
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()]. Returns `Error::InvalidWord` with
+    /// the position of the word if it's neither in the word list nor the abbreviation of a single
+    /// word, and `Error::InvalidWordCount` if the phrase already has all its words, in which case
+    /// nothing is added.
+    ///
+    /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn push_word(&mut self, word: &str) -> Result<&'static str, Error> {
+        let index = self.indices.len();
+
+        if index == self.max_words() {
+            return Err(Error::InvalidWordCount(index + 1));
+        }
+        if word.len() > Mnemonic::MAX_PHRASE_LEN {
+            return Err(Error::PhraseTooLong(word.len()));
+        }
+
+        let word = Mnemonic::normalize_phrase(word, self.lang);
+        let wordlist = self.lang.wordlist();
+
+        let found = match self.lang.index_of(&word) {
+            Some(found) => found,
+            None => wordlist
+                .expand_prefix(&word)
+                .and_then(|full| self.lang.index_of(full))
+                .ok_or_else(|| Error::InvalidWord {
+                    word: SecretWord::new(&word, Some(self.lang)),
+                    index,
+                })?,
+        };
+
+        self.indices.push(found);
+
+        Ok(wordlist
+            .get(found)
+            .expect("word list indices are below 2048"))
+    }
+
+    /// Remove the last word, for instance when it's deleted, and return it
+    pub fn pop_word(&mut self) -> Option<&'static str> {
+        let index = self.indices.pop()?;
+
+        self.lang.word_at(index)
+    }
+
+    /// The words of the word list starting with `prefix`, to suggest while the next word is typed
+    pub fn completions(&self, prefix: &str) -> &'static [&'static str] {
+        self.lang
+            .words_by_prefix(&Mnemonic::normalize_phrase(prefix, self.lang))
+    }
+
+    /// The number of words entered so far
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether no words have been entered yet
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// The number of words still to enter, counting up to 24 if the number of words isn't known
+    pub fn remaining(&self) -> usize {
+        self.max_words() - self.indices.len()
+    }
+
+    /// Whether the phrase has a valid number of words, the one it was started with if any, so
+    /// that [`PhraseEntry::finish()`][finish] can be tried
+    ///
+    /// [finish]: ./struct.PhraseEntry.html#method.finish
+    pub fn is_complete(&self) -> bool {
+        match self.mtype {
+            Some(mtype) => self.indices.len() == mtype.word_count(),
+            None => MnemonicType::for_word_count(self.indices.len()).is_ok(),
+        }
+    }
+
+    /// Verify the checksum of the phrase entered, and create its [`Mnemonic`][Mnemonic]
+    ///
+    /// Returns `Error::InvalidWordCount` if the phrase doesn't have a valid number of words, or
+    /// not the one it was started with, and `Error::InvalidChecksum` if the checksum is wrong.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn finish(self) -> Result<Mnemonic, Error> {
+        if !self.is_complete() {
+            return Err(Error::InvalidWordCount(self.indices.len()));
+        }
+
+        Mnemonic::from_indices(&self.indices, self.lang)
+    }
+
+    fn max_words(&self) -> usize {
+        self.mtype.map_or(MAX_WORDS, |mtype| mtype.word_count())
+    }
+}
+
+/// The words entered are secret, so only their number is printed
+impl fmt::Debug for PhraseEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PhraseEntry")
+            .field("lang", &self.lang)
+            .field("words", &self.indices.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn enter_phrase() {
+        let mut entry = PhraseEntry::new(Language::English);
+
+        for (count, word) in PHRASE.split(' ').enumerate() {
+            assert!(!entry.is_complete());
+            assert_eq!(entry.push_word(word), Ok(word));
+            assert_eq!(entry.len(), count + 1);
+        }
+
+        assert!(entry.is_complete());
+        assert_eq!(entry.remaining(), 12);
+        assert_eq!(entry.clone().finish().unwrap().phrase(), PHRASE);
+
+        assert_eq!(entry.pop_word(), Some("ankle"));
+        entry.push_word("anchor").unwrap();
+        assert_eq!(entry.finish().map(|_| ()), Err(Error::InvalidChecksum));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_words() {
+        let mut entry = PhraseEntry::with_word_count(Language::English, MnemonicType::Words12);
+
+        // "arm" is a word, even though it's the start of "armed" and "army"
+        assert_eq!(entry.push_word("arm"), Ok("arm"));
+        assert_eq!(entry.push_word("kitc"), Ok("kitchen"));
+        match entry.push_word(" Mule ") {
+            Err(Error::InvalidWord { index, word }) => {
+                assert_eq!(index, 2);
+                assert_eq!(word.suggestions().first(), Some(&"mule"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(entry.len(), 2);
+
+        for _ in 2..12 {
+            entry.push_word("zoo").unwrap();
+        }
+        assert_eq!(entry.push_word("zoo"), Err(Error::InvalidWordCount(13)));
+        assert_eq!(entry.remaining(), 0);
+
+        let entry = PhraseEntry::with_word_count(Language::English, MnemonicType::Words24);
+        assert_eq!(entry.finish().map(|_| ()), Err(Error::InvalidWordCount(0)));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn completions_and_debug() {
+        let mut entry = PhraseEntry::new(Language::English);
+        assert_eq!(entry.completions("arm"), ["arm", "armed", "armor", "army"]);
+
+        entry.push_word("park").unwrap();
+        assert_eq!(
+            format!("{:?}", entry),
+            "PhraseEntry { lang: English, words: 1, .. }"
+        );
+    }
+}
@@ -1,20 +1,209 @@
+use crate::language::Language;
 use crate::mnemonic_type::MnemonicType;
-use thiserror::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::NonZeroU32;
+use zeroize::Zeroizing;
 
-#[derive(Debug, Error)]
-pub enum ErrorKind {
+/// The error type returned by every fallible function in this crate
+///
+/// It implements [`core::error::Error`][core::error::Error] and is `Send + Sync + 'static`, so it
+/// can be propagated with `?` into `anyhow`, `Box<dyn Error>` or a `thiserror` enum of your own.
+/// The failures of other libraries it wraps are available from `source()`.
+///
+/// New variants may be added in minor releases, so matches on it need a wildcard arm.
+///
+/// [core::error::Error]: https://doc.rust-lang.org/core/error/trait.Error.html
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
     #[error("invalid checksum")]
     InvalidChecksum,
-    #[error("invalid word in phrase with index {0}")]
-    InvalidWord(usize),
+    /// [`Error::suggestions()`][suggestions] gives the closest words from the wordlist, which can
+    /// be shown as "did you mean" hints. The word isn't part of the error message.
+    ///
+    /// [suggestions]: ./enum.Error.html#method.suggestions
+    #[error("invalid word in phrase with index {index}")]
+    InvalidWord { word: SecretWord, index: usize },
     #[error("invalid keysize: {0}")]
     InvalidKeysize(usize),
     #[error("invalid number of words in phrase: {0}")]
-    InvalidWordLength(usize),
-    #[error("invalid entropy length {0}bits for mnemonic type {1:?}")]
-    InvalidEntropyLength(usize, MnemonicType),
+    InvalidWordCount(usize),
+    #[error("invalid entropy length {got}bits for mnemonic type {expected:?}")]
+    InvalidEntropyLength { got: usize, expected: MnemonicType },
+    #[error("unexpected whitespace in phrase")]
+    InvalidWhitespace,
+    #[error("phrase too long: {0} bytes")]
+    PhraseTooLong(usize),
+    #[error("phrase is not valid in any supported language")]
+    UnknownLanguage,
+    #[error("invalid number of words in wordlist: {0}")]
+    InvalidWordlistLength(usize),
+    #[error("invalid or duplicate word in wordlist with index {0}")]
+    InvalidWordlistWord(usize),
+    #[error("random number generator failed to provide entropy")]
+    RandomSource(#[source] RngError),
+    #[error("invalid dice roll with index {0}")]
+    InvalidDiceRoll(usize),
+    #[error("not enough input for entropy: got {got} of {needed} bits")]
+    NotEnoughEntropy { got: usize, needed: usize },
+    #[error("output buffer too small, {0} bytes needed")]
+    BufferTooSmall(usize),
+    #[error("invalid key derivation parameters")]
+    InvalidKdfParams,
+    #[error("invalid Monero spend key")]
+    InvalidSpendKey,
+    #[error("unsupported seed version {0}")]
+    UnsupportedVersion(u8),
+    #[error("wrong passphrase or corrupted seed")]
+    InvalidPassphrase,
+    #[error("invalid threshold or share count")]
+    InvalidThreshold,
+    #[error("invalid share")]
+    InvalidShare,
+    #[error("shares don't belong to the same secret")]
+    MismatchedShares,
+    #[error("not enough shares: got {got} of {needed} groups")]
+    NotEnoughShares { got: usize, needed: usize },
+    #[error("invalid derivation path")]
+    InvalidDerivationPath,
+    #[error("derived key is invalid")]
+    InvalidDerivedKey,
+    #[error("invalid base58check or bech32 string")]
+    InvalidEncoding,
+    #[error("invalid sealed mnemonic")]
+    InvalidSealedMnemonic,
+    #[error("invalid backup container")]
+    InvalidContainer,
+    #[error("invalid or unsupported keystore file")]
+    InvalidKeystore,
+    #[error("built-in word list of {0:?} doesn't match its reference")]
+    CorruptWordlist(Language),
+    #[error("language code {0:?} is already in use")]
+    LanguageCodeInUse(String),
+    #[error("no generated mnemonic matched the predicate in {0} attempts")]
+    NoMatchingMnemonic(usize),
+    #[error("can't generate {0} mnemonics at once")]
+    BatchTooLarge(usize),
+    #[cfg(feature = "async")]
+    #[error("the async runtime shut down before the seed was derived")]
+    TaskCancelled,
+    #[cfg(feature = "std")]
+    #[error("failed to read entropy: {0}")]
+    Io(std::io::ErrorKind),
 }
 
+impl Error {
+    /// The closest words to the one of an `Error::InvalidWord`, best match first, as with
+    /// [`SecretWord::suggestions()`][suggestions], or none for other errors
+    ///
+    /// [suggestions]: ./struct.SecretWord.html#method.suggestions
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        match self {
+            Error::InvalidWord { word, .. } => word.suggestions(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A word of a phrase kept by an error, which is zeroized when dropped and left out of `Debug`, as
+/// even a misspelled word gives away most of a secret one
+///
+/// The word is empty when the input wasn't a word, as for an index out of range.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretWord {
+    word: Zeroizing<String>,
+    lang: Option<Language>,
+}
+
+impl SecretWord {
+    /// A word expected in the word list of `lang`, if it's a BIP39 one
+    pub(crate) fn new(word: &str, lang: Option<Language>) -> SecretWord {
+        SecretWord {
+            word: Zeroizing::new(String::from(word)),
+            lang,
+        }
+    }
+
+    /// The word as it was checked, normalized the way it was looked up
+    pub fn as_str(&self) -> &str {
+        &self.word
+    }
+
+    /// The closest words from the word list, best match first, within an edit distance of 2 or
+    /// sharing the first four characters
+    ///
+    /// These are found when called, and there are none for word lists other than the BIP39 ones.
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        match self.lang {
+            Some(lang) if !self.word.is_empty() => lang.wordlist().suggestions(&self.word),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The word is secret, so it isn't printed
+impl fmt::Debug for SecretWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretWord")
+            .field("lang", &self.lang)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The failure of a random number generator, the source of `Error::RandomSource`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RngError {
+    code: Option<NonZeroU32>,
+}
+
+impl RngError {
+    pub(crate) fn new(code: Option<NonZeroU32>) -> RngError {
+        RngError { code }
+    }
+
+    /// The error code reported by the generator, an OS error code or one of `getrandom`'s, if
+    /// there is one
+    pub fn code(&self) -> Option<NonZeroU32> {
+        self.code
+    }
+}
+
+impl fmt::Display for RngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "random number generator error code {}", code),
+            None => f.write_str("no random number generator available"),
+        }
+    }
+}
+
+impl core::error::Error for RngError {}
+
+/// The check of [`self_test()`][self_test] that failed
+///
+/// [self_test]: ./fn.self_test.html
+#[cfg(feature = "self-test")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SelfTestError {
+    #[error("a built-in word list doesn't match its reference")]
+    WordList,
+    #[error("test vector {0} gave the wrong phrase")]
+    Phrase(usize),
+    #[error("test vector {0} gave the wrong seed")]
+    Seed(usize),
+    #[error("{words} word {lang:?} phrase didn't round trip")]
+    RoundTrip { lang: Language, words: usize },
+}
+
+/// The previous name of [`Error`][Error], from when it was wrapped in an opaque error type
+///
+/// [Error]: ./enum.Error.html
+#[deprecated(note = "use `Error` instead")]
+pub type ErrorKind = Error;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -24,20 +213,69 @@ mod test {
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn prints_correctly() {
+        assert_eq!(format!("{}", Error::InvalidChecksum), "invalid checksum",);
         assert_eq!(
-            format!("{}", ErrorKind::InvalidChecksum),
-            "invalid checksum",
+            format!(
+                "{}",
+                Error::InvalidWord {
+                    word: SecretWord::new("kitchn", Some(Language::English)),
+                    index: 4,
+                }
+            ),
+            "invalid word in phrase with index 4",
         );
         assert_eq!(
-            format!("{}", ErrorKind::InvalidKeysize(42)),
+            format!("{}", Error::InvalidKeysize(42)),
             "invalid keysize: 42",
         );
         assert_eq!(
             format!(
                 "{}",
-                ErrorKind::InvalidEntropyLength(42, MnemonicType::Words12)
+                Error::InvalidEntropyLength {
+                    got: 42,
+                    expected: MnemonicType::Words12
+                }
             ),
             "invalid entropy length 42bits for mnemonic type Words12",
         );
     }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn is_std_error() {
+        fn assert_std_error<E: std::error::Error + Send + Sync + 'static>() {}
+
+        assert_std_error::<Error>();
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn source_chain() {
+        use std::error::Error as _;
+
+        let code = NonZeroU32::new(42);
+        let error = Error::RandomSource(RngError::new(code));
+
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), "random number generator error code 42");
+        assert_eq!(source.downcast_ref::<RngError>().unwrap().code(), code);
+        assert!(Error::InvalidChecksum.source().is_none());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn secret_word() {
+        let error = Error::InvalidWord {
+            word: SecretWord::new("kitchn", Some(Language::English)),
+            index: 4,
+        };
+
+        assert!(!format!("{:?}", error).contains("kitchn"));
+        assert_eq!(error.suggestions(), ["kitchen", "kitten", "pitch"]);
+        assert!(Error::InvalidChecksum.suggestions().is_empty());
+
+        assert_eq!(SecretWord::new("kitchn", None).as_str(), "kitchn");
+        assert!(SecretWord::new("kitchn", None).suggestions().is_empty());
+        assert!(SecretWord::default().suggestions().is_empty());
+    }
 }
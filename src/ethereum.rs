@@ -0,0 +1,142 @@
+//! Ethereum account keys, enabled with the `ethereum` feature
+//!
+//! Ethereum wallets derive the key of account `index` along the BIP44 path
+//! `m/44'/60'/0'/0/{index}`, and its address from the Keccak-256 hash of the public key.
+//! [`Seed::derive_ethereum()`][Seed::derive_ethereum()] does both, giving an
+//! [`EthereumKey`][EthereumKey] with the private key to sign with and the EIP-55 checksummed
+//! address that MetaMask and other wallets show for the same mnemonic.
+//!
+//! ```
+//! use bip39::{Language, Mnemonic};
+//!
+//! let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! let key = mnemonic.to_seed("").derive_ethereum(0).unwrap();
+//!
+//! assert_eq!(key.address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+//! ```
+//!
+//! [Seed::derive_ethereum()]: ../seed/struct.Seed.html#method.derive_ethereum
+//! [EthereumKey]: ./struct.EthereumKey.html
+
+use crate::bip32::ExtendedPrivKey;
+use alloc::string::String;
+use core::fmt;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+
+/// The private key and address of an Ethereum account, derived with
+/// [`Seed::derive_ethereum()`][Seed::derive_ethereum()]
+///
+/// [Seed::derive_ethereum()]: ../seed/struct.Seed.html#method.derive_ethereum
+#[derive(Clone)]
+pub struct EthereumKey {
+    key: Zeroizing<[u8; 32]>,
+    address: [u8; 20],
+}
+
+impl EthereumKey {
+    pub(crate) fn new(key: &ExtendedPrivKey) -> EthereumKey {
+        let hash = Keccak256::digest(key.uncompressed_public_key());
+
+        let mut address = [0; 20];
+        address.copy_from_slice(&hash[12..]);
+
+        EthereumKey {
+            key: Zeroizing::new(*key.private_key()),
+            address,
+        }
+    }
+
+    /// The 32 byte secp256k1 private key, to sign transactions with
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The 20 bytes of the address
+    pub fn address_bytes(&self) -> &[u8; 20] {
+        &self.address
+    }
+
+    /// The address in hex with a `0x` prefix, in the mixed case of the EIP-55 checksum
+    pub fn address(&self) -> String {
+        let hex: String = self
+            .address
+            .iter()
+            .map(|byte| alloc::format!("{:02x}", byte))
+            .collect();
+        let hash = Keccak256::digest(hex.as_bytes());
+
+        let mut address = String::with_capacity(42);
+        address.push_str("0x");
+
+        for (index, c) in hex.chars().enumerate() {
+            let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+
+            if nibble >= 8 {
+                address.push(c.to_ascii_uppercase());
+            } else {
+                address.push(c);
+            }
+        }
+
+        address
+    }
+}
+
+/// The private key is a secret, so only the address is printed, use
+/// [`EthereumKey::private_key()`][EthereumKey::private_key()] to get the key explicitly.
+///
+/// [EthereumKey::private_key()]: ./struct.EthereumKey.html#method.private_key
+impl fmt::Debug for EthereumKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EthereumKey({}, ****)", self.address())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Language, Mnemonic};
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn derive_accounts() {
+        let seed = Mnemonic::from_phrase(PHRASE, Language::English)
+            .unwrap()
+            .to_seed("");
+
+        let first = seed.derive_ethereum(0).unwrap();
+        assert_eq!(first.private_key()[..4], [0x1a, 0xb4, 0x2c, 0xc4]);
+        assert_eq!(
+            first.address(),
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+        assert_eq!(first.address_bytes()[0], 0x98);
+
+        let second = seed.derive_ethereum(1).unwrap();
+        assert_eq!(
+            second.address(),
+            "0x6Fac4D18c912343BF86fa7049364Dd4E424Ab9C0"
+        );
+
+        assert_eq!(
+            format!("{:?}", first),
+            "EthereumKey(0x9858EfFD232B4033E47d90003D41EC34EcaEda94, ****)",
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn hardened_index() {
+        let seed = Mnemonic::from_phrase(PHRASE, Language::English)
+            .unwrap()
+            .to_seed("");
+
+        assert!(seed.derive_ethereum(0x8000_0000).is_err());
+    }
+}
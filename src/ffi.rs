@@ -0,0 +1,290 @@
+//! C bindings, enabled with the `ffi` feature
+//!
+//! The matching header is `include/bip39.h`, which can be regenerated with
+//! `cbindgen --config cbindgen.toml --output include/bip39.h`. To get a library C can link
+//! against, build the crate with `cargo rustc --release --features ffi --crate-type staticlib`
+//! (or `cdylib`).
+//!
+//! All functions return `BIP39_OK` on success, or one of the other `BIP39_*` status codes on
+//! failure. Languages are passed as the language codes accepted by
+//! [`Language::from_language_code()`][from_language_code], or `NULL` for English. Phrases
+//! returned by this library must be released with [`bip39_free_phrase()`][bip39_free_phrase],
+//! which wipes them before freeing them.
+//!
+//! [from_language_code]: ../language/enum.Language.html#method.from_language_code
+//! [bip39_free_phrase]: ./fn.bip39_free_phrase.html
+
+use crate::error::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use zeroize::Zeroize;
+
+/// Success
+pub const BIP39_OK: c_int = 0;
+/// A required pointer was `NULL`, or a string wasn't valid UTF-8
+pub const BIP39_INVALID_ARGUMENT: c_int = 1;
+/// The language code isn't known, or the language isn't enabled
+pub const BIP39_UNKNOWN_LANGUAGE: c_int = 2;
+/// The phrase or the requested mnemonic doesn't have a standard number of words
+pub const BIP39_INVALID_WORD_COUNT: c_int = 3;
+/// A word of the phrase isn't in the word list
+pub const BIP39_INVALID_WORD: c_int = 4;
+/// The checksum of the phrase doesn't match
+pub const BIP39_INVALID_CHECKSUM: c_int = 5;
+/// The operating system random number generator failed
+pub const BIP39_RANDOM_SOURCE: c_int = 6;
+/// Any other error
+pub const BIP39_ERROR: c_int = 7;
+
+/// Length of the seed written by [`bip39_to_seed()`][bip39_to_seed]
+///
+/// [bip39_to_seed]: ./fn.bip39_to_seed.html
+pub const BIP39_SEED_LENGTH: usize = 64;
+
+fn status(err: Error) -> c_int {
+    match err {
+        Error::InvalidWordCount(_) => BIP39_INVALID_WORD_COUNT,
+        Error::InvalidWord { .. } => BIP39_INVALID_WORD,
+        Error::InvalidChecksum => BIP39_INVALID_CHECKSUM,
+        Error::RandomSource(_) => BIP39_RANDOM_SOURCE,
+        _ => BIP39_ERROR,
+    }
+}
+
+/// Borrow a C string as a `&str`, failing on `NULL` or invalid UTF-8
+unsafe fn to_str<'a>(s: *const c_char) -> Result<&'a str, c_int> {
+    if s.is_null() {
+        return Err(BIP39_INVALID_ARGUMENT);
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| BIP39_INVALID_ARGUMENT)
+}
+
+unsafe fn to_language(lang: *const c_char) -> Result<Language, c_int> {
+    if lang.is_null() {
+        return Ok(Language::English);
+    }
+
+    Language::from_language_code(to_str(lang)?).ok_or(BIP39_UNKNOWN_LANGUAGE)
+}
+
+/// Generate a new mnemonic phrase with `words` words, using the operating system CSPRNG
+///
+/// On success, `*phrase_out` points to a NUL terminated phrase, which must be released with
+/// [`bip39_free_phrase()`][bip39_free_phrase]. On failure, it is set to `NULL`.
+///
+/// # Safety
+///
+/// `lang` must be `NULL` or a valid NUL terminated string, and `phrase_out` a valid pointer.
+///
+/// [bip39_free_phrase]: ./fn.bip39_free_phrase.html
+#[no_mangle]
+pub unsafe extern "C" fn bip39_generate(
+    words: usize,
+    lang: *const c_char,
+    phrase_out: *mut *mut c_char,
+) -> c_int {
+    if phrase_out.is_null() {
+        return BIP39_INVALID_ARGUMENT;
+    }
+
+    *phrase_out = ptr::null_mut();
+
+    let lang = match to_language(lang) {
+        Ok(lang) => lang,
+        Err(code) => return code,
+    };
+
+    let generated =
+        MnemonicType::for_word_count(words).and_then(|mtype| Mnemonic::try_new(mtype, lang));
+
+    let mnemonic = match generated {
+        Ok(mnemonic) => mnemonic,
+        Err(err) => return status(err),
+    };
+
+    // Word lists have no NUL bytes, but unwinding across the FFI boundary is never worth it
+    match CString::new(mnemonic.into_phrase()) {
+        Ok(phrase) => *phrase_out = phrase.into_raw(),
+        Err(err) => {
+            err.into_vec().zeroize();
+            return BIP39_ERROR;
+        }
+    }
+
+    BIP39_OK
+}
+
+/// Validate a mnemonic phrase, returning `BIP39_OK` if it is valid, checksum included
+///
+/// # Safety
+///
+/// `phrase` must be a valid NUL terminated string, and `lang` either `NULL` or one too.
+#[no_mangle]
+pub unsafe extern "C" fn bip39_validate(phrase: *const c_char, lang: *const c_char) -> c_int {
+    let (phrase, lang) = match (to_str(phrase), to_language(lang)) {
+        (Ok(phrase), Ok(lang)) => (phrase, lang),
+        (Err(code), _) | (_, Err(code)) => return code,
+    };
+
+    match Mnemonic::validate(phrase, lang) {
+        Ok(()) => BIP39_OK,
+        Err(err) => status(err),
+    }
+}
+
+/// Validate a mnemonic phrase and write its seed into the `BIP39_SEED_LENGTH` bytes at `seed_out`
+///
+/// `passphrase` may be `NULL` for an empty passphrase.
+///
+/// # Safety
+///
+/// `phrase` must be a valid NUL terminated string, `passphrase` and `lang` either `NULL` or one
+/// too, and `seed_out` must be valid for writes of `BIP39_SEED_LENGTH` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bip39_to_seed(
+    phrase: *const c_char,
+    passphrase: *const c_char,
+    lang: *const c_char,
+    seed_out: *mut u8,
+) -> c_int {
+    if seed_out.is_null() {
+        return BIP39_INVALID_ARGUMENT;
+    }
+
+    let passphrase = if passphrase.is_null() {
+        Ok("")
+    } else {
+        to_str(passphrase)
+    };
+
+    let (phrase, passphrase, lang) = match (to_str(phrase), passphrase, to_language(lang)) {
+        (Ok(phrase), Ok(passphrase), Ok(lang)) => (phrase, passphrase, lang),
+        (Err(code), _, _) | (_, Err(code), _) | (_, _, Err(code)) => return code,
+    };
+
+    let mnemonic = match Mnemonic::from_phrase(phrase, lang) {
+        Ok(mnemonic) => mnemonic,
+        Err(err) => return status(err),
+    };
+
+    let seed = Seed::new(&mnemonic, passphrase);
+
+    ptr::copy_nonoverlapping(seed.as_bytes().as_ptr(), seed_out, BIP39_SEED_LENGTH);
+
+    BIP39_OK
+}
+
+/// Wipe and free a phrase returned by this library, does nothing for `NULL`
+///
+/// # Safety
+///
+/// `phrase` must be `NULL` or a pointer returned by this library, which hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bip39_free_phrase(phrase: *mut c_char) {
+    if phrase.is_null() {
+        return;
+    }
+
+    let mut bytes = CString::from_raw(phrase).into_bytes();
+
+    bytes.zeroize();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &[u8] =
+        b"park remain person kitchen mule spell knee armed position rail grid ankle\0";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate() {
+        let mut phrase = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(bip39_generate(24, ptr::null(), &mut phrase), BIP39_OK);
+
+            let words = CStr::from_ptr(phrase).to_str().unwrap();
+            assert_eq!(words.split(' ').count(), 24);
+            assert_eq!(bip39_validate(phrase, ptr::null()), BIP39_OK);
+
+            bip39_free_phrase(phrase);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate_invalid() {
+        let mut phrase = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(
+                bip39_generate(13, ptr::null(), &mut phrase),
+                BIP39_INVALID_WORD_COUNT
+            );
+            assert!(phrase.is_null());
+            assert_eq!(
+                bip39_generate(12, b"xx\0".as_ptr().cast(), &mut phrase),
+                BIP39_UNKNOWN_LANGUAGE
+            );
+            assert_eq!(
+                bip39_generate(12, ptr::null(), ptr::null_mut()),
+                BIP39_INVALID_ARGUMENT
+            );
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn validate() {
+        unsafe {
+            assert_eq!(
+                bip39_validate(PHRASE.as_ptr().cast(), b"en\0".as_ptr().cast()),
+                BIP39_OK
+            );
+            assert_eq!(
+                bip39_validate(b"park remain\0".as_ptr().cast(), ptr::null()),
+                BIP39_INVALID_WORD_COUNT
+            );
+            assert_eq!(
+                bip39_validate(ptr::null(), ptr::null()),
+                BIP39_INVALID_ARGUMENT
+            );
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn to_seed() {
+        let mut seed = [0u8; BIP39_SEED_LENGTH];
+
+        let phrase = std::str::from_utf8(&PHRASE[..PHRASE.len() - 1]).unwrap();
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let expected = Seed::new(&mnemonic, "password");
+
+        unsafe {
+            assert_eq!(
+                bip39_to_seed(
+                    PHRASE.as_ptr().cast(),
+                    b"password\0".as_ptr().cast(),
+                    ptr::null(),
+                    seed.as_mut_ptr()
+                ),
+                BIP39_OK
+            );
+        }
+
+        assert_eq!(&seed[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,431 @@
+//! Allocation-free versions of the core operations, for microcontrollers that can't afford a heap
+//!
+//! Everything here works with fixed-size buffers: entropy goes in a `[u8; 32]`, word indices in a
+//! `[u16; 24]`, the seed in a `[u8; 64]`, and phrases are written into a caller-supplied
+//! `&mut [u8]`. The word lists of the built-in languages, and the perfect hash maps used to look
+//! words up, are generated at compile time, so nothing needs memory besides the stack.
+//!
+//! Unlike [`Mnemonic`][Mnemonic], nothing is normalized here: phrases must be NFKD normalized,
+//! with single separators between the words, as written by [`write_phrase()`][write_phrase()].
+//! For English, which is plain ASCII, that only means lowercase words separated by single spaces.
+//!
+//! ```
+//! use bip39::fixed;
+//! use bip39::Language;
+//!
+//! let entropy = [0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+//!
+//! let mut indices = [0; fixed::MAX_WORDS];
+//! let mtype = fixed::entropy_to_indices(&entropy, &mut indices).unwrap();
+//!
+//! let mut phrase = [0; 256];
+//! let len = fixed::write_phrase(&indices[..mtype.word_count()], Language::English, &mut phrase).unwrap();
+//! let phrase = core::str::from_utf8(&phrase[..len]).unwrap();
+//!
+//! assert_eq!(phrase, "crop cash unable insane eight faith inflict route frame loud box vibrant");
+//!
+//! let mut decoded = [0; fixed::MAX_ENTROPY_BYTES];
+//! fixed::phrase_to_entropy(phrase, Language::English, &mut decoded).unwrap();
+//!
+//! assert_eq!(&decoded[..16], &entropy);
+//!
+//! let mut seed = [0; fixed::SEED_BYTES];
+//! fixed::seed(phrase, "", &mut seed);
+//! ```
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [write_phrase()]: ./fn.write_phrase.html
+
+use crate::crypto::{sha256_first_byte, CryptoBackend, DefaultCrypto};
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic_type::MnemonicType;
+use crate::util::{checksum, is_word_separator, Bits11, IterExt};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// Entropy of the longest, 24 word, mnemonic
+pub const MAX_ENTROPY_BYTES: usize = 32;
+
+/// Number of words in the longest mnemonic
+pub const MAX_WORDS: usize = 24;
+
+/// Length of a BIP39 seed
+pub const SEED_BYTES: usize = 64;
+
+/// Turn `entropy` into the wordlist indices of its mnemonic, including the checksum
+///
+/// Only the first `mtype.word_count()` indices are written, with `mtype` being the returned
+/// [`MnemonicType`][MnemonicType]. Entropy of a length not provided for by the BIP39 standard
+/// returns `Error::InvalidKeysize`.
+///
+/// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+pub fn entropy_to_indices(
+    entropy: &[u8],
+    indices: &mut [u16; MAX_WORDS],
+) -> Result<MnemonicType, Error> {
+    entropy_to_indices_with_backend(entropy, indices, &DefaultCrypto)
+}
+
+/// The same as [`entropy_to_indices()`][entropy_to_indices()], with the checksum computed by
+/// `backend`
+///
+/// [entropy_to_indices()]: ./fn.entropy_to_indices.html
+pub fn entropy_to_indices_with_backend<B: CryptoBackend + ?Sized>(
+    entropy: &[u8],
+    indices: &mut [u16; MAX_WORDS],
+    backend: &B,
+) -> Result<MnemonicType, Error> {
+    let mtype = MnemonicType::for_entropy_len(entropy.len())?;
+    let checksum_byte = sha256_first_byte(backend, entropy);
+
+    let words = entropy.iter().chain(Some(&checksum_byte)).bits::<Bits11>();
+
+    for (index, bits) in indices.iter_mut().zip(words) {
+        *index = u16::from(bits);
+    }
+
+    Ok(mtype)
+}
+
+/// Write the words at `indices` into `out`, separated by the word separator of `lang`, and return
+/// the number of bytes written
+///
+/// Returns `Error::BufferTooSmall` with the length of the whole phrase if it doesn't fit, in which
+/// case nothing is written, or `Error::InvalidWord` for an index that is out of range. The `word`
+/// of that error is left empty, so creating it doesn't allocate.
+pub fn write_phrase(indices: &[u16], lang: Language, out: &mut [u8]) -> Result<usize, Error> {
+    let separator = lang.separator();
+    let word = |(index, &bits): (usize, &u16)| {
+        lang.word_at(bits).ok_or_else(|| Error::InvalidWord {
+            word: SecretWord::default(),
+            index,
+        })
+    };
+
+    let mut len = 0;
+
+    for (index, w) in indices.iter().enumerate().map(word).enumerate() {
+        if index > 0 {
+            len += separator.len();
+        }
+        len += w?.len();
+    }
+
+    if len > out.len() {
+        return Err(Error::BufferTooSmall(len));
+    }
+
+    let mut written = 0;
+
+    for (index, w) in indices.iter().enumerate().map(word).enumerate() {
+        if index > 0 {
+            out[written..written + separator.len()].copy_from_slice(separator.as_bytes());
+            written += separator.len();
+        }
+
+        let w = w?;
+        out[written..written + w.len()].copy_from_slice(w.as_bytes());
+        written += w.len();
+    }
+
+    Ok(written)
+}
+
+/// Validate `phrase` and write its entropy into `entropy`
+///
+/// Only the first `mtype.entropy_len()` bytes are written, with `mtype` being the returned
+/// [`MnemonicType`][MnemonicType]. The errors are the same as for
+/// [`Mnemonic::validate()`][Mnemonic::validate()], except that the `word` of
+/// `Error::InvalidWord` is left empty, so creating it doesn't allocate.
+///
+/// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+/// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+pub fn phrase_to_entropy(
+    phrase: &str,
+    lang: Language,
+    entropy: &mut [u8; MAX_ENTROPY_BYTES],
+) -> Result<MnemonicType, Error> {
+    phrase_to_entropy_with_backend(phrase, lang, entropy, &DefaultCrypto)
+}
+
+/// The same as [`phrase_to_entropy()`][phrase_to_entropy()], with the checksum computed by
+/// `backend`
+///
+/// [phrase_to_entropy()]: ./fn.phrase_to_entropy.html
+pub fn phrase_to_entropy_with_backend<B: CryptoBackend + ?Sized>(
+    phrase: &str,
+    lang: Language,
+    entropy: &mut [u8; MAX_ENTROPY_BYTES],
+    backend: &B,
+) -> Result<MnemonicType, Error> {
+    let mtype = MnemonicType::for_word_count(phrase.split(is_word_separator).count())?;
+
+    // Entropy and checksum, the checksum is at most a byte
+    let mut bits = [0u8; MAX_ENTROPY_BYTES + 1];
+
+    for (index, word) in phrase.split(is_word_separator).enumerate() {
+        let value = match lang.index_of(word) {
+            Some(value) => value,
+            None => {
+                bits.zeroize();
+
+                return Err(Error::InvalidWord {
+                    word: SecretWord::default(),
+                    index,
+                });
+            }
+        };
+
+        for bit in 0..11 {
+            if value & (1 << (10 - bit)) != 0 {
+                let offset = index * 11 + bit;
+
+                bits[offset / 8] |= 0x80 >> (offset % 8);
+            }
+        }
+    }
+
+    let entropy_bytes = mtype.entropy_len();
+    let actual_checksum = checksum(bits[entropy_bytes], mtype.checksum_bits());
+    let checksum_byte = sha256_first_byte(backend, &bits[..entropy_bytes]);
+    let expected_checksum = checksum(checksum_byte, mtype.checksum_bits());
+
+    if actual_checksum != expected_checksum {
+        bits.zeroize();
+
+        return Err(Error::InvalidChecksum);
+    }
+
+    entropy[..entropy_bytes].copy_from_slice(&bits[..entropy_bytes]);
+    bits.zeroize();
+
+    Ok(mtype)
+}
+
+/// Derive the BIP39 seed of `phrase` and `password` into `seed`
+///
+/// This gives the same result as [`Seed::new()`][Seed::new()], as long as both `phrase` and
+/// `password` are already NFKD normalized. The ideographic spaces that
+/// [`write_phrase()`][write_phrase()] puts between Japanese words are the exception: they're
+/// turned into ASCII spaces, as NFKD normalization does. The phrase isn't validated.
+///
+/// [Seed::new()]: ../seed/struct.Seed.html#method.new
+/// [write_phrase()]: ./fn.write_phrase.html
+pub fn seed(phrase: &str, password: &str, seed: &mut [u8; SEED_BYTES]) {
+    seed_with_backend(phrase, password, seed, &DefaultCrypto)
+}
+
+/// The same as [`seed()`][seed()], with PBKDF2 run by `backend` through
+/// [`Kdf::bip39_seed()`][Kdf::bip39_seed()]
+///
+/// [seed()]: ./fn.seed.html
+/// [Kdf::bip39_seed()]: ../trait.Kdf.html#method.bip39_seed
+pub fn seed_with_backend<B: CryptoBackend + ?Sized>(
+    phrase: &str,
+    password: &str,
+    seed: &mut [u8; SEED_BYTES],
+    backend: &B,
+) {
+    if !phrase.contains(IDEOGRAPHIC_SPACE) {
+        return backend.bip39_seed(phrase.as_bytes(), password.as_bytes(), seed);
+    }
+
+    // The phrase with ASCII spaces, as the HMAC key of PBKDF2, without copying the phrase: HMAC
+    // hashes keys longer than a SHA-512 block, so hashing it in pieces gives the same key
+    let pieces = || {
+        let words = phrase.split(IDEOGRAPHIC_SPACE).map(str::as_bytes);
+        words
+            .enumerate()
+            .flat_map(|(i, word)| [if i > 0 { &b" "[..] } else { &[] }, word])
+    };
+    let len = phrase.len() - 2 * phrase.matches(IDEOGRAPHIC_SPACE).count();
+
+    let mut key = [0; HMAC_BLOCK_BYTES];
+    let key_len = if len > HMAC_BLOCK_BYTES {
+        let digest = pieces().fold(Sha512::new(), |hasher, piece| hasher.chain_update(piece));
+        key[..64].copy_from_slice(&digest.finalize());
+        64
+    } else {
+        let mut written = 0;
+        for piece in pieces() {
+            key[written..written + piece.len()].copy_from_slice(piece);
+            written += piece.len();
+        }
+        written
+    };
+
+    backend.bip39_seed(&key[..key_len], password.as_bytes(), seed);
+    key.zeroize();
+}
+
+/// The word separator of Japanese, which NFKD normalizes to an ASCII space
+const IDEOGRAPHIC_SPACE: char = '\u{3000}';
+
+/// The block size of SHA-512, above which HMAC hashes its key
+const HMAC_BLOCK_BYTES: usize = 128;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mnemonic::Mnemonic;
+    use crate::seed::Seed;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    const ENTROPY: [u8; 16] = [
+        0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A,
+        0x79,
+    ];
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy_to_phrase() {
+        let mut indices = [0; MAX_WORDS];
+        let mtype = entropy_to_indices(&ENTROPY, &mut indices).unwrap();
+
+        assert_eq!(mtype, MnemonicType::Words12);
+
+        let mut out = [0; 128];
+        let len = write_phrase(&indices[..12], Language::English, &mut out).unwrap();
+
+        assert_eq!(&out[..len], PHRASE.as_bytes());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn matches_mnemonic() {
+        let entropy = [0xA5; 32];
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+        let mut indices = [0; MAX_WORDS];
+        entropy_to_indices(&entropy, &mut indices).unwrap();
+
+        let mut out = [0; 256];
+        let len = write_phrase(&indices, Language::English, &mut out).unwrap();
+
+        assert_eq!(&out[..len], mnemonic.phrase().as_bytes());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_entropy() {
+        let mut indices = [0; MAX_WORDS];
+
+        assert_eq!(
+            entropy_to_indices(&[0; 17], &mut indices),
+            Err(Error::InvalidKeysize(136))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn buffer_too_small() {
+        let mut out = [0xFF; 16];
+
+        assert_eq!(
+            write_phrase(&[0, 1, 2], Language::English, &mut out),
+            Err(Error::BufferTooSmall(20))
+        );
+        assert_eq!(out, [0xFF; 16]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_index() {
+        let mut out = [0; 64];
+
+        assert_eq!(
+            write_phrase(&[0, 2048], Language::English, &mut out),
+            Err(Error::InvalidWord {
+                word: SecretWord::default(),
+                index: 1,
+            })
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn phrase_round_trip() {
+        let mut entropy = [0; MAX_ENTROPY_BYTES];
+        let mtype = phrase_to_entropy(PHRASE, Language::English, &mut entropy).unwrap();
+
+        assert_eq!(mtype, MnemonicType::Words12);
+        assert_eq!(&entropy[..16], &ENTROPY);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_phrase() {
+        let mut entropy = [0; MAX_ENTROPY_BYTES];
+
+        assert_eq!(
+            phrase_to_entropy("crop cash unable", Language::English, &mut entropy),
+            Err(Error::InvalidWordCount(3))
+        );
+        assert_eq!(
+            phrase_to_entropy(
+                "crop cash unable insane eight faith inflict route frame loud box crop",
+                Language::English,
+                &mut entropy
+            ),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            phrase_to_entropy(
+                "crop cash unable insane eight faith inflict route frame loud box vibrnt",
+                Language::English,
+                &mut entropy
+            ),
+            Err(Error::InvalidWord {
+                word: SecretWord::default(),
+                index: 11,
+            })
+        );
+        assert_eq!(entropy, [0; MAX_ENTROPY_BYTES]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_matches() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let expected = Seed::new(&mnemonic, "password");
+
+        let mut seed = [0; SEED_BYTES];
+        super::seed(PHRASE, "password", &mut seed);
+
+        assert_eq!(&seed[..], expected.as_bytes());
+    }
+
+    #[cfg(feature = "japanese")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_matches_japanese() {
+        for entropy in [&[0; 16][..], &[0xA5; 32][..]] {
+            let mnemonic = Mnemonic::from_entropy(entropy, Language::Japanese).unwrap();
+            let expected = Seed::new(&mnemonic, "パスワード");
+
+            let mut indices = [0; MAX_WORDS];
+            let mtype = entropy_to_indices(entropy, &mut indices).unwrap();
+            let mut out = [0; 512];
+            let indices = &indices[..mtype.word_count()];
+            let len = write_phrase(indices, Language::Japanese, &mut out).unwrap();
+            let phrase = core::str::from_utf8(&out[..len]).unwrap();
+            assert!(phrase.contains(IDEOGRAPHIC_SPACE));
+
+            let password = crate::util::nfkd_zeroizing("パスワード");
+            let mut seed = [0; SEED_BYTES];
+            super::seed(phrase, &password, &mut seed);
+
+            assert_eq!(&seed[..], expected.as_bytes());
+        }
+
+        // Phrases as short as a SHA-512 block aren't hashed by HMAC
+        let mut expected = [0; SEED_BYTES];
+        super::seed("あい あか あさ", "", &mut expected);
+        let mut seed = [0; SEED_BYTES];
+        super::seed("あい\u{3000}あか\u{3000}あさ", "", &mut seed);
+        assert_eq!(seed, expected);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::error::Error;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// A language for the messages of [`Error::to_localized_string()`][to_localized_string]
+///
+/// [to_localized_string]: ./enum.Error.html#method.to_localized_string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, the messages of `Display`
+    #[default]
+    En,
+    /// Spanish
+    Es,
+    /// French
+    Fr,
+    /// Italian
+    It,
+    /// Portuguese
+    Pt,
+}
+
+impl Error {
+    /// Describe the error in `locale`, to show it to the end user
+    ///
+    /// The errors of entering a phrase, such as a wrong checksum, an unknown word or a wrong
+    /// number of words, and a wrong passphrase, are translated. Any other error, and every error
+    /// in `Locale::En`, is described in English, as `Display` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Error, Locale};
+    ///
+    /// let error = Error::InvalidChecksum;
+    ///
+    /// assert_eq!(error.to_localized_string(Locale::Es), "suma de verificación no válida");
+    /// assert_eq!(error.to_localized_string(Locale::En), "invalid checksum");
+    /// ```
+    pub fn to_localized_string(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::Es, Error::InvalidChecksum) => "suma de verificación no válida".into(),
+            (Locale::Fr, Error::InvalidChecksum) => "somme de contrôle invalide".into(),
+            (Locale::It, Error::InvalidChecksum) => "checksum non valido".into(),
+            (Locale::Pt, Error::InvalidChecksum) => "soma de verificação inválida".into(),
+
+            (Locale::Es, Error::InvalidWord { index, .. }) => {
+                format!("palabra no válida en la frase con índice {}", index)
+            }
+            (Locale::Fr, Error::InvalidWord { index, .. }) => {
+                format!("mot invalide dans la phrase à l'indice {}", index)
+            }
+            (Locale::It, Error::InvalidWord { index, .. }) => {
+                format!("parola non valida nella frase con indice {}", index)
+            }
+            (Locale::Pt, Error::InvalidWord { index, .. }) => {
+                format!("palavra inválida na frase com índice {}", index)
+            }
+
+            (Locale::Es, Error::InvalidWordCount(count)) => {
+                format!("número de palabras no válido en la frase: {}", count)
+            }
+            (Locale::Fr, Error::InvalidWordCount(count)) => {
+                format!("nombre de mots invalide dans la phrase : {}", count)
+            }
+            (Locale::It, Error::InvalidWordCount(count)) => {
+                format!("numero di parole non valido nella frase: {}", count)
+            }
+            (Locale::Pt, Error::InvalidWordCount(count)) => {
+                format!("número de palavras inválido na frase: {}", count)
+            }
+
+            (Locale::Es, Error::InvalidWhitespace) => "espacio inesperado en la frase".into(),
+            (Locale::Fr, Error::InvalidWhitespace) => "espace inattendu dans la phrase".into(),
+            (Locale::It, Error::InvalidWhitespace) => "spazio inatteso nella frase".into(),
+            (Locale::Pt, Error::InvalidWhitespace) => "espaço inesperado na frase".into(),
+
+            (Locale::Es, Error::PhraseTooLong(len)) => {
+                format!("frase demasiado larga: {} bytes", len)
+            }
+            (Locale::Fr, Error::PhraseTooLong(len)) => {
+                format!("phrase trop longue : {} octets", len)
+            }
+            (Locale::It, Error::PhraseTooLong(len)) => format!("frase troppo lunga: {} byte", len),
+            (Locale::Pt, Error::PhraseTooLong(len)) => format!("frase muito longa: {} bytes", len),
+
+            (Locale::Es, Error::UnknownLanguage) => {
+                "la frase no es válida en ningún idioma admitido".into()
+            }
+            (Locale::Fr, Error::UnknownLanguage) => {
+                "la phrase n'est valide dans aucune langue prise en charge".into()
+            }
+            (Locale::It, Error::UnknownLanguage) => {
+                "la frase non è valida in nessuna lingua supportata".into()
+            }
+            (Locale::Pt, Error::UnknownLanguage) => {
+                "a frase não é válida em nenhum idioma suportado".into()
+            }
+
+            (Locale::Es, Error::InvalidPassphrase) => {
+                "contraseña incorrecta o semilla dañada".into()
+            }
+            (Locale::Fr, Error::InvalidPassphrase) => {
+                "phrase secrète incorrecte ou graine corrompue".into()
+            }
+            (Locale::It, Error::InvalidPassphrase) => "passphrase errata o seed danneggiato".into(),
+            (Locale::Pt, Error::InvalidPassphrase) => {
+                "frase secreta incorreta ou semente corrompida".into()
+            }
+
+            _ => self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn localized_messages() {
+        let error = Error::InvalidWordCount(13);
+
+        assert_eq!(error.to_localized_string(Locale::En), error.to_string());
+        assert_eq!(
+            error.to_localized_string(Locale::Fr),
+            "nombre de mots invalide dans la phrase : 13",
+        );
+        assert_eq!(
+            Error::UnknownLanguage.to_localized_string(Locale::Pt),
+            "a frase não é válida em nenhum idioma suportado",
+        );
+
+        // Errors without a translation fall back to English
+        assert_eq!(
+            Error::InvalidKeystore.to_localized_string(Locale::It),
+            Error::InvalidKeystore.to_string(),
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn every_locale_translates_phrase_errors() {
+        let errors = [
+            Error::InvalidChecksum,
+            Error::InvalidWordCount(13),
+            Error::InvalidWhitespace,
+            Error::PhraseTooLong(2000),
+            Error::UnknownLanguage,
+            Error::InvalidPassphrase,
+        ];
+
+        for locale in [Locale::Es, Locale::Fr, Locale::It, Locale::Pt] {
+            for error in &errors {
+                assert_ne!(error.to_localized_string(locale), error.to_string());
+            }
+        }
+    }
+}
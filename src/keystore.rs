@@ -0,0 +1,422 @@
+//! Web3 Secret Storage keystore files, enabled with the `keystore` feature
+//!
+//! These are the version 3 JSON keystores of geth, MetaMask and most other Ethereum tools. They
+//! hold a secret, usually a private key, encrypted with AES-128-CTR under a key stretched from a
+//! password with scrypt, and authenticated with a Keccak-256 MAC. Writing uses scrypt, reading
+//! accepts PBKDF2-HMAC-SHA256 keystores too.
+//!
+//! ```
+//! use bip39::keystore;
+//! use bip39::{Language, Mnemonic, ScryptParams, Seed};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! let seed = Seed::new(&mnemonic, "");
+//!
+//! // Cheap parameters for the example, geth uses `log_n = 18`, `r = 8` and `p = 1`
+//! let params = ScryptParams::new(10, 8, 1).unwrap();
+//! let json = keystore::encrypt(seed.as_bytes(), "hunter2", &params).unwrap();
+//!
+//! assert_eq!(&keystore::decrypt(&json, "hunter2").unwrap()[..], seed.as_bytes());
+//! assert!(keystore::decrypt(&json, "hunter3").is_err());
+//! ```
+//!
+//! With the `bip32` feature, an account key from [`Seed::derive_path()`][Seed::derive_path()],
+//! such as the one at `m/44'/60'/0'/0/0`, can be stored the same way, and imported by any wallet
+//! that reads keystore files. Unlike seeds, the password is used as is, without Unicode
+//! normalization, as the other implementations do.
+//!
+//! [Seed::derive_path()]: ../seed/struct.Seed.html#method.derive_path
+
+use crate::crypto::{gen_random_bytes, pbkdf2_sha256};
+use crate::encoding::{from_hex, to_hex};
+use crate::error::Error;
+use crate::seed::ScryptParams;
+use aes::cipher::{KeyIvInit, StreamCipher};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::num::NonZeroU32;
+use rand_core::{CryptoRng, RngCore};
+use sha3::{Digest, Keccak256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// The version of the format, the only one in use
+const VERSION: u64 = 3;
+
+/// The cipher of every keystore file
+const CIPHER: &str = "aes-128-ctr";
+
+/// Length of the derived key, its first half is the AES key and its second half the MAC key
+const DKLEN: usize = 32;
+
+/// The largest parameters accepted, so that a crafted file can't make decrypting it take
+/// unbounded memory or time: 1 GiB of scrypt memory (`128 * r * n` bytes), 16 scrypt lanes and
+/// 10 million PBKDF2 rounds, well above what geth or MetaMask use
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+const MAX_SCRYPT_P: u32 = 16;
+const MAX_PBKDF2_ROUNDS: u32 = 10_000_000;
+
+const SALT_LEN: usize = 32;
+
+const IV_LEN: usize = 16;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Keystore {
+    #[serde(alias = "Crypto")]
+    crypto: Crypto,
+    id: String,
+    version: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum KdfParams {
+    Scrypt {
+        dklen: usize,
+        n: u64,
+        p: u32,
+        r: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: usize,
+        prf: String,
+        salt: String,
+    },
+}
+
+/// Encrypt `secret` under `password` into a keystore file, using entropy from the operating
+/// system CSPRNG
+#[cfg(feature = "rand")]
+pub fn encrypt(secret: &[u8], password: &str, params: &ScryptParams) -> Result<String, Error> {
+    encrypt_with_rng(&mut rand_core::OsRng, secret, password, params)
+}
+
+/// Encrypt `secret` under `password` into a keystore file, using entropy from `rng` for the salt,
+/// the IV and the UUID of the file
+///
+/// Returns `Error::InvalidKdfParams` if `params` take more than 1 GiB of memory or 16 lanes,
+/// which [`decrypt()`][decrypt] would refuse.
+///
+/// [decrypt]: ./fn.decrypt.html
+pub fn encrypt_with_rng<R>(
+    rng: &mut R,
+    secret: &[u8],
+    password: &str,
+    params: &ScryptParams,
+) -> Result<String, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let salt = gen_random_bytes(rng, SALT_LEN)?;
+    let iv = gen_random_bytes(rng, IV_LEN)?;
+    let mut id = gen_random_bytes(rng, 16)?;
+
+    let key = scrypt(password, &salt, params.log_n, params.r, params.p)?;
+    let mut ciphertext = secret.to_vec();
+    Aes128Ctr::new(key[..16].into(), iv[..].into()).apply_keystream(&mut ciphertext);
+
+    // A random, version 4 UUID
+    id[6] = (id[6] & 0x0f) | 0x40;
+    id[8] = (id[8] & 0x3f) | 0x80;
+
+    let keystore = Keystore {
+        crypto: Crypto {
+            cipher: CIPHER.into(),
+            cipherparams: CipherParams {
+                iv: to_hex(&iv).as_str().into(),
+            },
+            mac: to_hex(&mac(&key, &ciphertext)).as_str().into(),
+            ciphertext: to_hex(&ciphertext).as_str().into(),
+            kdf: "scrypt".into(),
+            kdfparams: KdfParams::Scrypt {
+                dklen: DKLEN,
+                n: 1 << params.log_n,
+                p: params.p,
+                r: params.r,
+                salt: to_hex(&salt).as_str().into(),
+            },
+        },
+        id: format!(
+            "{}-{}-{}-{}-{}",
+            to_hex(&id[..4]).as_str(),
+            to_hex(&id[4..6]).as_str(),
+            to_hex(&id[6..8]).as_str(),
+            to_hex(&id[8..10]).as_str(),
+            to_hex(&id[10..]).as_str(),
+        ),
+        version: VERSION,
+    };
+
+    Ok(serde_json::to_string(&keystore).expect("keystores serialize to JSON"))
+}
+
+/// Decrypt the secret of a keystore file with `password`
+///
+/// Returns `Error::InvalidPassphrase` if the MAC doesn't match, because the password is wrong or
+/// the file was altered, and `Error::UnsupportedVersion` for other versions than 3.
+/// `Error::InvalidKeystore` is returned for files that don't parse, or use another cipher or key
+/// derivation function, and `Error::InvalidKdfParams` for parameters the key derivation function
+/// doesn't accept, or that derive another key length than 32 bytes.
+///
+/// The scrypt or PBKDF2 parameters are read from the file, so to keep a file from an untrusted
+/// source from taking unbounded memory or time, they're rejected with `Error::InvalidKdfParams`
+/// before the password is stretched if scrypt would take more than 1 GiB of memory or 16
+/// lanes, or PBKDF2 more than 10 million rounds.
+pub fn decrypt(json: &str, password: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let keystore: Keystore = serde_json::from_str(json).map_err(|_| Error::InvalidKeystore)?;
+    let crypto = &keystore.crypto;
+
+    if keystore.version != VERSION {
+        return Err(Error::UnsupportedVersion(
+            u8::try_from(keystore.version).unwrap_or(u8::MAX),
+        ));
+    }
+    if !crypto.cipher.eq_ignore_ascii_case(CIPHER) {
+        return Err(Error::InvalidKeystore);
+    }
+
+    let iv = from_hex(&crypto.cipherparams.iv)
+        .ok()
+        .filter(|iv| iv.len() == IV_LEN);
+    let iv = iv.ok_or(Error::InvalidKeystore)?;
+    let ciphertext = from_hex(&crypto.ciphertext)
+        .ok()
+        .ok_or(Error::InvalidKeystore)?;
+    let expected_mac = from_hex(&crypto.mac).ok().ok_or(Error::InvalidKeystore)?;
+
+    let key = match (&crypto.kdf[..], &crypto.kdfparams) {
+        (
+            "scrypt",
+            &KdfParams::Scrypt {
+                dklen,
+                n,
+                p,
+                r,
+                ref salt,
+            },
+        ) => {
+            if !n.is_power_of_two() || n > u64::from(u32::MAX) || dklen != DKLEN {
+                return Err(Error::InvalidKdfParams);
+            }
+            let salt = from_hex(salt).ok().ok_or(Error::InvalidKeystore)?;
+
+            scrypt(password, &salt, n.trailing_zeros() as u8, r, p)?
+        }
+        (
+            "pbkdf2",
+            &KdfParams::Pbkdf2 {
+                c,
+                dklen,
+                ref prf,
+                ref salt,
+            },
+        ) => {
+            if prf != "hmac-sha256" {
+                return Err(Error::InvalidKeystore);
+            }
+            let c = NonZeroU32::new(c).filter(|&c| c.get() <= MAX_PBKDF2_ROUNDS);
+            let c = c
+                .filter(|_| dklen == DKLEN)
+                .ok_or(Error::InvalidKdfParams)?;
+            let salt = from_hex(salt).ok().ok_or(Error::InvalidKeystore)?;
+            let mut key = Zeroizing::new(vec![0; dklen]);
+            pbkdf2_sha256(password.as_bytes(), &salt, c, &mut key);
+
+            key
+        }
+        _ => return Err(Error::InvalidKeystore),
+    };
+
+    if !bool::from(mac(&key, &ciphertext)[..].ct_eq(&expected_mac)) {
+        return Err(Error::InvalidPassphrase);
+    }
+
+    let mut secret = ciphertext;
+    Aes128Ctr::new(key[..16].into(), iv[..].into()).apply_keystream(&mut secret);
+
+    Ok(secret)
+}
+
+/// Stretch the password with scrypt, into `DKLEN` bytes
+///
+/// Returns `Error::InvalidKdfParams` above `MAX_SCRYPT_MEMORY` or `MAX_SCRYPT_P`.
+fn scrypt(
+    password: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let memory = 128u64
+        .checked_shl(log_n.into())
+        .and_then(|memory| memory.checked_mul(r.into()));
+    if memory.is_none_or(|memory| memory > MAX_SCRYPT_MEMORY) || p > MAX_SCRYPT_P {
+        return Err(Error::InvalidKdfParams);
+    }
+
+    let params = scrypt::Params::new(log_n, r, p, DKLEN).map_err(|_| Error::InvalidKdfParams)?;
+    let mut key = Zeroizing::new(vec![0; DKLEN]);
+
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| Error::InvalidKdfParams)?;
+
+    Ok(key)
+}
+
+/// The Keccak-256 MAC of the ciphertext, under the second half of the derived key
+fn mac(key: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(&key[16..32])
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    /// The PBKDF2 test vector of the Web3 Secret Storage definition
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+            },
+            "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf" : "pbkdf2",
+            "kdfparams" : {
+                "c" : 262144,
+                "dklen" : 32,
+                "prf" : "hmac-sha256",
+                "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    const PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_vector() {
+        let secret = decrypt(PBKDF2_KEYSTORE, "testpassword").unwrap();
+
+        assert_eq!(to_hex(&secret).as_str(), PRIVATE_KEY);
+        assert_eq!(
+            decrypt(PBKDF2_KEYSTORE, "testpassword2"),
+            Err(Error::InvalidPassphrase)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn roundtrip() {
+        let secret = from_hex(PRIVATE_KEY).ok().unwrap();
+        let params = ScryptParams::new(10, 8, 1).unwrap();
+        let json =
+            encrypt_with_rng(&mut StdRng::seed_from_u64(0), &secret, "hunter2", &params).unwrap();
+
+        let keystore: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(keystore["version"], 3);
+        assert_eq!(keystore["crypto"]["kdfparams"]["n"], 1024);
+        assert_eq!(keystore["id"].as_str().unwrap().as_bytes()[14], b'4');
+
+        assert_eq!(&decrypt(&json, "hunter2").unwrap()[..], &secret[..]);
+
+        let mut altered = keystore.clone();
+        altered["crypto"]["ciphertext"] = to_hex(&[0; 32]).as_str().into();
+        assert_eq!(
+            decrypt(&altered.to_string(), "hunter2"),
+            Err(Error::InvalidPassphrase)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn errors() {
+        let newer = PBKDF2_KEYSTORE.replace(r#""version" : 3"#, r#""version" : 4"#);
+        assert_eq!(
+            decrypt(&newer, "testpassword"),
+            Err(Error::UnsupportedVersion(4))
+        );
+
+        let cbc = PBKDF2_KEYSTORE.replace("aes-128-ctr", "aes-128-cbc");
+        assert_eq!(decrypt(&cbc, "testpassword"), Err(Error::InvalidKeystore));
+
+        let no_rounds = PBKDF2_KEYSTORE.replace("262144", "0");
+        assert_eq!(
+            decrypt(&no_rounds, "testpassword"),
+            Err(Error::InvalidKdfParams)
+        );
+
+        assert_eq!(decrypt("{}", "testpassword"), Err(Error::InvalidKeystore));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn limits() {
+        let long_key = PBKDF2_KEYSTORE.replace(r#""dklen" : 32"#, r#""dklen" : 4096"#);
+        assert_eq!(
+            decrypt(&long_key, "testpassword"),
+            Err(Error::InvalidKdfParams)
+        );
+
+        let too_many_rounds = PBKDF2_KEYSTORE.replace("262144", "4294967295");
+        assert_eq!(
+            decrypt(&too_many_rounds, "testpassword"),
+            Err(Error::InvalidKdfParams)
+        );
+
+        let secret = from_hex(PRIVATE_KEY).ok().unwrap();
+        let params = ScryptParams::new(10, 8, 1).unwrap();
+        let json =
+            encrypt_with_rng(&mut StdRng::seed_from_u64(0), &secret, "hunter2", &params).unwrap();
+        let keystore: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        for (param, value) in [
+            ("dklen", 64u64),
+            ("n", 1 << 31),
+            ("r", 1 << 20),
+            ("p", 1 << 16),
+        ] {
+            let mut oversized = keystore.clone();
+            oversized["crypto"]["kdfparams"][param] = value.into();
+            let result = decrypt(&oversized.to_string(), "hunter2");
+            assert_eq!(result, Err(Error::InvalidKdfParams), "{}", param);
+        }
+
+        let params = ScryptParams::new(30, 8, 1).unwrap();
+        let result = encrypt_with_rng(&mut StdRng::seed_from_u64(0), &secret, "", &params);
+        assert_eq!(result, Err(Error::InvalidKdfParams));
+    }
+}
@@ -1,52 +1,360 @@
-use crate::util::{Bits, Bits11};
+use crate::error::Error;
+use crate::mnemonic::Mnemonic;
+use crate::util::{edit_distance, unaccented, Bits, Bits11};
+#[cfg(all(not(feature = "std"), not(feature = "constant-time")))]
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(all(feature = "std", not(feature = "constant-time")))]
 use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+use unicode_normalization::UnicodeNormalization;
 
+/// The map from the words of a [`WordList`][WordList] to their indices, see
+/// [`Language::wordmap()`][Language::wordmap()]
+///
+/// [WordList]: ./language/struct.WordList.html
+/// [Language::wordmap()]: ./language/enum.Language.html#method.wordmap
 pub struct WordMap {
-    inner: FxHashMap<&'static str, Bits11>,
+    inner: WordMapInner,
+}
+
+enum WordMapInner {
+    /// A perfect hash map of a built-in word list, generated by the build script
+    #[cfg(not(any(feature = "binary-search", feature = "constant-time")))]
+    Static(&'static phf::Map<&'static str, u16>),
+    /// A built-in word list and the indices of its words in sorted order, generated by the build
+    /// script
+    #[cfg(all(feature = "binary-search", not(feature = "constant-time")))]
+    Sorted(&'static WordList, &'static [u16; 2048]),
+    /// The map of a custom word list, built when it's created
+    #[cfg(all(feature = "std", not(feature = "constant-time")))]
+    Custom(FxHashMap<&'static str, Bits11>),
+    #[cfg(all(not(feature = "std"), not(feature = "constant-time")))]
+    Custom(BTreeMap<&'static str, Bits11>),
+    /// The words of any word list, all compared to the word looked up in constant time
+    #[cfg(feature = "constant-time")]
+    Scan([&'static str; 2048]),
 }
 
+/// The 2048 words of a language, each word's index being its 11 bit value in a phrase, see
+/// [`Language::wordlist()`][Language::wordlist()]
+///
+/// These are the exact lists phrases are validated against, NFKD normalized.
+///
+/// # Example
+///
+/// ```
+/// use bip39::Language;
+///
+/// let wordlist = Language::English.wordlist();
+///
+/// assert_eq!(wordlist.get(1002), Some("later"));
+/// assert_eq!(wordlist.index_of("later"), Some(1002));
+/// assert!(wordlist.contains("zoo"));
+/// assert_eq!(wordlist.iter().count(), 2048);
+/// ```
+///
+/// [Language::wordlist()]: ./language/enum.Language.html#method.wordlist
+#[derive(PartialEq, Eq, Hash)]
 pub struct WordList {
-    inner: Vec<&'static str>,
+    inner: [&'static str; 2048],
+    /// The same words in byte order, which most word lists aren't in
+    sorted: [&'static str; 2048],
 }
 
 impl WordMap {
     pub fn get_bits(&self, word: &str) -> Option<Bits11> {
-        self.inner.get(word).cloned()
+        match &self.inner {
+            #[cfg(not(any(feature = "binary-search", feature = "constant-time")))]
+            WordMapInner::Static(map) => map.get(word).map(|&index| Bits11::from(index)),
+            #[cfg(all(feature = "binary-search", not(feature = "constant-time")))]
+            WordMapInner::Sorted(wordlist, sorted) => sorted
+                .binary_search_by(|&index| wordlist.inner[index as usize].cmp(word))
+                .ok()
+                .map(|position| Bits11::from(sorted[position])),
+            #[cfg(not(feature = "constant-time"))]
+            WordMapInner::Custom(map) => map.get(word).cloned(),
+            #[cfg(feature = "constant-time")]
+            WordMapInner::Scan(words) => scan(words, word).map(Bits11::from),
+        }
+    }
+
+    /// The index of `word` in the word list
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        self.get_bits(word).map(u16::from)
+    }
+
+    /// Whether `word` is in the word list
+    pub fn contains(&self, word: &str) -> bool {
+        self.get_bits(word).is_some()
     }
 }
 
+/// Find `word` in `words` by comparing it to every one of them in constant time, so that the
+/// time taken only depends on the length of `word`, not on which word it is, or whether it's
+/// in the list
+#[cfg(feature = "constant-time")]
+fn scan(words: &[&'static str; 2048], word: &str) -> Option<u16> {
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    let mut found = Choice::from(0);
+    let mut index = 0u16;
+
+    for (i, candidate) in words.iter().enumerate() {
+        let is_word = candidate.as_bytes().ct_eq(word.as_bytes());
+
+        index.conditional_assign(&(i as u16), is_word);
+        found |= is_word;
+    }
+
+    Option::from(subtle::CtOption::new(index, found))
+}
+
 impl WordList {
+    /// All the words, in the order of their indices
+    ///
+    /// The word lists of built-in languages are `static`, so `Language::wordlist()` gives a
+    /// `&'static [&'static str; 2048]`.
+    pub fn words(&self) -> &[&'static str; 2048] {
+        &self.inner
+    }
+
+    /// The word at `index`, if it's less than 2048
+    pub fn get(&self, index: u16) -> Option<&'static str> {
+        self.inner.get(index as usize).copied()
+    }
+
+    /// The index of `word`, found by going through the words one by one
+    ///
+    /// Looking words up in the [`WordMap`][WordMap] of the language is faster, see
+    /// [`Language::index_of()`][Language::index_of()].
+    ///
+    /// [WordMap]: ./language/struct.WordMap.html
+    /// [Language::index_of()]: ./language/enum.Language.html#method.index_of
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        self.inner
+            .iter()
+            .position(|&w| w == word)
+            .map(|index| index as u16)
+    }
+
+    /// Whether `word` is in the word list
+    pub fn contains(&self, word: &str) -> bool {
+        self.sorted.binary_search(&word).is_ok()
+    }
+
+    /// All the words, in the order of their indices
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'static str> + '_ {
+        self.inner.iter().copied()
+    }
+
     pub fn get_word(&self, bits: Bits11) -> &'static str {
         self.inner[bits.bits() as usize]
     }
 
+    /// All the words starting with `prefix`, in byte order
     pub fn get_words_by_prefix(&self, prefix: &str) -> &[&'static str] {
-        let start = self.inner
-            .binary_search(&prefix)
-            .unwrap_or_else(|idx| idx);
-        let count = self.inner[start..].iter()
+        let start = self.sorted.binary_search(&prefix).unwrap_or_else(|idx| idx);
+        let count = self.sorted[start..]
+            .iter()
             .take_while(|word| word.starts_with(prefix))
             .count();
 
-        &self.inner[start..start + count]
+        &self.sorted[start..start + count]
+    }
+
+    /// Find the words closest to a misspelled `word`, best match first
+    ///
+    /// These are words within an edit distance of 2, or sharing the first four characters.
+    pub(crate) fn suggestions(&self, word: &str) -> Vec<&'static str> {
+        const MAX_SUGGESTIONS: usize = 5;
+        const MAX_DISTANCE: usize = 2;
+
+        let prefix: String = word.chars().take(4).collect();
+        let has_prefix = prefix.chars().count() == 4;
+
+        let mut candidates: Vec<(usize, &'static str)> = self
+            .inner
+            .iter()
+            .filter_map(|&candidate| {
+                let distance = edit_distance(word, candidate);
+
+                if distance <= MAX_DISTANCE {
+                    Some((distance, candidate))
+                } else if has_prefix && candidate.starts_with(&prefix) {
+                    Some((MAX_DISTANCE + 1, candidate))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // stable, so words at the same distance stay in wordlist order
+        candidates.sort_by_key(|&(distance, _)| distance);
+
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    /// Find the only word starting with `prefix`, if the prefix is at least 4 characters long
+    ///
+    /// The prefix is looked up by binary search in the copy of the list sorted by code point, as
+    /// not all wordlists are sorted that way once NFKD normalized.
+    pub(crate) fn expand_prefix(&self, prefix: &str) -> Option<&'static str> {
+        if prefix.chars().count() < 4 {
+            return None;
+        }
+
+        match self.get_words_by_prefix(prefix) {
+            [word] => Some(word),
+            _ => None,
+        }
+    }
+
+    /// Find the only word spelled like `word` once the accents of both are removed, or the only
+    /// one starting with it if `prefix` is set and it's at least 4 characters long
+    ///
+    /// No two words of the built-in lists differ only by their accents. `word` must be NFKD
+    /// normalized, like the lists are.
+    pub(crate) fn match_unaccented(&self, word: &str, prefix: bool) -> Option<&'static str> {
+        if prefix && unaccented(word).count() < 4 {
+            return None;
+        }
+
+        let mut matches = self.inner.iter().copied().filter(|candidate| {
+            let mut candidate = unaccented(candidate);
+
+            unaccented(word).all(|c| candidate.next() == Some(c))
+                && (prefix || candidate.next().is_none())
+        });
+
+        match (matches.next(), matches.next()) {
+            (Some(word), None) => Some(word),
+            _ => None,
+        }
     }
 }
 
-mod lazy {
-    use super::{Bits11, WordList, WordMap};
-    use once_cell::sync::Lazy;
+/// A user supplied list of 2048 words, to be used as [`Language::Custom`][Language::Custom]
+///
+/// Since [`Language`][Language] is `Copy`, it only holds a `&'static` reference to the list, so
+/// the list is typically stored in a `static`:
+///
+/// ```
+/// use bip39::{CustomWordlist, Language, Mnemonic};
+/// use once_cell::sync::Lazy;
+///
+/// static NUMBERED: Lazy<CustomWordlist> = Lazy::new(|| {
+///     let words = (0..2048)
+///         .map(|i| &*Box::leak(format!("w{}", i).into_boxed_str()))
+///         .collect();
+///
+///     CustomWordlist::from_words(words).unwrap()
+/// });
+///
+/// let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::Custom(&NUMBERED)).unwrap();
+///
+/// assert_eq!(mnemonic.phrase(), "w0 w0 w0 w0 w0 w0 w0 w0 w0 w0 w0 w3");
+/// assert!(Mnemonic::validate(mnemonic.phrase(), Language::Custom(&NUMBERED)).is_ok());
+/// ```
+///
+/// [Language]: ./language/enum.Language.html
+/// [Language::Custom]: ./language/enum.Language.html#variant.Custom
+pub struct CustomWordlist {
+    wordlist: WordList,
+    wordmap: WordMap,
+}
 
-    /// lazy generation of the word list
-    fn gen_wordlist(lang_words: &'static str) -> WordList {
-        let inner: Vec<_> = lang_words.split_whitespace().collect();
+impl CustomWordlist {
+    /// Create a custom word list from exactly 2048 unique words.
+    ///
+    /// The words are used as they are, so they must be NFKD normalized and contain no whitespace
+    /// or control characters, such as the NUL that C strings end at, otherwise `Error::InvalidWordlistWord` is returned with the index of the first offending
+    /// word. A list of any other length returns `Error::InvalidWordlistLength`.
+    pub fn from_words(words: Vec<&'static str>) -> Result<CustomWordlist, Error> {
+        let inner = <[&'static str; 2048]>::try_from(words)
+            .map_err(|words| Error::InvalidWordlistLength(words.len()))?;
 
-        debug_assert!(inner.len() == 2048, "Invalid wordlist length");
+        let mut sorted = inner;
+        sorted.sort_unstable();
 
-        WordList { inner }
+        let wordlist = WordList { inner, sorted };
+        let wordmap = lazy::gen_wordmap(&wordlist);
+
+        for (idx, word) in wordlist.inner.iter().enumerate() {
+            let is_valid = !word.is_empty()
+                && !word.chars().any(|c| c.is_whitespace() || c.is_control())
+                && unicode_normalization::is_nfkd(word)
+                // duplicates map to the index of their last occurrence
+                && wordmap.get_bits(word).map(|bits| bits.bits() as usize) == Some(idx);
+
+            if !is_valid {
+                return Err(Error::InvalidWordlistWord(idx));
+            }
+        }
+
+        Ok(CustomWordlist { wordlist, wordmap })
+    }
+}
+
+/// Only compares (and hashes) the word lists, since the word maps are derived from them
+impl PartialEq for CustomWordlist {
+    fn eq(&self, other: &Self) -> bool {
+        self.wordlist == other.wordlist
     }
+}
 
-    /// lazy generation of the word map
-    fn gen_wordmap(wordlist: &WordList) -> WordMap {
+impl Eq for CustomWordlist {}
+
+impl Hash for CustomWordlist {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.wordlist.hash(state);
+    }
+}
+
+impl fmt::Debug for CustomWordlist {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CustomWordlist")
+            .field(&self.wordlist.inner.first())
+            .field(&"..")
+            .finish()
+    }
+}
+
+mod lazy {
+    #[cfg(not(feature = "constant-time"))]
+    use super::Bits11;
+    #[cfg(feature = "compressed-wordlists")]
+    use super::CustomWordlist;
+    use super::{WordList, WordMap, WordMapInner};
+    #[cfg(feature = "compressed-wordlists")]
+    use crate::util::Lazy;
+    #[cfg(feature = "compressed-wordlists")]
+    use alloc::string::String;
+    #[cfg(feature = "compressed-wordlists")]
+    use core::ops::Deref;
+
+    /// generation of the word map of a custom word list
+    #[cfg(feature = "constant-time")]
+    pub(super) fn gen_wordmap(wordlist: &WordList) -> WordMap {
+        WordMap {
+            inner: WordMapInner::Scan(wordlist.inner),
+        }
+    }
+
+    /// generation of the word map of a custom word list
+    #[cfg(not(feature = "constant-time"))]
+    pub(super) fn gen_wordmap(wordlist: &WordList) -> WordMap {
         let inner = wordlist
             .inner
             .iter()
@@ -54,50 +362,55 @@ mod lazy {
             .map(|(i, item)| (*item, Bits11::from(i as u16)))
             .collect();
 
-        WordMap { inner }
+        WordMap {
+            inner: WordMapInner::Custom(inner),
+        }
     }
 
-    pub static WORDLIST_ENGLISH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/english.txt")));
-    #[cfg(feature = "chinese-simplified")]
-    pub static WORDLIST_CHINESE_SIMPLIFIED: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/chinese_simplified.txt")));
-    #[cfg(feature = "chinese-traditional")]
-    pub static WORDLIST_CHINESE_TRADITIONAL: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/chinese_traditional.txt")));
-    #[cfg(feature = "french")]
-    pub static WORDLIST_FRENCH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/french.txt")));
-    #[cfg(feature = "italian")]
-    pub static WORDLIST_ITALIAN: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/italian.txt")));
-    #[cfg(feature = "japanese")]
-    pub static WORDLIST_JAPANESE: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/japanese.txt")));
-    #[cfg(feature = "korean")]
-    pub static WORDLIST_KOREAN: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/korean.txt")));
-    #[cfg(feature = "spanish")]
-    pub static WORDLIST_SPANISH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/spanish.txt")));
+    /// The word list of a built-in language embedded deflated, built on first use
+    #[cfg(feature = "compressed-wordlists")]
+    pub struct LazyWordList(&'static Lazy<CustomWordlist>);
 
-    pub static WORDMAP_ENGLISH: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_ENGLISH));
-    #[cfg(feature = "chinese-simplified")]
-    pub static WORDMAP_CHINESE_SIMPLIFIED: Lazy<WordMap> =
-        Lazy::new(|| gen_wordmap(&WORDLIST_CHINESE_SIMPLIFIED));
-    #[cfg(feature = "chinese-traditional")]
-    pub static WORDMAP_CHINESE_TRADITIONAL: Lazy<WordMap> =
-        Lazy::new(|| gen_wordmap(&WORDLIST_CHINESE_TRADITIONAL));
-    #[cfg(feature = "french")]
-    pub static WORDMAP_FRENCH: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_FRENCH));
-    #[cfg(feature = "italian")]
-    pub static WORDMAP_ITALIAN: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_ITALIAN));
-    #[cfg(feature = "japanese")]
-    pub static WORDMAP_JAPANESE: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_JAPANESE));
-    #[cfg(feature = "korean")]
-    pub static WORDMAP_KOREAN: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_KOREAN));
-    #[cfg(feature = "spanish")]
-    pub static WORDMAP_SPANISH: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_SPANISH));
+    #[cfg(feature = "compressed-wordlists")]
+    impl Deref for LazyWordList {
+        type Target = WordList;
+
+        fn deref(&self) -> &WordList {
+            &self.0.wordlist
+        }
+    }
+
+    /// The word map of a built-in language embedded deflated, built on first use
+    #[cfg(feature = "compressed-wordlists")]
+    pub struct LazyWordMap(&'static Lazy<CustomWordlist>);
+
+    #[cfg(feature = "compressed-wordlists")]
+    impl Deref for LazyWordMap {
+        type Target = WordMap;
+
+        fn deref(&self) -> &WordMap {
+            &self.0.wordmap
+        }
+    }
+
+    /// The words of a built-in language, one per line, deflated by the build script
+    #[cfg(feature = "compressed-wordlists")]
+    fn inflate(deflated: &[u8]) -> String {
+        let text = miniz_oxide::inflate::decompress_to_vec(deflated)
+            .expect("the build script deflates the word lists");
+
+        String::from_utf8(text).expect("the word lists are UTF-8")
+    }
+
+    /// The word list and word map of the words of a built-in language, one per line
+    #[cfg(feature = "compressed-wordlists")]
+    fn from_text(text: &'static str) -> CustomWordlist {
+        CustomWordlist::from_words(text.split('\n').collect())
+            .expect("the built-in word lists have 2048 unique NFKD normalized words")
+    }
+
+    // The `WORDLIST_*` word lists and `WORDMAP_*` word maps of the built-in languages
+    include!(concat!(env!("OUT_DIR"), "/wordlists.rs"));
 }
 
 /// The language determines which words will be used in a mnemonic phrase, but also indirectly
@@ -108,13 +421,16 @@ mod lazy {
 ///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
 /// [Seed]: ./seed/struct.Seed.html
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Language {
+    #[default]
     English,
     #[cfg(feature = "chinese-simplified")]
     ChineseSimplified,
     #[cfg(feature = "chinese-traditional")]
     ChineseTraditional,
+    #[cfg(feature = "czech")]
+    Czech,
     #[cfg(feature = "french")]
     French,
     #[cfg(feature = "italian")]
@@ -123,20 +439,262 @@ pub enum Language {
     Japanese,
     #[cfg(feature = "korean")]
     Korean,
+    #[cfg(feature = "portuguese")]
+    Portuguese,
     #[cfg(feature = "spanish")]
     Spanish,
+    /// A user supplied word list, see [`CustomWordlist`][CustomWordlist]
+    ///
+    /// [CustomWordlist]: ./language/struct.CustomWordlist.html
+    Custom(&'static CustomWordlist),
 }
 
+/// The custom word lists added with `Language::register()`, with their language codes
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<(&'static str, &'static CustomWordlist)>> = RwLock::new(Vec::new());
+
+/// All built-in languages, in the order they are tried when detecting the language of a phrase
+pub(crate) const BUILTIN_LANGUAGES: &[Language] = &[
+    Language::English,
+    #[cfg(feature = "chinese-simplified")]
+    Language::ChineseSimplified,
+    #[cfg(feature = "chinese-traditional")]
+    Language::ChineseTraditional,
+    #[cfg(feature = "czech")]
+    Language::Czech,
+    #[cfg(feature = "french")]
+    Language::French,
+    #[cfg(feature = "italian")]
+    Language::Italian,
+    #[cfg(feature = "japanese")]
+    Language::Japanese,
+    #[cfg(feature = "korean")]
+    Language::Korean,
+    #[cfg(feature = "portuguese")]
+    Language::Portuguese,
+    #[cfg(feature = "spanish")]
+    Language::Spanish,
+];
+
 impl Language {
-    /// Construct a word list from its language code. Returns None
-    /// if the language code is not valid or not supported.
+    /// Iterate over all built-in languages enabled at compile time, English first
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// for lang in Language::all() {
+    ///     println!("{:?}: {}", lang, lang.code().unwrap());
+    /// }
+    ///
+    /// assert_eq!(Language::all().next(), Some(Language::English));
+    /// ```
+    pub fn all() -> impl ExactSizeIterator<Item = Language> + Clone {
+        BUILTIN_LANGUAGES.iter().copied()
+    }
+
+    /// Register a custom word list under a language `code`, so that it's handled like the built-in
+    /// languages
+    ///
+    /// The returned [`Language::Custom`][Language::Custom] is detected by
+    /// [`Language::detect()`][Language::detect()] after the built-in languages, parsed from its
+    /// code by [`Language::from_language_code()`][Language::from_language_code()] and `FromStr`,
+    /// and has the code as its [`Language::code()`][Language::code()], so it can be serialized.
+    /// Codes are case insensitive, `Error::LanguageCodeInUse` is returned for the code of a
+    /// built-in language or of one registered before. Registered languages stay registered, their
+    /// word list and code are leaked to get `'static` references to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{CustomWordlist, Language, Mnemonic};
+    ///
+    /// let words = (0..2048).map(|i| &*Box::leak(format!("k{}", i).into_boxed_str())).collect();
+    /// let klingon = Language::register("tlh", CustomWordlist::from_words(words).unwrap()).unwrap();
+    ///
+    /// let mnemonic = Mnemonic::from_entropy(&[0; 16], klingon).unwrap();
+    ///
+    /// assert_eq!(Language::detect(mnemonic.phrase()), Ok(klingon));
+    /// assert_eq!(Language::from_language_code("tlh"), Some(klingon));
+    /// assert_eq!(klingon.code(), Some("tlh"));
+    /// ```
+    ///
+    /// [Language::Custom]: ./language/enum.Language.html#variant.Custom
+    /// [Language::detect()]: ./language/enum.Language.html#method.detect
+    /// [Language::from_language_code()]: ./language/enum.Language.html#method.from_language_code
+    /// [Language::code()]: ./language/enum.Language.html#method.code
+    #[cfg(feature = "std")]
+    pub fn register(code: &str, wordlist: CustomWordlist) -> Result<Language, Error> {
+        let code = code.to_ascii_lowercase();
+        let mut registry = REGISTRY.write().unwrap_or_else(|err| err.into_inner());
+
+        // The registry is locked already, so it's searched directly
+        let registered = registry.iter().any(|&(registered, _)| registered == code);
+        if registered || Language::builtin_from_code(&code).is_some() {
+            return Err(Error::LanguageCodeInUse(code));
+        }
+
+        let wordlist: &'static CustomWordlist = alloc::boxed::Box::leak(wordlist.into());
+        registry.push((alloc::boxed::Box::leak(code.into_boxed_str()), wordlist));
+
+        Ok(Language::Custom(wordlist))
+    }
+
+    /// Get the languages added with [`Language::register()`][Language::register()], in the order
+    /// they were registered
+    ///
+    /// [Language::register()]: ./language/enum.Language.html#method.register
+    #[cfg(feature = "std")]
+    pub fn registered() -> Vec<Language> {
+        let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+
+        registry
+            .iter()
+            .map(|&(_, wordlist)| Language::Custom(wordlist))
+            .collect()
+    }
+
+    /// The built-in languages, followed by the registered ones
+    pub(crate) fn known() -> Vec<Language> {
+        #[cfg(feature = "std")]
+        return Language::all().chain(Language::registered()).collect();
+        #[cfg(not(feature = "std"))]
+        return Language::all().collect();
+    }
+
+    /// The language registered under `code`, which is lowercase
+    fn registered_by_code(code: &str) -> Option<Language> {
+        #[cfg(feature = "std")]
+        {
+            let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+
+            registry
+                .iter()
+                .find(|&&(registered, _)| registered == code)
+                .map(|&(_, wordlist)| Language::Custom(wordlist))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = code;
+            None
+        }
+    }
+
+    /// The code `wordlist` was registered under, if it was
+    fn registered_code(wordlist: &'static CustomWordlist) -> Option<&'static str> {
+        #[cfg(feature = "std")]
+        {
+            let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+
+            registry
+                .iter()
+                .find(|&&(_, registered)| core::ptr::eq(registered, wordlist))
+                .map(|&(code, _)| code)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = wordlist;
+            None
+        }
+    }
+
+    /// Detect the language of a mnemonic phrase
+    ///
+    /// Every built-in language enabled at compile time is tried in turn, then every registered
+    /// one, and the first one in which the phrase is fully valid, checksum included, is returned.
+    /// If there is no such language, an `Error::UnknownLanguage` is returned. Use [`Language::detect_all()`][Language::detect_all()]
+    /// for phrases that may be valid in more than one language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// assert_eq!(Language::detect(phrase).unwrap(), Language::English);
+    /// ```
+    ///
+    /// [Language::detect_all()]: ./language/enum.Language.html#method.detect_all
+    pub fn detect(phrase: &str) -> Result<Language, Error> {
+        Language::known()
+            .into_iter()
+            .find(|&lang| Mnemonic::validate(phrase, lang).is_ok())
+            .ok_or(Error::UnknownLanguage)
+    }
+
+    /// Detect every language a mnemonic phrase is valid in
+    ///
+    /// About a hundred words are on both the English and French lists, so a phrase can be valid,
+    /// checksum included, in both. Where [`Language::detect()`][Language::detect()] picks the
+    /// first language, this returns all of them, in the order of
+    /// [`Language::all()`][Language::all()] followed by the registered languages, to let the
+    /// caller ask the user. The result is empty if the phrase isn't valid in any language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// assert_eq!(Language::detect_all(phrase), [Language::English]);
+    /// ```
+    ///
+    /// [Language::detect()]: ./language/enum.Language.html#method.detect
+    /// [Language::all()]: ./language/enum.Language.html#method.all
+    pub fn detect_all(phrase: &str) -> Vec<Language> {
+        Language::known()
+            .into_iter()
+            .filter(|&lang| Mnemonic::validate(phrase, lang).is_ok())
+            .collect()
+    }
+
+    /// Get every built-in or registered language whose word list contains `word`, in the order of
+    /// [`Language::all()`][Language::all()] followed by the registered languages
+    ///
+    /// The word is NFKD normalized first, so precomposed accented letters match, but it must be
+    /// lowercase. Knowing the languages of each word helps guess the language of a phrase as it's
+    /// typed, and tell when it mixes words from different lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::languages_containing("zoo"), [Language::English]);
+    /// assert!(Language::languages_containing("zo").is_empty());
+    /// ```
+    ///
+    /// [Language::all()]: ./language/enum.Language.html#method.all
+    pub fn languages_containing(word: &str) -> Vec<Language> {
+        let word: String = word.nfkd().collect();
+
+        Language::known()
+            .into_iter()
+            .filter(|lang| lang.wordmap().contains(&word))
+            .collect()
+    }
+
+    /// Construct a word list from its language code, that of a built-in language or of a
+    /// registered one. Returns None if the language code is not valid or not supported.
     pub fn from_language_code(language_code: &str) -> Option<Self> {
-        match &language_code.to_ascii_lowercase()[..] {
+        let code = language_code.to_ascii_lowercase();
+
+        Language::builtin_from_code(&code).or_else(|| Language::registered_by_code(&code))
+    }
+
+    /// The built-in language of a lowercase language `code`
+    fn builtin_from_code(code: &str) -> Option<Self> {
+        match code {
             "en" => Some(Language::English),
             #[cfg(feature = "chinese-simplified")]
             "zh-hans" => Some(Language::ChineseSimplified),
             #[cfg(feature = "chinese-traditional")]
             "zh-hant" => Some(Language::ChineseTraditional),
+            #[cfg(feature = "czech")]
+            "cs" => Some(Language::Czech),
             #[cfg(feature = "french")]
             "fr" => Some(Language::French),
             #[cfg(feature = "italian")]
@@ -145,12 +703,218 @@ impl Language {
             "ja" => Some(Language::Japanese),
             #[cfg(feature = "korean")]
             "ko" => Some(Language::Korean),
+            #[cfg(feature = "portuguese")]
+            "pt" => Some(Language::Portuguese),
             #[cfg(feature = "spanish")]
             "es" => Some(Language::Spanish),
             _ => None,
         }
     }
 
+    /// Construct a word list from the lowercase name of its language, the name of the Cargo
+    /// feature enabling it, such as `"chinese-simplified"`
+    #[cfg(feature = "serde")]
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Language::English),
+            #[cfg(feature = "chinese-simplified")]
+            "chinese-simplified" => Some(Language::ChineseSimplified),
+            #[cfg(feature = "chinese-traditional")]
+            "chinese-traditional" => Some(Language::ChineseTraditional),
+            #[cfg(feature = "czech")]
+            "czech" => Some(Language::Czech),
+            #[cfg(feature = "french")]
+            "french" => Some(Language::French),
+            #[cfg(feature = "italian")]
+            "italian" => Some(Language::Italian),
+            #[cfg(feature = "japanese")]
+            "japanese" => Some(Language::Japanese),
+            #[cfg(feature = "korean")]
+            "korean" => Some(Language::Korean),
+            #[cfg(feature = "portuguese")]
+            "portuguese" => Some(Language::Portuguese),
+            #[cfg(feature = "spanish")]
+            "spanish" => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    /// Get the language code of a built-in language, the inverse of
+    /// [`Language::from_language_code()`][Language::from_language_code()]
+    ///
+    /// Returns `None` for a custom word list, unless it was registered with
+    /// [`Language::register()`][Language::register()].
+    ///
+    /// [Language::register()]: ./language/enum.Language.html#method.register
+    /// [Language::from_language_code()]: ./language/enum.Language.html#method.from_language_code
+    pub fn code(&self) -> Option<&'static str> {
+        match *self {
+            Language::English => Some("en"),
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => Some("zh-hans"),
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => Some("zh-hant"),
+            #[cfg(feature = "czech")]
+            Language::Czech => Some("cs"),
+            #[cfg(feature = "french")]
+            Language::French => Some("fr"),
+            #[cfg(feature = "italian")]
+            Language::Italian => Some("it"),
+            #[cfg(feature = "japanese")]
+            Language::Japanese => Some("ja"),
+            #[cfg(feature = "korean")]
+            Language::Korean => Some("ko"),
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => Some("pt"),
+            #[cfg(feature = "spanish")]
+            Language::Spanish => Some("es"),
+            Language::Custom(custom) => Language::registered_code(custom),
+        }
+    }
+
+    /// Get the separator placed between the words of a phrase in this language
+    ///
+    /// This is an ideographic space (`U+3000`) for Japanese, as in the BIP39 reference
+    /// implementation, and an ASCII space for every other language. Either is accepted when
+    /// parsing a phrase.
+    pub fn separator(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+
+    /// Check that the word lists of the built-in languages enabled at compile time match the
+    /// reference lists of the BIP39 repository byte for byte
+    ///
+    /// The SHA-256 of each reference file, one word per line, is embedded in the crate, and
+    /// compared with that of the compiled-in list. Returns `Error::CorruptWordlist` with the first
+    /// language that doesn't match. Wallets can call this once at startup, while packagers and
+    /// auditors can compare the embedded hashes with the upstream files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert!(Language::verify_integrity().is_ok());
+    /// ```
+    pub fn verify_integrity() -> Result<(), Error> {
+        for lang in Language::all() {
+            let digest = lang.wordlist().iter().fold(Sha256::new(), |hasher, word| {
+                hasher.chain_update(word).chain_update("\n")
+            });
+
+            let hex = digest
+                .finalize()
+                .into_iter()
+                .flat_map(|byte| [byte >> 4, byte & 0x0f]);
+            let hex = hex.map(|nibble| b"0123456789abcdef"[usize::from(nibble)]);
+
+            if !lang
+                .reference_sha256()
+                .is_some_and(|reference| hex.eq(reference.bytes()))
+            {
+                return Err(Error::CorruptWordlist(lang));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The SHA-256 of the reference word list file, from
+    /// <https://github.com/bitcoin/bips/tree/master/bip-0039>
+    fn reference_sha256(&self) -> Option<&'static str> {
+        match *self {
+            Language::English => {
+                Some("2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda")
+            }
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => {
+                Some("5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726")
+            }
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => {
+                Some("417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f")
+            }
+            #[cfg(feature = "czech")]
+            Language::Czech => {
+                Some("7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc")
+            }
+            #[cfg(feature = "french")]
+            Language::French => {
+                Some("ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59")
+            }
+            #[cfg(feature = "italian")]
+            Language::Italian => {
+                Some("d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2")
+            }
+            #[cfg(feature = "japanese")]
+            Language::Japanese => {
+                Some("2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd")
+            }
+            #[cfg(feature = "korean")]
+            Language::Korean => {
+                Some("9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60")
+            }
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => {
+                Some("2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f")
+            }
+            #[cfg(feature = "spanish")]
+            Language::Spanish => {
+                Some("46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b")
+            }
+            Language::Custom(_) => None,
+        }
+    }
+
+    /// Get the word at `index`, if it's less than 2048
+    pub(crate) fn word_at(&self, index: u16) -> Option<&'static str> {
+        self.wordlist().get(index)
+    }
+
+    /// Get the index of `word`, which is its 11 bit value in a phrase, or `None` if it isn't in
+    /// the word list
+    ///
+    /// This never allocates, since the word maps of built-in languages are generated at compile
+    /// time. The word must be NFKD normalized, as the words of a phrase are by
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.index_of("abandon"), Some(0));
+    /// assert_eq!(Language::English.index_of("zoo"), Some(2047));
+    /// assert_eq!(Language::English.index_of("Zoo"), None);
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        self.wordmap().index_of(word)
+    }
+
+    /// All the words of this language starting with `prefix`, in byte order
+    ///
+    /// This is for completing words while the user types a phrase. The prefix must be NFKD
+    /// normalized like the words, which it is when typed in most languages, but precomposed
+    /// Hangul syllables or accented letters need normalizing first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.words_by_prefix("fro"), ["frog", "front", "frost", "frown", "frozen"]);
+    /// assert!(Language::English.words_by_prefix("xyz").is_empty());
+    /// ```
+    pub fn words_by_prefix(&self, prefix: &str) -> &'static [&'static str] {
+        self.wordlist().get_words_by_prefix(prefix)
+    }
+
     /// Get the word list for this language
     pub fn wordlist(&self) -> &'static WordList {
         match *self {
@@ -159,6 +923,8 @@ impl Language {
             Language::ChineseSimplified => &lazy::WORDLIST_CHINESE_SIMPLIFIED,
             #[cfg(feature = "chinese-traditional")]
             Language::ChineseTraditional => &lazy::WORDLIST_CHINESE_TRADITIONAL,
+            #[cfg(feature = "czech")]
+            Language::Czech => &lazy::WORDLIST_CZECH,
             #[cfg(feature = "french")]
             Language::French => &lazy::WORDLIST_FRENCH,
             #[cfg(feature = "italian")]
@@ -167,8 +933,11 @@ impl Language {
             Language::Japanese => &lazy::WORDLIST_JAPANESE,
             #[cfg(feature = "korean")]
             Language::Korean => &lazy::WORDLIST_KOREAN,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => &lazy::WORDLIST_PORTUGUESE,
             #[cfg(feature = "spanish")]
             Language::Spanish => &lazy::WORDLIST_SPANISH,
+            Language::Custom(custom) => &custom.wordlist,
         }
     }
 
@@ -183,6 +952,8 @@ impl Language {
             Language::ChineseSimplified => &lazy::WORDMAP_CHINESE_SIMPLIFIED,
             #[cfg(feature = "chinese-traditional")]
             Language::ChineseTraditional => &lazy::WORDMAP_CHINESE_TRADITIONAL,
+            #[cfg(feature = "czech")]
+            Language::Czech => &lazy::WORDMAP_CZECH,
             #[cfg(feature = "french")]
             Language::French => &lazy::WORDMAP_FRENCH,
             #[cfg(feature = "italian")]
@@ -191,23 +962,90 @@ impl Language {
             Language::Japanese => &lazy::WORDMAP_JAPANESE,
             #[cfg(feature = "korean")]
             Language::Korean => &lazy::WORDMAP_KOREAN,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => &lazy::WORDMAP_PORTUGUESE,
             #[cfg(feature = "spanish")]
             Language::Spanish => &lazy::WORDMAP_SPANISH,
+            Language::Custom(custom) => &custom.wordmap,
         }
     }
 }
 
-impl Default for Language {
-    fn default() -> Language {
-        Language::English
+/// Parses a language code, or a BCP 47 language tag such as a user's locale
+///
+/// Subtags are dropped from the end until a language code matches, so `"en-US"` is English and
+/// `"zh-Hant-TW"` is Traditional Chinese. Underscores are accepted in place of dashes, as in
+/// POSIX locales.
+///
+/// ```
+/// use bip39::Language;
+///
+/// assert_eq!("en_GB".parse(), Ok(Language::English));
+/// assert!("tlh".parse::<Language>().is_err());
+/// ```
+impl FromStr for Language {
+    type Err = Error;
+
+    fn from_str(tag: &str) -> Result<Self, Error> {
+        let tag = tag.replace('_', "-");
+        let mut tag = &tag[..];
+
+        loop {
+            if let Some(lang) = Language::from_language_code(tag) {
+                return Ok(lang);
+            }
+
+            match tag.rfind('-') {
+                Some(idx) => tag = &tag[..idx],
+                None => return Err(Error::UnknownLanguage),
+            }
+        }
+    }
+}
+
+/// Serialized as the language code, as in `"en"`, and not as the name, so that existing
+/// serialized mnemonics keep the same format. Custom word lists can't be serialized unless they
+/// were registered with `Language::register()`. Deserialized from the
+/// language code or the lowercase name, as in `"en"` or `"english"`, the name being the one of the
+/// Cargo feature enabling the language.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Language {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.code() {
+            Some(code) => serializer.serialize_str(code),
+            None => Err(serde::ser::Error::custom(
+                "unregistered word lists can't be serialized",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Language {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        Language::from_language_code(&code)
+            .or_else(|| Language::from_name(&code))
+            .ok_or_else(|| serde::de::Error::custom(alloc::format!("unknown language {:?}", code)))
+    }
+}
+
+/// One of the built-in languages enabled at compile time, never a custom word list
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Language {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(BUILTIN_LANGUAGES).copied()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::lazy;
+    use super::CustomWordlist;
     use super::Language;
     use super::WordList;
+    use crate::error::Error;
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
 
@@ -216,7 +1054,7 @@ mod test {
     fn words_by_prefix() {
         let wl = &lazy::WORDLIST_ENGLISH;
         let res = wl.get_words_by_prefix("woo");
-        assert_eq!(res, ["wood","wool"]);
+        assert_eq!(res, ["wood", "wool"]);
     }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
@@ -227,6 +1065,33 @@ mod test {
         assert_eq!(res.len(), 2048);
     }
 
+    #[cfg(feature = "french")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn words_by_prefix_of_unsorted_lists() {
+        // The French word list is in alphabetical order, accented letters aren't in byte order
+        let words = Language::French.words_by_prefix("e\u{301}");
+        assert_eq!(words.len(), 87);
+        assert!(words.iter().all(|word| word.starts_with("e\u{301}")));
+
+        for lang in Language::all() {
+            for word in lang.wordlist().words() {
+                let prefix: String = word.chars().take(2).collect();
+                assert!(lang.words_by_prefix(&prefix).contains(word));
+            }
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn suggestions() {
+        let wl = &lazy::WORDLIST_ENGLISH;
+
+        assert_eq!(wl.suggestions("kitchn"), ["kitchen", "kitten", "pitch"]);
+        assert_eq!(wl.suggestions("abandn"), ["abandon"]);
+        assert!(wl.suggestions("xxxxxxxxxx").is_empty());
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn words_by_invalid_prefix() {
@@ -235,6 +1100,111 @@ mod test {
         assert!(res.is_empty());
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn static_words() {
+        let words: &'static [&'static str; 2048] = Language::English.wordlist().words();
+
+        assert_eq!(words[0], "abandon");
+        assert_eq!(words[2047], "zoo");
+        assert_eq!(&words[..], &english_words()[..]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn wordmaps_match_wordlists() {
+        for &lang in super::BUILTIN_LANGUAGES {
+            let (wordlist, wordmap) = (lang.wordlist(), lang.wordmap());
+
+            for index in 0..2048u16 {
+                let word = wordlist.get_word(index.into());
+
+                assert_eq!(
+                    wordmap.get_bits(word).map(u16::from),
+                    Some(index),
+                    "{:?}",
+                    lang
+                );
+            }
+            assert!(wordmap.get_bits("notaword").is_none());
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn wordlist_accessors() {
+        for lang in Language::all() {
+            let (wordlist, wordmap) = (lang.wordlist(), lang.wordmap());
+
+            for (index, word) in wordlist.iter().enumerate() {
+                assert_eq!(wordlist.get(index as u16), Some(word));
+                assert_eq!(wordlist.index_of(word), Some(index as u16));
+                assert_eq!(wordmap.index_of(word), Some(index as u16));
+                assert!(wordlist.contains(word) && wordmap.contains(word));
+            }
+
+            assert_eq!(wordlist.get(2048), None);
+            assert_eq!(wordlist.index_of("notaword"), None);
+            assert!(!wordlist.contains("notaword") && !wordmap.contains("notaword"));
+        }
+    }
+
+    fn english_words() -> Vec<&'static str> {
+        include_str!("langs/english.txt")
+            .split_whitespace()
+            .collect()
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn custom_wordlist() {
+        use crate::mnemonic::Mnemonic;
+        use crate::util::Lazy;
+
+        static REVERSED: Lazy<CustomWordlist> = Lazy::new(|| {
+            let mut words = english_words();
+            words.reverse();
+
+            CustomWordlist::from_words(words).unwrap()
+        });
+
+        let lang = Language::Custom(&REVERSED);
+        let mnemonic = Mnemonic::from_entropy(&[0xFF; 16], lang).unwrap();
+
+        assert!(mnemonic.phrase().starts_with("abandon abandon"));
+        assert_eq!(
+            Mnemonic::from_phrase(mnemonic.phrase(), lang)
+                .unwrap()
+                .entropy(),
+            &[0xFF; 16]
+        );
+        assert!(Mnemonic::validate(mnemonic.phrase(), Language::English).is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_custom_wordlist() {
+        let mut words = english_words();
+
+        words.pop();
+        assert!(CustomWordlist::from_words(words.clone()).is_err());
+
+        words.push("abandon");
+        assert!(CustomWordlist::from_words(words.clone()).is_err());
+
+        words[2047] = "two words";
+        assert!(CustomWordlist::from_words(words.clone()).is_err());
+
+        words[2047] = "nul\0";
+        assert_eq!(
+            CustomWordlist::from_words(words.clone()),
+            Err(Error::InvalidWordlistWord(2047))
+        );
+
+        words[2047] = "zzz";
+        assert!(CustomWordlist::from_words(words).is_ok());
+    }
+
     fn is_wordlist_nfkd(wl: &WordList) -> bool {
         for idx in 0..2047 {
             let word = wl.get_word(idx.into());
@@ -242,7 +1212,13 @@ mod test {
                 return false;
             }
         }
-        return true;
+        true
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn english_wordlist_is_nfkd() {
+        assert!(is_wordlist_nfkd(&lazy::WORDLIST_ENGLISH));
     }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
@@ -259,6 +1235,13 @@ mod test {
         assert!(is_wordlist_nfkd(&lazy::WORDLIST_CHINESE_TRADITIONAL));
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "czech")]
+    fn czech_wordlist_is_nfkd() {
+        assert!(is_wordlist_nfkd(&lazy::WORDLIST_CZECH));
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     #[cfg(feature = "french")]
@@ -287,6 +1270,13 @@ mod test {
         assert!(is_wordlist_nfkd(&lazy::WORDLIST_KOREAN));
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "portuguese")]
+    fn portuguese_wordlist_is_nfkd() {
+        assert!(is_wordlist_nfkd(&lazy::WORDLIST_PORTUGUESE));
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     #[cfg(feature = "spanish")]
@@ -295,6 +1285,78 @@ mod test {
     }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn language_code_roundtrip() {
+        for lang in Language::all() {
+            let code = lang.code().unwrap();
+
+            assert_eq!(Language::from_language_code(code), Some(lang));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn serde_names_and_codes() {
+        assert_eq!(
+            serde_json::to_string(&Language::English).unwrap(),
+            r#""en""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Language>(r#""en""#).unwrap(),
+            Language::English
+        );
+        assert_eq!(
+            serde_json::from_str::<Language>(r#""english""#).unwrap(),
+            Language::English
+        );
+        assert!(serde_json::from_str::<Language>(r#""klingon""#).is_err());
+
+        for lang in Language::all() {
+            let json = serde_json::to_string(&lang).unwrap();
+            assert_eq!(serde_json::from_str::<Language>(&json).unwrap(), lang);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn register() {
+        let words = (0..2048)
+            .map(|i| &*Box::leak(format!("x{}", i).into_boxed_str()))
+            .collect();
+        let custom = Language::register("X-Test", CustomWordlist::from_words(words).unwrap());
+        let custom = custom.unwrap();
+
+        assert_eq!(custom.code(), Some("x-test"));
+        assert_eq!(Language::from_language_code("x-TEST"), Some(custom));
+        assert_eq!("x-test".parse(), Ok(custom));
+        assert!(Language::registered().contains(&custom));
+        assert!(Language::all().all(|lang| lang != custom));
+
+        let mnemonic = crate::Mnemonic::from_entropy(&[0; 16], custom).unwrap();
+        assert_eq!(Language::detect(mnemonic.phrase()), Ok(custom));
+        assert_eq!(Language::detect_all(mnemonic.phrase()), [custom]);
+        assert_eq!(Language::languages_containing("x42"), [custom]);
+
+        let words = (0..2048)
+            .map(|i| &*Box::leak(format!("y{}", i).into_boxed_str()))
+            .collect();
+        let wordlist = CustomWordlist::from_words(words).unwrap();
+        assert_eq!(
+            Language::register("en", wordlist),
+            Err(Error::LanguageCodeInUse("en".into())),
+        );
+        let words = (0..2048)
+            .map(|i| &*Box::leak(format!("y{}", i).into_boxed_str()))
+            .collect();
+        let wordlist = CustomWordlist::from_words(words).unwrap();
+        assert_eq!(
+            Language::register("x-test", wordlist),
+            Err(Error::LanguageCodeInUse("x-test".into())),
+        );
+    }
+
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn from_language_code_en() {
         assert_eq!(
@@ -323,6 +1385,16 @@ mod test {
         );
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "czech")]
+    fn from_language_code_cs() {
+        assert_eq!(
+            Language::from_language_code("Cs").expect("cs is a valid language"),
+            Language::Czech
+        );
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     #[cfg(feature = "french")]
@@ -363,6 +1435,16 @@ mod test {
         );
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "portuguese")]
+    fn from_language_code_pt() {
+        assert_eq!(
+            Language::from_language_code("PT").expect("pt is a valid language"),
+            Language::Portuguese
+        );
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     #[cfg(feature = "spanish")]
@@ -373,6 +1455,111 @@ mod test {
         );
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn detect_builtin_languages() {
+        use crate::mnemonic::Mnemonic;
+
+        for &lang in super::BUILTIN_LANGUAGES {
+            let mnemonic = Mnemonic::from_entropy(&[0x42; 32], lang).unwrap();
+
+            assert_eq!(Language::detect(mnemonic.phrase()).unwrap(), lang);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn detect_unknown_language() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid park";
+
+        assert!(Language::detect(phrase).is_err());
+        assert!(Language::detect("").is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "french")]
+    fn detect_all_languages() {
+        // Every word is on both lists, and the checksum is valid in both
+        let phrase =
+            "civil festival festival palace rival concert distance panda junior unique spatial science";
+
+        assert_eq!(Language::detect(phrase), Ok(Language::English));
+        assert_eq!(
+            Language::detect_all(phrase),
+            [Language::English, Language::French]
+        );
+        assert!(Language::detect_all("park remain person").is_empty());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "french")]
+    fn languages_containing() {
+        assert_eq!(
+            Language::languages_containing("abandon"),
+            [Language::English, Language::French],
+        );
+        assert_eq!(
+            Language::languages_containing("abriter"),
+            [Language::French]
+        );
+        // Precomposed, while the word list has it decomposed
+        assert_eq!(
+            Language::languages_containing("\u{e9}lève"),
+            [Language::French]
+        );
+        assert!(Language::languages_containing("Abandon").is_empty());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn verify_integrity() {
+        assert_eq!(Language::verify_integrity(), Ok(()));
+
+        for lang in Language::all() {
+            assert!(
+                lang.reference_sha256().is_some(),
+                "{:?} has a reference",
+                lang
+            );
+        }
+
+        let custom = CustomWordlist::from_words(Language::English.wordlist().iter().collect());
+        let custom = Language::Custom(Box::leak(Box::new(custom.unwrap())));
+        assert_eq!(custom.reference_sha256(), None);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn all_as_map_keys() {
+        use std::collections::HashSet;
+
+        let langs: HashSet<Language> = Language::all().collect();
+
+        assert_eq!(langs.len(), Language::all().len());
+        assert!(langs.contains(&Language::English));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn from_str() {
+        assert_eq!("EN".parse(), Ok(Language::English));
+        assert_eq!("en-US".parse(), Ok(Language::English));
+        assert_eq!("".parse::<Language>(), Err(Error::UnknownLanguage));
+        assert_eq!("de-DE".parse::<Language>(), Err(Error::UnknownLanguage));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(all(feature = "chinese-simplified", feature = "chinese-traditional"))]
+    fn from_str_chinese() {
+        assert_eq!("zh-Hans-CN".parse(), Ok(Language::ChineseSimplified));
+        assert_eq!("zh_Hant_TW".parse(), Ok(Language::ChineseTraditional));
+        assert_eq!("zh".parse::<Language>(), Err(Error::UnknownLanguage));
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn from_invalid_language_code() {
@@ -0,0 +1,235 @@
+//! Non-standard phrases of old wallets, enabled with the `legacy` feature
+//!
+//! Some wallets written before BIP39 was settled made phrases from the BIP39 word lists with
+//! other numbers of words, or without a valid checksum. [`Mnemonic`][Mnemonic] rejects them, as it
+//! should, so recovery tools can parse them as a [`LegacyMnemonic`][LegacyMnemonic] instead, and
+//! derive their seed the way BIP39 does. A legacy mnemonic is never turned into a `Mnemonic`
+//! unless it's a valid BIP39 phrase as well.
+//!
+//! ```
+//! use bip39::legacy::LegacyMnemonic;
+//! use bip39::Language;
+//!
+//! // 9 words, which BIP39 doesn't allow
+//! let phrase = "park remain person kitchen mule spell knee armed position";
+//! let legacy = LegacyMnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! assert!(!legacy.is_standard());
+//! let seed = legacy.to_seed("");
+//! ```
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [LegacyMnemonic]: ./struct.LegacyMnemonic.html
+
+use crate::crypto::{pbkdf2, DefaultCrypto};
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::seed::{Seed, SeedDerivationParams};
+use crate::util::{is_word_separator, nfkd_zeroizing};
+use alloc::string::String;
+use core::fmt;
+use zeroize::Zeroizing;
+
+/// The most words accepted, twice as many as the longest BIP39 phrase
+pub const MAX_WORDS: usize = 48;
+
+/// A phrase of words from a BIP39 word list, with any number of words up to
+/// [`MAX_WORDS`][MAX_WORDS] and no checksum requirement
+///
+/// [MAX_WORDS]: ./constant.MAX_WORDS.html
+#[derive(Clone, PartialEq, Eq)]
+pub struct LegacyMnemonic {
+    phrase: Zeroizing<String>,
+    lang: Language,
+}
+
+impl LegacyMnemonic {
+    /// Parse a legacy phrase in `lang`
+    ///
+    /// The words are normalized like [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] does,
+    /// and each one must be on the word list, otherwise `Error::InvalidWord` is returned.
+    /// Phrases without any words, or with more than [`MAX_WORDS`][MAX_WORDS], are rejected with
+    /// `Error::InvalidWordCount`, and phrases longer than
+    /// [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN] with `Error::PhraseTooLong`.
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [MAX_WORDS]: ./constant.MAX_WORDS.html
+    /// [MAX_PHRASE_LEN]: ../mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+    pub fn from_phrase(phrase: &str, lang: Language) -> Result<LegacyMnemonic, Error> {
+        if phrase.len() > Mnemonic::MAX_PHRASE_LEN {
+            return Err(Error::PhraseTooLong(phrase.len()));
+        }
+
+        let word_count = phrase.split_whitespace().count();
+        if word_count == 0 || word_count > MAX_WORDS {
+            return Err(Error::InvalidWordCount(word_count));
+        }
+
+        let phrase = Mnemonic::normalize_phrase(phrase, lang);
+        let wordmap = lang.wordmap();
+
+        for (index, word) in phrase.split(is_word_separator).enumerate() {
+            if !wordmap.contains(word) {
+                return Err(Error::InvalidWord {
+                    word: SecretWord::new(word, Some(lang)),
+                    index,
+                });
+            }
+        }
+
+        Ok(LegacyMnemonic { phrase, lang })
+    }
+
+    /// Get the normalized phrase
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Get the language of the word list
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+
+    /// Get the number of words
+    pub fn word_count(&self) -> usize {
+        self.phrase.split(is_word_separator).count()
+    }
+
+    /// Whether the phrase is a valid BIP39 mnemonic as well, with a standard number of words and
+    /// a valid checksum
+    pub fn is_standard(&self) -> bool {
+        Mnemonic::validate(&self.phrase, self.lang).is_ok()
+    }
+
+    /// Get the BIP39 [`Mnemonic`][Mnemonic] of the same phrase, if it's a standard one
+    ///
+    /// Returns the same errors as [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] otherwise.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn to_mnemonic(&self) -> Result<Mnemonic, Error> {
+        Mnemonic::from_phrase(&self.phrase, self.lang)
+    }
+
+    /// Derive the [`Seed`][Seed] for the phrase and `passphrase`, the way BIP39 does
+    ///
+    /// For a standard phrase, the seed is the same as [`Mnemonic::to_seed()`][to_seed] gives.
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [to_seed]: ../mnemonic/struct.Mnemonic.html#method.to_seed
+    pub fn to_seed(&self, passphrase: &str) -> Seed {
+        let params = SeedDerivationParams::bip39();
+
+        let mut salt = Zeroizing::new(String::from(params.salt_prefix));
+        salt.push_str(passphrase);
+
+        let phrase = nfkd_zeroizing(&self.phrase);
+        let bytes = pbkdf2(
+            &DefaultCrypto,
+            phrase.as_bytes(),
+            &nfkd_zeroizing(&salt),
+            &params,
+        );
+
+        Seed::from_bytes(bytes)
+    }
+}
+
+/// The phrase is a secret, so it's never printed, use
+/// [`LegacyMnemonic::phrase()`][LegacyMnemonic::phrase()] to get it explicitly.
+///
+/// [LegacyMnemonic::phrase()]: ./struct.LegacyMnemonic.html#method.phrase
+impl fmt::Debug for LegacyMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LegacyMnemonic(****)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn short_phrase() {
+        let phrase = "park  remain person kitchen mule spell";
+        let legacy = LegacyMnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(legacy.phrase(), "park remain person kitchen mule spell");
+        assert_eq!(legacy.word_count(), 6);
+        assert!(!legacy.is_standard());
+        assert_eq!(
+            legacy.to_mnemonic().map(|_| ()),
+            Err(Error::InvalidWordCount(6))
+        );
+        assert_eq!(format!("{:?}", legacy), "LegacyMnemonic(****)");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn standard_phrase() {
+        let legacy = LegacyMnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let mnemonic = legacy.to_mnemonic().unwrap();
+
+        assert!(legacy.is_standard());
+        assert_eq!(legacy.to_seed("password"), mnemonic.to_seed("password"));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn checksum_less_phrase() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid able";
+        let legacy = LegacyMnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert!(!legacy.is_standard());
+        assert_eq!(
+            legacy.to_mnemonic().map(|_| ()),
+            Err(Error::InvalidChecksum)
+        );
+
+        let standard = LegacyMnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        assert_ne!(legacy.to_seed(""), standard.to_seed(""));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn unchecked_checksum() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid able";
+        let legacy = Mnemonic::from_string_unchecked_checksum(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            legacy,
+            LegacyMnemonic::from_phrase(phrase, Language::English).unwrap()
+        );
+
+        let short = Mnemonic::from_string_unchecked_checksum("park remain", Language::English);
+        assert_eq!(short, Err(Error::InvalidWordCount(2)));
+        let invalid = Mnemonic::from_string_unchecked_checksum(
+            "park remain person kitchen mule spell knee armed position rail grid abel",
+            Language::English,
+        );
+        assert!(matches!(invalid, Err(Error::InvalidWord { index: 11, .. })));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn errors() {
+        let invalid = LegacyMnemonic::from_phrase("park remain kitchn", Language::English);
+        assert!(matches!(invalid, Err(Error::InvalidWord { index: 2, .. })));
+
+        assert_eq!(
+            LegacyMnemonic::from_phrase(" ", Language::English),
+            Err(Error::InvalidWordCount(0)),
+        );
+        assert_eq!(
+            LegacyMnemonic::from_phrase(&"grid ".repeat(MAX_WORDS + 1), Language::English),
+            Err(Error::InvalidWordCount(MAX_WORDS + 1)),
+        );
+    }
+}
@@ -27,17 +27,141 @@
 //! println!("{:X}", seed);
 //! ```
 //!
+//! ## `no_std`
+//!
+//! With the default `std` feature disabled, the crate builds with `#![no_std]`, as long as an
+//! allocator is available. Word lookups work the same way with or without `std`: the built-in
+//! word lists use maps generated at build time, and custom ones a `BTreeMap`. Generating new
+//! mnemonics needs an RNG passed to [`Mnemonic::new_with_rng()`][Mnemonic::new_with_rng()].
+//!
+//! [Mnemonic::new_with_rng()]: ./mnemonic/struct.Mnemonic.html#method.new_with_rng
+//!
+//! ## Thread safety
+//!
+//! [`Mnemonic`][Mnemonic], [`Seed`][Seed], [`Language`][Language] and
+//! [`MnemonicType`][MnemonicType] are all `Send` and `Sync`, so they can be shared with a thread
+//! pool, for instance to validate phrases concurrently. The word lists are `static`, and word
+//! lists registered with [`Language::register()`][Language::register()] are behind a lock.
+//!
+//! [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+//! [Seed]: ./seed/struct.Seed.html
+//! [Language]: ./language/enum.Language.html
+//! [MnemonicType]: ./mnemonic_type/enum.MnemonicType.html
+//! [Language::register()]: ./language/enum.Language.html#method.register
+//!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "aezeed")]
+pub mod aezeed;
+pub mod backup;
+#[cfg(feature = "bip32")]
+pub mod bip32;
+#[cfg(feature = "bip85")]
+pub mod bip85;
+mod builder;
+#[cfg(feature = "seal")]
+pub mod container;
+mod display;
+#[cfg(feature = "electrum")]
+pub mod electrum;
+mod encoding;
+pub mod entropy;
+mod entry;
 mod error;
+#[cfg(feature = "ethereum")]
+pub mod ethereum;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fixed;
+#[cfg(feature = "i18n-errors")]
+mod i18n;
+#[cfg(feature = "keystore")]
+pub mod keystore;
 mod language;
+#[cfg(feature = "legacy")]
+pub mod legacy;
+#[cfg(feature = "mlock")]
+mod locked;
 mod mnemonic;
 mod mnemonic_type;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+#[cfg(feature = "monero")]
+pub mod monero;
+#[cfg(feature = "passphrase")]
+pub mod passphrase;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "qr")]
+pub mod qr;
+pub mod recovery;
+#[cfg(feature = "seal")]
+mod seal;
 mod seed;
+pub mod seedqr;
+#[cfg(feature = "self-test")]
+mod self_test;
+#[cfg(any(feature = "slip39", feature = "sskr"))]
+mod shamir;
+#[cfg(feature = "slip21")]
+pub mod slip21;
+#[cfg(feature = "slip39")]
+pub mod slip39;
+#[cfg(feature = "sskr")]
+pub mod sskr;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "test-utils")]
+pub mod test_vectors;
+#[cfg(all(feature = "self-test", not(feature = "test-utils")))]
+mod test_vectors;
 mod util;
+mod validation;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod wasm;
 
 mod crypto;
 
-pub use error::ErrorKind;
-pub use language::Language;
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+pub use builder::MnemonicBuilder;
+#[cfg(feature = "std")]
+pub use crypto::calibrate_kdf;
+pub use crypto::{CryptoBackend, DefaultCrypto, Digest, Kdf};
+pub use display::PhraseDisplay;
+pub use entry::PhraseEntry;
+#[cfg(feature = "self-test")]
+pub use error::SelfTestError;
+#[allow(deprecated)]
+pub use error::{Error, ErrorKind, RngError, SecretWord};
+#[cfg(feature = "i18n-errors")]
+pub use i18n::Locale;
+pub use language::{CustomWordlist, Language, WordList, WordMap};
 pub use mnemonic::Mnemonic;
-pub use mnemonic_type::MnemonicType;
-pub use seed::Seed;
+pub use mnemonic_type::{MnemonicType, WordCount};
+#[cfg(feature = "argon2")]
+pub use seed::Argon2Params;
+#[cfg(feature = "scrypt")]
+pub use seed::ScryptParams;
+pub use seed::{Seed, SeedDerivationParams, SeedHmac, SeedKdf};
+#[cfg(feature = "self-test")]
+pub use self_test::self_test;
+pub use validation::{
+    AuditWarning, BackupCheck, Homograph, ValidationIssue, ValidationOptions, ValidationReport,
+};
+
+// The types shared between threads, see "Thread safety" above
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Mnemonic>();
+    assert_send_sync::<Seed>();
+    assert_send_sync::<Language>();
+    assert_send_sync::<MnemonicType>();
+    assert_send_sync::<WordList>();
+    assert_send_sync::<WordMap>();
+    assert_send_sync::<Error>();
+};
@@ -0,0 +1,173 @@
+//! Buffers of secret bytes in memory that is locked into RAM, so it's never written to swap
+//!
+//! Each buffer gets pages of its own, as locks apply to whole pages, and unlocking a page shared
+//! with another buffer would unlock that one too. Locking can fail, most often because of the
+//! limit on locked memory (`ulimit -l`), in which case the buffer still works, it just isn't
+//! locked.
+
+use std::alloc::{self, Layout};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::ptr::NonNull;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Bytes in locked pages, zeroed and unlocked when dropped
+pub(crate) struct LockedBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+    locked: bool,
+}
+
+// SAFETY: the bytes are owned, like the bytes of a `Vec<u8>`
+unsafe impl Send for LockedBytes {}
+unsafe impl Sync for LockedBytes {}
+
+impl LockedBytes {
+    /// Copy `bytes` into pages of their own, locked if possible
+    pub(crate) fn new(bytes: &[u8]) -> LockedBytes {
+        let page = page_size();
+        let size = bytes.len().max(1).div_ceil(page) * page;
+        let layout = Layout::from_size_align(size, page).expect("the page size is a power of two");
+
+        // SAFETY: the size of the layout isn't zero
+        let ptr = NonNull::new(unsafe { alloc::alloc_zeroed(layout) })
+            .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+        // SAFETY: the allocation is `size` bytes long, and not aliased
+        let locked = unsafe { lock(ptr.as_ptr(), size) };
+
+        // SAFETY: the allocation is at least as long as `bytes`, which it can't overlap
+        unsafe {
+            ptr.as_ptr()
+                .copy_from_nonoverlapping(bytes.as_ptr(), bytes.len())
+        };
+
+        LockedBytes {
+            ptr,
+            len: bytes.len(),
+            layout,
+            locked,
+        }
+    }
+}
+
+impl Deref for LockedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: the first `len` bytes of the allocation are initialized
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Zeroize for LockedBytes {
+    fn zeroize(&mut self) {
+        // SAFETY: the allocation is `layout.size()` bytes long, and borrowed mutably
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }.zeroize();
+    }
+}
+
+impl Drop for LockedBytes {
+    fn drop(&mut self) {
+        self.zeroize();
+
+        // SAFETY: the allocation was made with `layout`, and locked if `locked` is set
+        unsafe {
+            if self.locked {
+                unlock(self.ptr.as_ptr(), self.layout.size());
+            }
+            alloc::dealloc(self.ptr.as_ptr(), self.layout);
+        }
+    }
+}
+
+impl Clone for LockedBytes {
+    fn clone(&self) -> LockedBytes {
+        LockedBytes::new(self)
+    }
+}
+
+/// Hashes the bytes the same way as a `Vec<u8>` or `[u8]`
+impl Hash for LockedBytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self[..].hash(state);
+    }
+}
+
+/// Moves the bytes into locked pages, zeroing the vector they were in
+impl From<Vec<u8>> for LockedBytes {
+    fn from(mut bytes: Vec<u8>) -> LockedBytes {
+        let locked = LockedBytes::new(&bytes);
+        bytes.zeroize();
+
+        locked
+    }
+}
+
+/// Moves the bytes into locked pages, the vector they were in is zeroed when dropped
+impl From<Zeroizing<Vec<u8>>> for LockedBytes {
+    fn from(bytes: Zeroizing<Vec<u8>>) -> LockedBytes {
+        LockedBytes::new(&bytes)
+    }
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    // SAFETY: `sysconf` has no preconditions
+    match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as usize,
+        _ => 4096,
+    }
+}
+
+#[cfg(not(unix))]
+fn page_size() -> usize {
+    4096
+}
+
+#[cfg(unix)]
+unsafe fn lock(ptr: *mut u8, len: usize) -> bool {
+    libc::mlock(ptr.cast(), len) == 0
+}
+
+#[cfg(unix)]
+unsafe fn unlock(ptr: *mut u8, len: usize) {
+    libc::munlock(ptr.cast(), len);
+}
+
+#[cfg(windows)]
+unsafe fn lock(ptr: *mut u8, len: usize) -> bool {
+    windows_sys::Win32::System::Memory::VirtualLock(ptr.cast_const().cast(), len) != 0
+}
+
+#[cfg(windows)]
+unsafe fn unlock(ptr: *mut u8, len: usize) {
+    windows_sys::Win32::System::Memory::VirtualUnlock(ptr.cast_const().cast(), len);
+}
+
+#[cfg(not(any(unix, windows)))]
+unsafe fn lock(_: *mut u8, _: usize) -> bool {
+    false
+}
+
+#[cfg(not(any(unix, windows)))]
+unsafe fn unlock(_: *mut u8, _: usize) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn locked_bytes() {
+        let bytes = LockedBytes::from(vec![0x42; 64]);
+
+        assert_eq!(&bytes[..], &[0x42; 64][..]);
+        assert_eq!(bytes.ptr.as_ptr() as usize % page_size(), 0);
+        assert_eq!(&bytes.clone()[..], &bytes[..]);
+        assert!(LockedBytes::new(&[]).is_empty());
+    }
+}
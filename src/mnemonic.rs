@@ -1,16 +1,62 @@
 #[cfg(feature = "rand")]
-use crate::crypto::gen_random_bytes;
-use crate::crypto::sha256_first_byte;
-use crate::error::ErrorKind;
-use crate::language::Language;
+use crate::crypto::gen_os_random_bytes;
+#[cfg(feature = "cardano")]
+use crate::crypto::pbkdf2_sha512;
+use crate::crypto::{gen_random_bytes, pbkdf2, sha256_first_byte, CryptoBackend, DefaultCrypto};
+use crate::display::PhraseDisplay;
+use crate::encoding;
+#[cfg(feature = "std")]
+use crate::entropy;
+use crate::error::{Error, SecretWord};
+use crate::fixed;
+use crate::language::{Language, BUILTIN_LANGUAGES};
+#[cfg(feature = "legacy")]
+use crate::legacy::LegacyMnemonic;
+#[cfg(feature = "mlock")]
+use crate::locked::LockedBytes;
 use crate::mnemonic_type::MnemonicType;
-use crate::util::{checksum, BitWriter, IterExt};
-use anyhow::Error;
-use std::fmt;
-use std::mem;
+#[cfg(feature = "rand")]
+use crate::mnemonic_type::WordCount;
+#[cfg(feature = "qr")]
+use crate::qr::{self, QrPayload};
+#[cfg(feature = "seal")]
+use crate::seal;
+#[cfg(feature = "seal")]
+use crate::seed::Argon2Params;
+use crate::seed::{Seed, SeedDerivationParams, SeedKdf};
+use crate::util::{checksum, is_word_separator, nfkd_zeroizing, BitWriter, Bits11, IterExt};
+use crate::validation::{
+    AuditWarning, BackupCheck, Homograph, ValidationIssue, ValidationOptions, ValidationReport,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter;
+use core::mem;
+#[cfg(feature = "cardano")]
+use core::num::NonZeroU32;
+use core::str::FromStr;
+use hmac::{Hmac, Mac};
+use rand_core::{CryptoRng, RngCore};
+use sha2::Sha256;
+#[cfg(feature = "std")]
+use std::io::Read;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use unicode_normalization::UnicodeNormalization;
 use zeroize::Zeroizing;
 
+/// The message prefix of `Mnemonic::fingerprint_id()`, part of its stable scheme
+const FINGERPRINT_ID_DOMAIN: &[u8] = b"tiny-bip39 fingerprint id v1";
+
+/// The PBKDF2 rounds of the Cardano Icarus master key
+#[cfg(feature = "cardano")]
+const ICARUS_ROUNDS: NonZeroU32 = match NonZeroU32::new(4096) {
+    Some(rounds) => rounds,
+    None => unreachable!(),
+};
+
 /// The primary type in this crate, most tasks require creating or using one.
 ///
 /// To create a *new* [`Mnemonic`][Mnemonic] from a randomly generated key, call [`Mnemonic::new()`][Mnemonic::new()].
@@ -18,7 +64,8 @@ use zeroize::Zeroizing;
 /// To get a [`Mnemonic`][Mnemonic] instance for an existing mnemonic phrase, including
 /// those generated by other software or hardware wallets, use [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
 ///
-/// You can get the HD wallet [`Seed`][Seed] from a [`Mnemonic`][Mnemonic] by calling [`Seed::new()`][Seed::new()].
+/// You can get the HD wallet [`Seed`][Seed] from a [`Mnemonic`][Mnemonic] by calling [`Mnemonic::to_seed()`][Mnemonic::to_seed()]
+/// or [`Seed::new()`][Seed::new()].
 /// From there you can either get the raw byte value with [`Seed::as_bytes()`][Seed::as_bytes()], or the hex
 /// representation using Rust formatting: `format!("{:X}", seed)`.
 ///
@@ -32,6 +79,7 @@ use zeroize::Zeroizing;
 /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
 /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
 /// [Mnemonic::entropy()]: ./mnemonic/struct.Mnemonic.html#method.entropy
+/// [Mnemonic::to_seed()]: ./mnemonic/struct.Mnemonic.html#method.to_seed
 /// [Seed]: ./seed/struct.Seed.html
 /// [Seed::new()]: ./seed/struct.Seed.html#method.new
 /// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
@@ -40,14 +88,55 @@ use zeroize::Zeroizing;
 pub struct Mnemonic {
     phrase: Zeroizing<String>,
     lang: Language,
-    entropy: Zeroizing<Vec<u8>>,
+    entropy: EntropyBytes,
+}
+
+/// The entropy of a mnemonic, in locked memory with the `mlock` feature
+#[cfg(feature = "mlock")]
+type EntropyBytes = LockedBytes;
+#[cfg(not(feature = "mlock"))]
+type EntropyBytes = Zeroizing<Vec<u8>>;
+
+#[cfg(feature = "mlock")]
+fn entropy_bytes(entropy: Zeroizing<Vec<u8>>) -> EntropyBytes {
+    LockedBytes::from(entropy)
+}
+
+#[cfg(not(feature = "mlock"))]
+fn entropy_bytes(entropy: Zeroizing<Vec<u8>>) -> EntropyBytes {
+    entropy
 }
 
 impl Mnemonic {
+    /// The longest phrase in bytes accepted by [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()],
+    /// [`Mnemonic::validate()`][Mnemonic::validate()] and the like, before normalizing it
+    ///
+    /// The longest 24 word phrase, in Korean, is less than half as long with single spaces.
+    /// Longer phrases are rejected with `Error::PhraseTooLong` before doing any work on them, and
+    /// so are phrases without 12, 15, 18, 21 or 24 words, with `Error::InvalidWordCount`, so that
+    /// validating untrusted input takes a bounded amount of time and memory.
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    pub const MAX_PHRASE_LEN: usize = 2048;
+
+    /// How many mnemonics [`Mnemonic::generate_where()`][Mnemonic::generate_where()] generates at
+    /// most before giving up
+    ///
+    /// [Mnemonic::generate_where()]: ../mnemonic/struct.Mnemonic.html#method.generate_where
+    pub const MAX_GENERATE_ATTEMPTS: usize = 100_000;
+
     /// Generates a new [`Mnemonic`][Mnemonic]
     ///
     /// Use [`Mnemonic::phrase()`][Mnemonic::phrase()] to get an `str` slice of the generated phrase.
     ///
+    /// The entropy comes from the operating system CSPRNG.
+    ///
+    /// # Panics
+    ///
+    /// If the operating system CSPRNG is unavailable, use [`Mnemonic::try_new()`][Mnemonic::try_new()]
+    /// to handle that case.
+    ///
     /// # Example
     ///
     /// ```
@@ -63,43 +152,474 @@ impl Mnemonic {
     ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
     /// [Mnemonic::phrase()]: ./mnemonic/struct.Mnemonic.html#method.phrase
+    /// [Mnemonic::try_new()]: ./mnemonic/struct.Mnemonic.html#method.try_new
     #[cfg(feature = "rand")]
     pub fn new(mtype: MnemonicType, lang: Language) -> Mnemonic {
-        let entropy = gen_random_bytes(mtype.entropy_bits() / 8);
+        Mnemonic::try_new(mtype, lang).expect("operating system CSPRNG is unavailable")
+    }
 
-        Mnemonic::from_entropy_unchecked(entropy, lang)
+    /// Generates a new [`Mnemonic`][Mnemonic], or returns `Error::RandomSource` if the operating
+    /// system CSPRNG is unavailable
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::try_new(MnemonicType::Words24, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase().split(" ").count(), 24);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    #[cfg(feature = "rand")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(words = mtype.word_count(), lang = ?lang)
+        )
+    )]
+    pub fn try_new(mtype: MnemonicType, lang: Language) -> Result<Mnemonic, Error> {
+        let entropy = gen_os_random_bytes(mtype.entropy_len())?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
     }
 
-    /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy
+    /// Generates a new [`Mnemonic`][Mnemonic] from `user_entropy` XORed with entropy from the
+    /// operating system CSPRNG
+    ///
+    /// As long as either source is truly random, so is the result, which protects against a
+    /// backdoored RNG on either side. `user_entropy` must be exactly as long as `mtype` needs,
+    /// otherwise `Error::InvalidEntropyLength` is returned, and `Error::RandomSource` is returned
+    /// if the operating system CSPRNG is unavailable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{entropy, Mnemonic, MnemonicType, Language};
+    ///
+    /// let rolls = [3, 6, 1, 4, 2, 5].repeat(20);
+    /// let user_entropy = entropy::from_dice_rolls(&rolls, MnemonicType::Words12).unwrap();
+    ///
+    /// let mnemonic = Mnemonic::new_mixed(&user_entropy, MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_ne!(mnemonic.entropy(), &user_entropy[..]);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    #[cfg(feature = "rand")]
+    pub fn new_mixed(
+        user_entropy: &[u8],
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Mnemonic, Error> {
+        let byte_length = mtype.entropy_len();
+
+        if user_entropy.len() != byte_length {
+            return Err(Error::InvalidEntropyLength {
+                got: user_entropy.len() * 8,
+                expected: mtype,
+            });
+        }
+
+        let mut entropy = gen_os_random_bytes(byte_length)?;
+
+        for (byte, user_byte) in entropy.iter_mut().zip(user_entropy) {
+            *byte ^= user_byte;
+        }
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Generates new [`Mnemonic`][Mnemonic]s until one satisfies `predicate`, for instance
+    /// without two words sharing their first four letters, or with short words only for engraving
+    ///
+    /// Returns `Error::NoMatchingMnemonic` if none does in
+    /// [`Mnemonic::MAX_GENERATE_ATTEMPTS`][MAX_GENERATE_ATTEMPTS] attempts, and
+    /// `Error::RandomSource` if the operating system CSPRNG is unavailable.
+    ///
+    /// Every mnemonic the predicate rejects is one an attacker doesn't have to try: a predicate
+    /// that one mnemonic in `n` satisfies takes `log2(n)` bits off the entropy. Predicates that
+    /// most mnemonics satisfy cost next to nothing, but one that only one in a thousand satisfies
+    /// turns 128 bits into 118.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::generate_where(MnemonicType::Words12, Language::English, |m| {
+    ///     m.phrase().split(' ').all(|word| word.len() <= 7)
+    /// })
+    /// .unwrap();
+    ///
+    /// assert!(mnemonic.phrase().split(' ').all(|word| word.len() <= 7));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [MAX_GENERATE_ATTEMPTS]: ./mnemonic/struct.Mnemonic.html#associatedconstant.MAX_GENERATE_ATTEMPTS
+    #[cfg(feature = "rand")]
+    pub fn generate_where<P>(
+        mtype: MnemonicType,
+        lang: Language,
+        predicate: P,
+    ) -> Result<Mnemonic, Error>
+    where
+        P: Fn(&Mnemonic) -> bool,
+    {
+        for _ in 0..Mnemonic::MAX_GENERATE_ATTEMPTS {
+            let mnemonic = Mnemonic::try_new(mtype, lang)?;
+
+            if predicate(&mnemonic) {
+                return Ok(mnemonic);
+            }
+        }
+
+        Err(Error::NoMatchingMnemonic(Mnemonic::MAX_GENERATE_ATTEMPTS))
+    }
+
+    /// Generates `count` new [`Mnemonic`][Mnemonic]s at once, for instance to provision many
+    /// wallets, or returns `Error::RandomSource` if the operating system CSPRNG is unavailable
+    ///
+    /// The entropy of all of them is read from the CSPRNG in a single call, and with the `rayon`
+    /// feature the phrases are then built in parallel, on rayon's global thread pool. Returns
+    /// `Error::BatchTooLarge` if `count` is so large that the size of that entropy overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonics = Mnemonic::generate_batch(100, MnemonicType::Words24, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonics.len(), 100);
+    /// assert!(mnemonics.iter().all(|mnemonic| mnemonic.word_count() == 24));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    #[cfg(feature = "rand")]
+    pub fn generate_batch(
+        count: usize,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Vec<Mnemonic>, Error> {
+        let len = mtype.entropy_len();
+        let total = count
+            .checked_mul(len)
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(Error::BatchTooLarge(count))?;
+        let entropy = gen_os_random_bytes(total)?;
+        let to_mnemonic =
+            |chunk: &[u8]| Mnemonic::from_entropy_unchecked(Zeroizing::new(chunk.to_vec()), lang);
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            Ok(entropy.par_chunks_exact(len).map(to_mnemonic).collect())
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            Ok(entropy.chunks_exact(len).map(to_mnemonic).collect())
+        }
+    }
+
+    /// Generates a new 12 word English [`Mnemonic`][Mnemonic], the most common kind
+    ///
+    /// The passphrase is only chosen when deriving the seed, pass `""` to
+    /// [`Mnemonic::to_seed()`][Mnemonic::to_seed()] for none.
+    ///
+    /// # Panics
+    ///
+    /// If the operating system CSPRNG is unavailable, like [`Mnemonic::new()`][Mnemonic::new()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::generate();
+    /// let seed = mnemonic.to_seed("");
+    ///
+    /// assert_eq!(mnemonic.word_count(), 12);
+    /// assert_eq!(mnemonic.language(), Language::English);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::to_seed()]: ./mnemonic/struct.Mnemonic.html#method.to_seed
+    /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Mnemonic {
+        Mnemonic::new(MnemonicType::Words12, Language::English)
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] of `word_count` words in `lang`, or returns
+    /// `Error::RandomSource` if the operating system CSPRNG is unavailable
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, WordCount};
+    ///
+    /// let mnemonic = Mnemonic::generate_in(Language::English, WordCount::Words24).unwrap();
+    ///
+    /// assert_eq!(mnemonic.word_count(), 24);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    #[cfg(feature = "rand")]
+    pub fn generate_in(lang: Language, word_count: WordCount) -> Result<Mnemonic, Error> {
+        Mnemonic::try_new(word_count.into(), lang)
+    }
+
+    /// Generate a new [`Mnemonic`][Mnemonic] in the specified language, using entropy from `rng`
+    ///
+    /// This works like [`Mnemonic::new()`][Mnemonic::new()], but lets you supply the random number
+    /// generator, e.g. the hardware RNG of an embedded device. It is available even when the `rand`
+    /// feature is disabled.
+    ///
+    /// **Note:** the security of the mnemonic depends entirely on `rng`. Only seed it with a fixed
+    /// value in tests.
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails to provide the entropy, use
+    /// [`Mnemonic::try_new_with_rng()`][Mnemonic::try_new_with_rng()] to handle that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let first = Mnemonic::new_with_rng(&mut StdRng::seed_from_u64(42), MnemonicType::Words12, Language::English);
+    /// let second = Mnemonic::new_with_rng(&mut StdRng::seed_from_u64(42), MnemonicType::Words12, Language::English);
+    ///
+    /// assert_eq!(first.phrase(), second.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
+    /// [Mnemonic::try_new_with_rng()]: ./mnemonic/struct.Mnemonic.html#method.try_new_with_rng
+    pub fn new_with_rng<R>(rng: &mut R, mtype: MnemonicType, lang: Language) -> Mnemonic
+    where
+        R: RngCore + CryptoRng,
+    {
+        Mnemonic::try_new_with_rng(rng, mtype, lang)
+            .expect("random number generator failed to provide entropy")
+    }
+
+    /// Generate a new [`Mnemonic`][Mnemonic] using entropy from `rng`, or return
+    /// `Error::RandomSource` if `rng` fails to provide it
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(words = mtype.word_count(), lang = ?lang)
+        )
+    )]
+    pub fn try_new_with_rng<R>(
+        rng: &mut R,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Mnemonic, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let entropy = gen_random_bytes(rng, mtype.entropy_len())?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from entropy read from `reader`, such as `/dev/hwrng` or a
+    /// file of pre-generated randomness
+    ///
+    /// Exactly as many bytes as `mtype` needs are read, see [`entropy::from_reader()`][from_reader]
+    /// for the errors that can be returned.
     ///
     /// # Example
     ///
     /// ```
     /// use bip39::{Mnemonic, MnemonicType, Language};
     ///
+    /// let randomness = [0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    /// let mnemonic = Mnemonic::from_reader(&randomness[..], MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [from_reader]: ../entropy/fn.from_reader.html
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: Read>(
+        reader: R,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Mnemonic, Error> {
+        let entropy = entropy::from_reader(reader, mtype)?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy
+    ///
+    /// This is for entropy from an HSM or another source of randomness, the checksum is computed
+    /// from it. Returns `Error::InvalidKeysize` unless `entropy` is 16, 20, 24, 28 or 32 bytes
+    /// long, one of the sizes of [`MnemonicType`][MnemonicType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language, Seed};
+    ///
     /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
     /// let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
     ///
     /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.phrase());
     /// assert_eq!("33E46BB13A746EA41CDDE45C90846A79", format!("{:X}", mnemonic));
+    ///
+    /// let seed = Seed::new(&mnemonic, "");
+    /// assert_eq!(seed.as_bytes().len(), 64);
     /// ```
     ///
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
     pub fn from_entropy(entropy: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy_with_backend(entropy, lang, &DefaultCrypto)
+    }
+
+    /// The same as [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()], with the checksum
+    /// computed by `backend`
+    ///
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_with_backend<B: CryptoBackend + ?Sized>(
+        entropy: &[u8],
+        lang: Language,
+        backend: &B,
+    ) -> Result<Mnemonic, Error> {
         // Validate entropy size
-        MnemonicType::for_key_size(entropy.len() * 8)?;
+        MnemonicType::for_entropy_len(entropy.len())?;
 
-        Ok(Self::from_entropy_unchecked(entropy, lang))
+        let entropy = Zeroizing::new(entropy.to_vec());
+
+        Ok(Mnemonic::from_entropy_unchecked_with_backend(
+            entropy, lang, backend,
+        ))
     }
 
-    fn from_entropy_unchecked<E>(entropy: E, lang: Language) -> Mnemonic
-    where
-        E: Into<Vec<u8>>,
-    {
-        let entropy = Zeroizing::new(entropy.into());
+    /// Create a [`Mnemonic`][Mnemonic] in `lang` from entropy exported with
+    /// [`Mnemonic::entropy_base58check()`][Mnemonic::entropy_base58check()], which must start
+    /// with `version`
+    ///
+    /// Returns `Error::InvalidEncoding` if `string` isn't Base58Check, or has another version, and
+    /// `Error::InvalidKeysize` like [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()].
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy_base58check()]: ../mnemonic/struct.Mnemonic.html#method.entropy_base58check
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_base58check(
+        string: &str,
+        version: &[u8],
+        lang: Language,
+    ) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy(&encoding::from_base58check(string, version)?, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] in `lang` from entropy exported with
+    /// [`Mnemonic::entropy_bech32()`][Mnemonic::entropy_bech32()], under the human readable
+    /// part `hrp`
+    ///
+    /// Returns `Error::InvalidEncoding` if `string` isn't bech32, or has another human readable
+    /// part, and `Error::InvalidKeysize` like
+    /// [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()].
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy_bech32()]: ../mnemonic/struct.Mnemonic.html#method.entropy_bech32
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_bech32(string: &str, hrp: &str, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy(&encoding::from_bech32(string, hrp)?, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] in `lang` from entropy in hex, as in
+    /// [`Mnemonic::entropy_hex()`][Mnemonic::entropy_hex()] and most test vectors
+    ///
+    /// Both lowercase and uppercase digits are accepted, but nothing else, not even whitespace or
+    /// a `0x` prefix. Returns `Error::InvalidEncoding` if `hex` isn't hex, and
+    /// `Error::InvalidKeysize` like [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy_hex("33e46bb13a746ea41cdde45c90846a79", Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), "crop cash unable insane eight faith inflict route frame loud box vibrant");
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy_hex()]: ../mnemonic/struct.Mnemonic.html#method.entropy_hex
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_hex(hex: &str, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy(&encoding::from_hex(hex)?, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] in `lang` from entropy in base64, as in
+    /// [`Mnemonic::entropy_base64()`][Mnemonic::entropy_base64()]
+    ///
+    /// Only the standard alphabet of RFC 4648 with padding is accepted, in its canonical form.
+    /// Returns `Error::InvalidEncoding` if `base64` isn't that, and `Error::InvalidKeysize` like
+    /// [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()].
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy_base64()]: ../mnemonic/struct.Mnemonic.html#method.entropy_base64
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_base64(base64: &str, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy(&encoding::from_base64(base64)?, lang)
+    }
+
+    /// Create a mnemonic with entropy of `byte` repeated, as a fixture for tests
+    ///
+    /// The same arguments always give the same mnemonic, so tests can share fixtures without
+    /// storing phrases or hex. **Never** use it for a real wallet, anyone can recreate it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_fixed_entropy(0x42, MnemonicType::Words12, Language::English);
+    ///
+    /// assert_eq!(mnemonic.entropy(), &[0x42; 16]);
+    /// ```
+    #[cfg(feature = "test-utils")]
+    pub fn from_fixed_entropy(byte: u8, mtype: MnemonicType, lang: Language) -> Mnemonic {
+        let entropy = Zeroizing::new(alloc::vec![byte; mtype.entropy_len()]);
+
+        Mnemonic::from_entropy_unchecked(entropy, lang)
+    }
+
+    pub(crate) fn from_entropy_unchecked(entropy: Zeroizing<Vec<u8>>, lang: Language) -> Mnemonic {
+        Mnemonic::from_entropy_unchecked_with_backend(entropy, lang, &DefaultCrypto)
+    }
+
+    fn from_entropy_unchecked_with_backend<B: CryptoBackend + ?Sized>(
+        entropy: Zeroizing<Vec<u8>>,
+        lang: Language,
+        backend: &B,
+    ) -> Mnemonic {
         let wordlist = lang.wordlist();
 
-        let checksum_byte = sha256_first_byte(&entropy);
+        let checksum_byte = sha256_first_byte(backend, &entropy);
 
         // First, create a byte iterator for the given entropy and the first byte of the
         // hash of the entropy that will serve as the checksum (up to 8 bits for biggest
@@ -116,13 +636,13 @@ impl Mnemonic {
                 .chain(Some(&checksum_byte))
                 .bits()
                 .map(|bits| wordlist.get_word(bits))
-                .join(" "),
+                .join(lang.separator()),
         );
 
         Mnemonic {
             phrase,
             lang,
-            entropy,
+            entropy: entropy_bytes(entropy),
         }
     }
 
@@ -143,234 +663,2589 @@ impl Mnemonic {
     /// ```
     ///
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(words = phrase.split_whitespace().count(), lang = ?lang)
+        )
+    )]
     pub fn from_phrase(phrase: &str, lang: Language) -> Result<Mnemonic, Error> {
-        let phrase = Zeroizing::new(
-            phrase
-                .split_whitespace()
-                .map(|w| w.nfkd())
-                .join::<String>(" "),
-        );
+        Mnemonic::from_phrase_with_backend(phrase, lang, &DefaultCrypto)
+    }
+
+    /// The same as [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], with the checksum
+    /// verified by `backend`
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_phrase_with_backend<B: CryptoBackend + ?Sized>(
+        phrase: &str,
+        lang: Language,
+        backend: &B,
+    ) -> Result<Mnemonic, Error> {
+        Mnemonic::check_bounds(phrase)?;
+
+        let phrase = Mnemonic::normalize_phrase(phrase, lang);
 
         // this also validates the checksum and phrase length before returning the entropy so we
         // can store it. We don't use the validate function here to avoid having a public API that
         // takes a phrase string and returns the entropy directly.
-        let entropy = Zeroizing::new(Mnemonic::phrase_to_entropy(&phrase, lang)?);
+        let entropy = Mnemonic::phrase_to_entropy(&phrase, lang, true, backend)?;
 
         let mnemonic = Mnemonic {
             phrase,
             lang,
-            entropy,
+            entropy: entropy_bytes(entropy),
         };
 
         Ok(mnemonic)
     }
 
-    /// Validate a mnemonic phrase
+    /// Create a [`Mnemonic`][Mnemonic] from its words one by one, as picked in a user interface
     ///
-    /// The phrase supplied will be checked for word length and validated according to the checksum
-    /// specified in BIP0039.
+    /// This is the same as [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] without joining
+    /// the words and splitting them again, which is easy to get wrong for languages with another
+    /// separator, such as Japanese. Every word is normalized and must be in the wordlist of
+    /// `lang`, otherwise `Error::InvalidWord` is returned with the index of the first one that
+    /// isn't, and then the checksum is verified. Words with whitespace around them aren't trimmed.
+    ///
+    /// Returns `Error::InvalidWordCount` for a slice without 12, 15, 18, 21 or 24 words, and
+    /// `Error::PhraseTooLong` if the words are longer than
+    /// [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN] in total.
     ///
     /// # Example
     ///
     /// ```
     /// use bip39::{Mnemonic, Language};
     ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let words: Vec<&str> = phrase.split(' ').collect();
     ///
-    /// assert!(Mnemonic::validate(test_mnemonic, Language::English).is_ok());
+    /// let mnemonic = Mnemonic::from_words(&words, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), phrase);
     /// ```
-    pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
-        Mnemonic::phrase_to_entropy(phrase, lang)?;
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [MAX_PHRASE_LEN]: ../mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+    pub fn from_words(words: &[&str], lang: Language) -> Result<Mnemonic, Error> {
+        let len: usize = words.iter().map(|word| word.len()).sum();
+        if len > Mnemonic::MAX_PHRASE_LEN {
+            return Err(Error::PhraseTooLong(len));
+        }
 
-        Ok(())
-    }
+        MnemonicType::for_word_count(words.len())?;
 
-    /// Calculate the checksum, verify it and return the entropy
-    ///
-    /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
-    /// used as the seed is likely to cause problems for someone eventually. All the other functions
-    /// that return something like that are explicit about what it is and what to use it for.
-    fn phrase_to_entropy(phrase: &str, lang: Language) -> Result<Vec<u8>, Error> {
         let wordmap = lang.wordmap();
+        let mut bits = BitWriter::with_capacity(words.len() * 11);
 
-        // Preallocate enough space for the longest possible word list
-        let mut bits = BitWriter::with_capacity(264);
-
-        for (idx, word) in phrase.split(' ').enumerate() {
-            let word_bits = wordmap.get_bits(word).ok_or(ErrorKind::InvalidWord(idx))?;
+        for (index, word) in words.iter().enumerate() {
+            let word = nfkd_zeroizing(word);
+            let word_bits = wordmap.get_bits(&word).ok_or_else(|| Error::InvalidWord {
+                word: SecretWord::new(&word, Some(lang)),
+                index,
+            })?;
             bits.push(word_bits);
         }
 
-        let mtype = MnemonicType::for_word_count(bits.len() / 11)?;
-
-        debug_assert!(
-            bits.len() == mtype.total_bits(),
-            "Insufficient amount of bits to validate"
-        );
-
-        let mut entropy = bits.into_bytes();
-        let entropy_bytes = mtype.entropy_bits() / 8;
+        let entropy = Mnemonic::bits_to_entropy(bits, true, &DefaultCrypto)?;
 
-        let actual_checksum = checksum(entropy[entropy_bytes], mtype.checksum_bits());
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
 
-        // Truncate to get rid of the byte containing the checksum
-        entropy.truncate(entropy_bytes);
+    /// Parse a phrase with a standard number of words but possibly a bad checksum, as made by some
+    /// buggy early wallets, for importing it
+    ///
+    /// Words are checked and the errors are the same as for
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], except that the checksum isn't
+    /// verified. The phrase is returned as a [`LegacyMnemonic`][LegacyMnemonic] rather than a
+    /// `Mnemonic`, so it can't be taken for a valid BIP39 phrase, and its seed is derived the
+    /// way BIP39 does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid able";
+    /// let legacy = Mnemonic::from_string_unchecked_checksum(phrase, Language::English).unwrap();
+    ///
+    /// assert!(!legacy.is_standard());
+    /// let seed = legacy.to_seed("");
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [LegacyMnemonic]: ../legacy/struct.LegacyMnemonic.html
+    #[cfg(feature = "legacy")]
+    pub fn from_string_unchecked_checksum(
+        phrase: &str,
+        lang: Language,
+    ) -> Result<LegacyMnemonic, Error> {
+        Mnemonic::check_bounds(phrase)?;
 
-        let checksum_byte = sha256_first_byte(&entropy);
-        let expected_checksum = checksum(checksum_byte, mtype.checksum_bits());
+        LegacyMnemonic::from_phrase(phrase, lang)
+    }
 
-        if actual_checksum != expected_checksum {
-            Err(ErrorKind::InvalidChecksum)?;
-        }
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase that may have been typed or copied carelessly
+    ///
+    /// Works like [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], but also lowercases every
+    /// word before looking it up, so capitalized or all-caps backups are accepted, and accepts
+    /// words typed without their accents. Leading, trailing and repeated whitespace of any kind
+    /// (tabs, newlines, ideographic spaces) is ignored in both. The stored phrase always uses the
+    /// wordlist spelling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "Park remain person kitchen MULE spell knee armed\tposition rail grid ankle\n";
+    /// let mnemonic = Mnemonic::from_phrase_lenient(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_phrase_lenient(phrase: &str, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::check_bounds(phrase)?;
 
-        Ok(entropy)
+        let options = ValidationOptions::default()
+            .case_sensitive(false)
+            .accent_insensitive(true);
+        let phrase = Mnemonic::normalize_phrase_with(phrase, lang, &options);
+        let entropy = Mnemonic::phrase_to_entropy(&phrase, lang, true, &DefaultCrypto)?;
+
+        Ok(Mnemonic {
+            phrase,
+            lang,
+            entropy: entropy_bytes(entropy),
+        })
     }
 
-    /// Get the mnemonic phrase as a string reference.
-    pub fn phrase(&self) -> &str {
-        &self.phrase
+    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase, normalizing it according
+    /// to custom [`ValidationOptions`][ValidationOptions]
+    ///
+    /// This can be used to accept abbreviated words, or to reject sloppy whitespace. The checksum
+    /// is always verified, regardless of the options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, ValidationOptions};
+    ///
+    /// let phrase = "park rema pers kitc mule spel knee arme posi rail grid ankl";
+    /// let options = ValidationOptions::default().expand_prefixes(true);
+    /// let mnemonic = Mnemonic::from_phrase_with(phrase, Language::English, &options).unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [ValidationOptions]: ../validation/struct.ValidationOptions.html
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(words = phrase.split_whitespace().count(), lang = ?lang)
+        )
+    )]
+    pub fn from_phrase_with(
+        phrase: &str,
+        lang: Language,
+        options: &ValidationOptions,
+    ) -> Result<Mnemonic, Error> {
+        Mnemonic::check_bounds(phrase)?;
+        Mnemonic::check_whitespace(phrase, options)?;
+
+        let phrase = Mnemonic::normalize_phrase_with(phrase, lang, options);
+        let entropy = Mnemonic::phrase_to_entropy(&phrase, lang, true, &DefaultCrypto)?;
+
+        Ok(Mnemonic {
+            phrase,
+            lang,
+            entropy: entropy_bytes(entropy),
+        })
     }
 
-    /// Consume the `Mnemonic` and return the phrase as a `String`.
-    pub fn into_phrase(mut self) -> String {
-        // Create an empty string and swap values with the mnemonic's phrase.
-        // This allows `Mnemonic` to implement `Drop`, while still returning the phrase.
-        mem::replace(&mut self.phrase, String::new())
+    /// NFKD normalize each word and join them with the word separator of the language
+    pub(crate) fn normalize_phrase(phrase: &str, lang: Language) -> Zeroizing<String> {
+        Mnemonic::normalize_phrase_with(phrase, lang, &ValidationOptions::default())
     }
 
-    /// Get the original entropy value of the mnemonic phrase as a slice.
+    /// Reject phrases longer than `MAX_PHRASE_LEN`, or without a valid number of words, before
+    /// looking at any of the words
+    pub(crate) fn check_bounds(phrase: &str) -> Result<(), Error> {
+        if phrase.len() > Mnemonic::MAX_PHRASE_LEN {
+            return Err(Error::PhraseTooLong(phrase.len()));
+        }
+
+        MnemonicType::for_word_count(phrase.split_whitespace().count())?;
+
+        Ok(())
+    }
+
+    /// Unless `options` tolerate whitespace, reject anything but single separators between words
+    fn check_whitespace(phrase: &str, options: &ValidationOptions) -> Result<(), Error> {
+        if !options.whitespace_tolerant {
+            let is_clean = phrase
+                .split(is_word_separator)
+                .all(|word| !word.is_empty() && !word.contains(char::is_whitespace));
+
+            if !is_clean {
+                return Err(Error::InvalidWhitespace);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Same as `normalize_phrase`, lowercasing the words unless `options` are case sensitive, and
+    /// matching unaccented or abbreviated words if `options` ask for it
+    fn normalize_phrase_with(
+        phrase: &str,
+        lang: Language,
+        options: &ValidationOptions,
+    ) -> Zeroizing<String> {
+        let words = phrase.split_whitespace();
+        let separator = lang.separator();
+
+        let phrase = Zeroizing::new(if options.case_sensitive {
+            words.map(|w| w.nfkd()).join::<String>(separator)
+        } else {
+            words
+                .map(|w| w.chars().flat_map(char::to_lowercase).nfkd())
+                .join::<String>(separator)
+        });
+
+        if !options.expand_prefixes && !options.accent_insensitive {
+            return phrase;
+        }
+
+        let wordlist = lang.wordlist();
+        let wordmap = lang.wordmap();
+
+        let resolve = |w: &str| {
+            let unaccented = |prefix| {
+                if options.accent_insensitive {
+                    wordlist.match_unaccented(w, prefix)
+                } else {
+                    None
+                }
+            };
+            let expanded = || {
+                if options.expand_prefixes {
+                    wordlist.expand_prefix(w).or_else(|| unaccented(true))
+                } else {
+                    None
+                }
+            };
+
+            unaccented(false).or_else(expanded)
+        };
+
+        // Unknown words that can't be resolved are kept as is, to be reported by the lookup
+        Zeroizing::new(
+            phrase
+                .split(is_word_separator)
+                .map(|w| match wordmap.get_bits(w) {
+                    Some(_) => w,
+                    None => resolve(w).unwrap_or(w),
+                })
+                .join::<String>(separator),
+        )
+    }
+
+    /// Validate a mnemonic phrase
+    ///
+    /// The phrase supplied will be checked for word length and validated according to the checksum
+    /// specified in BIP0039. Words are NFKD normalized before lookup, as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()],
+    /// so precomposed Hangul syllables or accented letters are matched against the wordlist.
     ///
     /// # Example
     ///
     /// ```
     /// use bip39::{Mnemonic, Language};
     ///
-    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// assert!(Mnemonic::validate(test_mnemonic, Language::English).is_ok());
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(words = phrase.split_whitespace().count(), lang = ?lang)
+        )
+    )]
+    pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
+        Mnemonic::validate_with_backend(phrase, lang, &DefaultCrypto)
+    }
+
+    /// The same as [`Mnemonic::validate()`][Mnemonic::validate()], with the checksum verified by
+    /// `backend`
+    ///
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    pub fn validate_with_backend<B: CryptoBackend + ?Sized>(
+        phrase: &str,
+        lang: Language,
+        backend: &B,
+    ) -> Result<(), Error> {
+        Mnemonic::check_bounds(phrase)?;
+
+        let phrase = Mnemonic::normalize_phrase(phrase, lang);
+
+        Mnemonic::phrase_to_entropy(&phrase, lang, true, backend)?;
+
+        Ok(())
+    }
+
+    /// Validate many mnemonic phrases at once, returning the result of
+    /// [`Mnemonic::validate()`][Mnemonic::validate()] for each, in the same order
+    ///
+    /// With the `rayon` feature the phrases are validated in parallel, on rayon's global thread
+    /// pool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrases = [
+    ///     "park remain person kitchen mule spell knee armed position rail grid ankle",
+    ///     "park remain person kitchen mule spell knee armed position rail grid",
+    /// ];
+    ///
+    /// let results = Mnemonic::validate_batch(&phrases, Language::English);
+    ///
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// ```
+    ///
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    pub fn validate_batch(phrases: &[&str], lang: Language) -> Vec<Result<(), Error>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            phrases
+                .par_iter()
+                .map(|phrase| Mnemonic::validate(phrase, lang))
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            phrases
+                .iter()
+                .map(|phrase| Mnemonic::validate(phrase, lang))
+                .collect()
+        }
+    }
+
+    /// Validate a mnemonic phrase with custom [`ValidationOptions`][ValidationOptions]
+    ///
+    /// Depending on the options, this can be stricter or more permissive than
+    /// [`Mnemonic::validate()`][Mnemonic::validate()], which is the same as passing
+    /// `ValidationOptions::default()`. Whatever the options, a phrase that's too long or doesn't
+    /// have a standard number of words is rejected first, as it is there. When whitespace isn't
+    /// tolerated, any whitespace other than single separators returns `Error::InvalidWhitespace`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, ValidationOptions};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle ";
+    ///
+    /// assert!(Mnemonic::validate(phrase, Language::English).is_ok());
+    /// assert!(Mnemonic::validate_with(phrase, Language::English, &ValidationOptions::strict()).is_err());
+    /// ```
+    ///
+    /// [ValidationOptions]: ../validation/struct.ValidationOptions.html
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    pub fn validate_with(
+        phrase: &str,
+        lang: Language,
+        options: &ValidationOptions,
+    ) -> Result<(), Error> {
+        Mnemonic::check_bounds(phrase)?;
+        Mnemonic::check_whitespace(phrase, options)?;
+
+        let phrase = Mnemonic::normalize_phrase_with(phrase, lang, options);
+
+        Mnemonic::phrase_to_entropy(&phrase, lang, options.verify_checksum, &DefaultCrypto)?;
+
+        Ok(())
+    }
+
+    /// Find every problem with a mnemonic phrase at once
+    ///
+    /// Unlike [`Mnemonic::validate()`][Mnemonic::validate()], which stops at the first invalid
+    /// word, this reports all unknown words with their positions, words from another language,
+    /// a wrong word count, and a checksum mismatch in a single [`ValidationReport`][ValidationReport].
+    /// The phrase is normalized the same way as in [`Mnemonic::validate()`][Mnemonic::validate()],
+    /// unless it's longer than [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN], which is reported
+    /// as the only issue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, ValidationIssue};
+    ///
+    /// let phrase = "park remain person kitchn mule spell knee armed position rail grdi ankle";
+    ///
+    /// let report = Mnemonic::diagnose(phrase, Language::English);
+    ///
+    /// assert!(!report.is_valid());
+    /// assert_eq!(report.issues().len(), 2);
+    /// assert!(matches!(report.issues()[0], ValidationIssue::InvalidWord { index: 3, .. }));
+    /// assert!(matches!(report.issues()[1], ValidationIssue::InvalidWord { index: 10, .. }));
+    /// ```
+    ///
+    /// [ValidationReport]: ../validation/struct.ValidationReport.html
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    /// [MAX_PHRASE_LEN]: ../mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+    pub fn diagnose(phrase: &str, lang: Language) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        if phrase.len() > Mnemonic::MAX_PHRASE_LEN {
+            report
+                .issues
+                .push(ValidationIssue::PhraseTooLong(phrase.len()));
+
+            return report;
+        }
+
+        let phrase = Mnemonic::normalize_phrase(phrase, lang);
+        let wordmap = lang.wordmap();
+        let mut word_count = 0;
+
+        for (index, word) in phrase
+            .split(is_word_separator)
+            .filter(|word| !word.is_empty())
+            .enumerate()
+        {
+            word_count += 1;
+
+            if wordmap.get_bits(word).is_some() {
+                continue;
+            }
+
+            let foreign = BUILTIN_LANGUAGES
+                .iter()
+                .copied()
+                .find(|&other| other != lang && other.wordmap().get_bits(word).is_some());
+
+            report.issues.push(match foreign {
+                Some(language) => ValidationIssue::ForeignWord {
+                    word: SecretWord::new(word, Some(lang)),
+                    index,
+                    language,
+                },
+                None => ValidationIssue::InvalidWord {
+                    word: SecretWord::new(word, Some(lang)),
+                    index,
+                },
+            });
+        }
+
+        if MnemonicType::for_word_count(word_count).is_err() {
+            report
+                .issues
+                .push(ValidationIssue::InvalidWordCount(word_count));
+        }
+
+        // The only error left at this point is a checksum mismatch
+        if report.is_valid()
+            && Mnemonic::phrase_to_entropy(&phrase, lang, true, &DefaultCrypto).is_err()
+        {
+            report.issues.push(ValidationIssue::InvalidChecksum);
+        }
+
+        report
+    }
+
+    /// Calculate the checksum, verify it (unless told otherwise) and return the entropy
+    ///
+    /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
+    /// used as the seed is likely to cause problems for someone eventually. All the other functions
+    /// that return something like that are explicit about what it is and what to use it for.
+    fn phrase_to_entropy<B: CryptoBackend + ?Sized>(
+        phrase: &str,
+        lang: Language,
+        verify_checksum: bool,
+        backend: &B,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let wordmap = lang.wordmap();
+
+        // Preallocate enough space for the longest possible word list
+        let mut bits = BitWriter::with_capacity(264);
+
+        // Both the ASCII space and, for Japanese, the ideographic space are accepted
+        for (idx, word) in phrase.split(is_word_separator).enumerate() {
+            let word_bits = wordmap.get_bits(word).ok_or_else(|| Error::InvalidWord {
+                word: SecretWord::new(word, Some(lang)),
+                index: idx,
+            })?;
+            bits.push(word_bits);
+        }
+
+        Mnemonic::bits_to_entropy(bits, verify_checksum, backend)
+    }
+
+    /// Split the 11 bits per word into the entropy and checksum, verifying the latter unless told
+    /// otherwise
+    fn bits_to_entropy<B: CryptoBackend + ?Sized>(
+        bits: BitWriter,
+        verify_checksum: bool,
+        backend: &B,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let mtype = MnemonicType::for_word_count(bits.len() / 11)?;
+
+        // Only whole words are ever written, but a stray bit must not index past the checksum
+        if bits.len() != mtype.total_bits() {
+            return Err(Error::InvalidWordCount(bits.len() / 11));
+        }
+
+        // Wrapped right away so the bytes are wiped even if the checksum turns out to be invalid.
+        // This also covers the truncated checksum byte left behind in the spare capacity.
+        let mut entropy = Zeroizing::new(bits.into_bytes());
+        let entropy_bytes = mtype.entropy_len();
+
+        let actual_checksum = checksum(entropy[entropy_bytes], mtype.checksum_bits());
+
+        // Truncate to get rid of the byte containing the checksum
+        entropy.truncate(entropy_bytes);
+
+        let checksum_byte = sha256_first_byte(backend, &entropy);
+        let expected_checksum = checksum(checksum_byte, mtype.checksum_bits());
+
+        if verify_checksum && actual_checksum != expected_checksum {
+            return Err(Error::InvalidChecksum);
+        }
+
+        Ok(entropy)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the indices of its words in the word list
+    ///
+    /// Each index is the 11 bit value of a word, from `0` to `2047`, as used by hardware wallets
+    /// and metal backups. The checksum is verified like it is for
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()]. An index out of range returns
+    /// `Error::InvalidWord`, with the position of the index and an empty word.
+    ///
+    /// # Example
     ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
     /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
     ///
-    /// let entropy: &[u8] = mnemonic.entropy();
+    /// let indices = mnemonic.to_indices();
+    /// assert_eq!(indices[0], 1282);
+    ///
+    /// let restored = Mnemonic::from_indices(&indices, Language::English).unwrap();
+    /// assert_eq!(restored.phrase(), phrase);
     /// ```
     ///
-    /// **Note:** You shouldn't use the generated entropy as secrets, for that generate a new
-    /// `Seed` from the `Mnemonic`.
-    pub fn entropy(&self) -> &[u8] {
-        &self.entropy
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_indices(indices: &[u16], lang: Language) -> Result<Mnemonic, Error> {
+        let mut bits = BitWriter::with_capacity(indices.len() * 11);
+
+        for (index, &word_index) in indices.iter().enumerate() {
+            if word_index >= 2048 {
+                return Err(Error::InvalidWord {
+                    word: SecretWord::default(),
+                    index,
+                });
+            }
+
+            bits.push(Bits11::from(word_index));
+        }
+
+        let entropy = Mnemonic::bits_to_entropy(bits, true, &DefaultCrypto)?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
     }
 
-    /// Get the [`Language`][Language]
+    /// Get the indices of the words in the word list, each being the 11 bit value of a word
     ///
-    /// [Language]: ../language/struct.Language.html
-    pub fn language(&self) -> Language {
-        self.lang
+    /// See [`Mnemonic::from_indices()`][Mnemonic::from_indices()] for the inverse.
+    ///
+    /// [Mnemonic::from_indices()]: ../mnemonic/struct.Mnemonic.html#method.from_indices
+    pub fn to_indices(&self) -> Zeroizing<Vec<u16>> {
+        let checksum_byte = sha256_first_byte(&DefaultCrypto, &self.entropy);
+
+        Zeroizing::new(
+            self.entropy
+                .iter()
+                .chain(Some(&checksum_byte))
+                .bits::<Bits11>()
+                .map(u16::from)
+                .collect(),
+        )
     }
-}
 
-impl AsRef<str> for Mnemonic {
-    fn as_ref(&self) -> &str {
-        self.phrase()
+    /// Get the phrase as the word numbers of a metal backup plate, from `0001` to `2048`
+    ///
+    /// Each number is the position of the word in the word list starting from 1, unlike
+    /// [`Mnemonic::to_indices()`][Mnemonic::to_indices()], zero-padded to four digits and
+    /// separated by spaces. See [`Mnemonic::from_index_string()`][Mnemonic::from_index_string()]
+    /// for the inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(
+    ///     &*mnemonic.to_index_string(),
+    ///     "1283 1453 1307 0985 1163 1674 0989 0095 1348 1416 0820 0074",
+    /// );
+    /// ```
+    ///
+    /// [Mnemonic::to_indices()]: ../mnemonic/struct.Mnemonic.html#method.to_indices
+    /// [Mnemonic::from_index_string()]: ../mnemonic/struct.Mnemonic.html#method.from_index_string
+    pub fn to_index_string(&self) -> Zeroizing<String> {
+        let indices = self.to_indices();
+        let mut string = Zeroizing::new(String::with_capacity(indices.len() * 5));
+
+        for (position, index) in indices.iter().enumerate() {
+            if position > 0 {
+                string.push(' ');
+            }
+            // Four digits at most, so the string never reallocates
+            let _ = fmt::Write::write_fmt(&mut *string, format_args!("{:04}", index + 1));
+        }
+
+        string
     }
-}
 
-impl fmt::Display for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(self.phrase(), f)
+    /// Create a [`Mnemonic`][Mnemonic] from the word numbers of a metal backup plate, from `1` to
+    /// `2048`, separated by any whitespace, such as spaces or line breaks
+    ///
+    /// Leading zeros are optional. Returns `Error::InvalidWord` with the position of a number that
+    /// isn't one, or is out of range, `Error::InvalidWordCount` without 12, 15, 18, 21 or 24
+    /// numbers, and `Error::InvalidChecksum` like
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let numbers = "1283 1453 1307 985 1163 1674\n989 95 1348 1416 820 74";
+    /// let mnemonic = Mnemonic::from_index_string(numbers, Language::English).unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_index_string(string: &str, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::check_bounds(string)?;
+
+        let mut indices = Zeroizing::new(Vec::with_capacity(24));
+
+        for (index, number) in string.split_whitespace().enumerate() {
+            let word_index = number
+                .bytes()
+                .all(|byte| byte.is_ascii_digit())
+                .then(|| number.parse::<u16>().ok())
+                .flatten()
+                .filter(|word_index| (1..=2048).contains(word_index))
+                .ok_or_else(|| Error::InvalidWord {
+                    word: SecretWord::new(number, None),
+                    index,
+                })?;
+
+            indices.push(word_index - 1);
+        }
+
+        Mnemonic::from_indices(&indices, lang)
     }
-}
 
-impl fmt::Debug for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self.phrase(), f)
-    }
-}
+    /// Split the mnemonic into `n` parts with the SeedXOR scheme, using the operating system
+    /// CSPRNG
+    ///
+    /// Every part is a valid mnemonic of the same length and language, and the entropy of the
+    /// original is the XOR of the entropy of all parts. All `n` parts are needed to get it back
+    /// with [`Mnemonic::combine_xor()`][Mnemonic::combine_xor()], fewer reveal nothing about it.
+    ///
+    /// Returns `Error::InvalidThreshold` if `n` is less than 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let parts = mnemonic.split_xor(3).unwrap();
+    ///
+    /// assert_eq!(Mnemonic::combine_xor(&parts).unwrap(), mnemonic);
+    /// ```
+    ///
+    /// [Mnemonic::combine_xor()]: ../mnemonic/struct.Mnemonic.html#method.combine_xor
+    #[cfg(feature = "rand")]
+    pub fn split_xor(&self, n: usize) -> Result<Vec<Mnemonic>, Error> {
+        self.split_xor_with_rng(&mut rand_core::OsRng, n)
+    }
+
+    /// Split the mnemonic into `n` parts with the SeedXOR scheme, using entropy from `rng`
+    ///
+    /// See [`Mnemonic::split_xor()`][Mnemonic::split_xor()].
+    ///
+    /// [Mnemonic::split_xor()]: ../mnemonic/struct.Mnemonic.html#method.split_xor
+    pub fn split_xor_with_rng<R>(&self, rng: &mut R, n: usize) -> Result<Vec<Mnemonic>, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        if n < 2 {
+            return Err(Error::InvalidThreshold);
+        }
+
+        let mut last = Zeroizing::new(self.entropy.to_vec());
+        let mut parts = Vec::with_capacity(n);
+
+        for _ in 1..n {
+            let entropy = gen_random_bytes(rng, last.len())?;
+
+            for (byte, part_byte) in last.iter_mut().zip(entropy.iter()) {
+                *byte ^= part_byte;
+            }
+
+            parts.push(Mnemonic::from_entropy_unchecked(entropy, self.lang));
+        }
+
+        parts.push(Mnemonic::from_entropy_unchecked(last, self.lang));
+
+        Ok(parts)
+    }
+
+    /// Combine the parts of a SeedXOR split back into the original mnemonic, in the language of
+    /// the first part
+    ///
+    /// The order of the parts doesn't matter. Returns `Error::InvalidThreshold` if there are no
+    /// parts, and `Error::MismatchedShares` if they don't all have the same number of words.
+    pub fn combine_xor(parts: &[Mnemonic]) -> Result<Mnemonic, Error> {
+        let first = parts.first().ok_or(Error::InvalidThreshold)?;
+
+        let mut entropy = Zeroizing::new(first.entropy.to_vec());
+
+        for part in &parts[1..] {
+            if part.entropy.len() != entropy.len() {
+                return Err(Error::MismatchedShares);
+            }
+
+            for (byte, part_byte) in entropy.iter_mut().zip(part.entropy.iter()) {
+                *byte ^= part_byte;
+            }
+        }
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, first.lang))
+    }
+
+    /// Seal the mnemonic under `password`, for storing it at rest, using entropy from the
+    /// operating system CSPRNG
+    ///
+    /// The entropy and language are encrypted with XChaCha20-Poly1305, under a key stretched from
+    /// the password with Argon2id and the default [`Argon2Params`][Argon2Params]. The result is a
+    /// versioned blob that holds the salt, nonce and Argon2id parameters, so all it takes to
+    /// unseal it is [`Mnemonic::unseal()`][Mnemonic::unseal()] and the password.
+    ///
+    /// Returns `Error::UnknownLanguage` for a custom word list, which has no language code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let sealed = mnemonic.seal("hunter2").unwrap();
+    ///
+    /// assert_eq!(Mnemonic::unseal(&sealed, "hunter2").unwrap(), mnemonic);
+    /// assert!(Mnemonic::unseal(&sealed, "hunter3").is_err());
+    /// ```
+    ///
+    /// [Argon2Params]: ../seed/struct.Argon2Params.html
+    /// [Mnemonic::unseal()]: ../mnemonic/struct.Mnemonic.html#method.unseal
+    #[cfg(all(feature = "seal", feature = "rand"))]
+    pub fn seal(&self, password: &str) -> Result<Vec<u8>, Error> {
+        self.seal_with_rng(&mut rand_core::OsRng, password, &Argon2Params::default())
+    }
+
+    /// Seal the mnemonic under `password` with Argon2id `params`, using entropy from `rng`
+    ///
+    /// See [`Mnemonic::seal()`][Mnemonic::seal()]. Returns `Error::InvalidKdfParams` if
+    /// `params` are above 1 GiB of memory, 16 iterations or 16 lanes, which
+    /// [`Mnemonic::unseal()`][Mnemonic::unseal()] would refuse.
+    ///
+    /// [Mnemonic::seal()]: ../mnemonic/struct.Mnemonic.html#method.seal
+    /// [Mnemonic::unseal()]: ../mnemonic/struct.Mnemonic.html#method.unseal
+    #[cfg(feature = "seal")]
+    pub fn seal_with_rng<R>(
+        &self,
+        rng: &mut R,
+        password: &str,
+        params: &Argon2Params,
+    ) -> Result<Vec<u8>, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        seal::seal(rng, &self.entropy, self.lang, password, params)
+    }
+
+    /// Unseal a mnemonic sealed with [`Mnemonic::seal()`][Mnemonic::seal()]
+    ///
+    /// Returns `Error::InvalidPassphrase` if the password is wrong or the blob was altered,
+    /// `Error::InvalidSealedMnemonic` if it isn't a sealed mnemonic at all, and
+    /// `Error::UnsupportedVersion` for a newer version of the format. `Error::UnknownLanguage` is
+    /// returned if the language of the mnemonic isn't enabled.
+    ///
+    /// The Argon2id parameters are read from the blob, and returned as `Error::InvalidKdfParams`
+    /// without stretching the password if they're above 1 GiB of memory, 16 iterations or 16
+    /// lanes, so that a blob from an untrusted source can't take unbounded memory or time.
+    ///
+    /// [Mnemonic::seal()]: ../mnemonic/struct.Mnemonic.html#method.seal
+    #[cfg(feature = "seal")]
+    pub fn unseal(sealed: &[u8], password: &str) -> Result<Mnemonic, Error> {
+        let (entropy, lang) = seal::unseal(sealed, password)?;
+
+        Mnemonic::from_entropy(&entropy, lang)
+    }
+
+    /// Get the modules of a QR code of `payload`, row by row, `true` being dark
+    ///
+    /// The code has the lowest error correction level, as SeedQR codes do, and no quiet zone.
+    /// See the [`qr`][qr] module.
+    ///
+    /// [qr]: ../qr/index.html
+    #[cfg(feature = "qr")]
+    pub fn to_qr_matrix(&self, payload: QrPayload) -> Zeroizing<Vec<Vec<bool>>> {
+        let code = qr::encode(self, payload);
+        let colors = Zeroizing::new(
+            code.to_colors()
+                .into_iter()
+                .map(|color| color == qrcode::Color::Dark)
+                .collect::<Vec<_>>(),
+        );
+
+        Zeroizing::new(colors.chunks(code.width()).map(<[bool]>::to_vec).collect())
+    }
+
+    /// Render a QR code of `payload` as an SVG image, with a quiet zone
+    ///
+    /// See [`Mnemonic::to_qr_matrix()`][Mnemonic::to_qr_matrix()].
+    ///
+    /// [Mnemonic::to_qr_matrix()]: ../mnemonic/struct.Mnemonic.html#method.to_qr_matrix
+    #[cfg(feature = "qr")]
+    pub fn to_qr_svg(&self, payload: QrPayload) -> Zeroizing<String> {
+        Zeroizing::new(
+            qr::encode(self, payload)
+                .render::<qrcode::render::svg::Color>()
+                .build(),
+        )
+    }
+
+    /// Get the mnemonic phrase as a string reference.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Format the phrase for printing on a backup sheet, numbered or in columns, see
+    /// [`PhraseDisplay`][PhraseDisplay]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// println!("{}", mnemonic.display().numbered().columns(2));
+    /// ```
+    ///
+    /// [PhraseDisplay]: ./display/struct.PhraseDisplay.html
+    pub fn display(&self) -> PhraseDisplay<'_> {
+        PhraseDisplay::new(self)
+    }
+
+    /// Iterate over the words of the phrase
+    ///
+    /// This splits on the separator of the language, see [`Language::separator()`][separator].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// for (idx, word) in mnemonic.words().enumerate() {
+    ///     println!("{:>2}. {}", idx + 1, word);
+    /// }
+    ///
+    /// assert_eq!(mnemonic.words().nth(3), Some("kitchen"));
+    /// ```
+    ///
+    /// [separator]: ./language/enum.Language.html#method.separator
+    pub fn words(&self) -> impl Iterator<Item = &str> + Clone + '_ {
+        self.phrase.split(self.lang.separator())
+    }
+
+    /// Iterate over the words of the phrase in groups of `size`, for showing or reading out the
+    /// phrase in blocks
+    ///
+    /// The last group is shorter if `size` doesn't divide the word count, and a `size` of 0 is
+    /// taken as 1. Each group is collected from [`Mnemonic::words()`][Mnemonic::words()], since
+    /// the words aren't stored apart from the phrase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let mut groups = mnemonic.word_groups(4);
+    ///
+    /// assert_eq!(groups.next(), Some(vec!["park", "remain", "person", "kitchen"]));
+    /// assert_eq!(groups.count(), 2);
+    /// ```
+    ///
+    /// [Mnemonic::words()]: ./mnemonic/struct.Mnemonic.html#method.words
+    pub fn word_groups(&self, size: usize) -> impl Iterator<Item = Vec<&str>> + '_ {
+        let size = size.max(1);
+        let mut words = self.words();
+
+        iter::from_fn(move || {
+            let group: Vec<&str> = words.by_ref().take(size).collect();
+            Some(group).filter(|group| !group.is_empty())
+        })
+    }
+
+    /// Get the number of words in the phrase
+    pub fn word_count(&self) -> usize {
+        // One checksum bit for every 32 bits of entropy, and 11 bits per word
+        self.entropy.len() * 8 * 33 / 32 / 11
+    }
+
+    /// Consume the `Mnemonic` and return the phrase as a `String`.
+    pub fn into_phrase(mut self) -> String {
+        // Create an empty string and swap values with the mnemonic's phrase.
+        // This allows `Mnemonic` to implement `Drop`, while still returning the phrase.
+        mem::take(&mut self.phrase)
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let entropy: &[u8] = mnemonic.entropy();
+    /// ```
+    ///
+    /// **Note:** You shouldn't use the generated entropy as secrets, for that generate a new
+    /// `Seed` from the `Mnemonic`.
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Lend the entropy to `f` for the duration of the call, and return what it returns
+    ///
+    /// Like [`Seed::with_bytes()`][Seed::with_bytes()], no copy of the entropy is made, so it
+    /// can't outlive the closure unless `f` copies it out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let bits = mnemonic.with_entropy(|entropy| entropy.len() * 8);
+    ///
+    /// assert_eq!(bits, 128);
+    /// ```
+    ///
+    /// [Seed::with_bytes()]: ../seed/struct.Seed.html#method.with_bytes
+    pub fn with_entropy<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        f(&self.entropy)
+    }
+
+    /// Copy the entropy into an array of `N` bytes, which can stay on the stack
+    ///
+    /// `N` is the [`MnemonicType::entropy_len()`][entropy_len] of the mnemonic, anything else
+    /// returns `Error::InvalidEntropyLength` with the `N * 8` bits asked for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let entropy = mnemonic.entropy_array::<{ MnemonicType::Words12.entropy_len() }>().unwrap();
+    ///
+    /// assert_eq!(entropy[..4], [0x33, 0xe4, 0x6b, 0xb1]);
+    /// assert!(mnemonic.entropy_array::<32>().is_err());
+    /// ```
+    ///
+    /// [entropy_len]: ../mnemonic_type/enum.MnemonicType.html#method.entropy_len
+    pub fn entropy_array<const N: usize>(&self) -> Result<Zeroizing<[u8; N]>, Error> {
+        if self.entropy.len() != N {
+            return Err(Error::InvalidEntropyLength {
+                got: N * 8,
+                expected: MnemonicType::for_entropy_len(self.entropy.len())?,
+            });
+        }
+
+        let mut entropy = Zeroizing::new([0; N]);
+        entropy.copy_from_slice(&self.entropy);
+
+        Ok(entropy)
+    }
+
+    /// Copy the entropy into the start of `out`, and return its length, without allocating
+    ///
+    /// Returns `Error::BufferTooSmall` with the length of the entropy if it doesn't fit, in which
+    /// case nothing is written. A buffer of [`fixed::MAX_ENTROPY_BYTES`][max] always fits.
+    ///
+    /// [max]: ../fixed/constant.MAX_ENTROPY_BYTES.html
+    pub fn entropy_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let len = self.entropy.len();
+
+        if out.len() < len {
+            return Err(Error::BufferTooSmall(len));
+        }
+
+        out[..len].copy_from_slice(&self.entropy);
+
+        Ok(len)
+    }
+
+    /// Validate `phrase` and write its entropy into the start of `out`, returning its length,
+    /// without allocating
+    ///
+    /// Unlike [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], the phrase isn't normalized,
+    /// see [`fixed::phrase_to_entropy()`][phrase_to_entropy] for the details and errors.
+    /// Returns `Error::BufferTooSmall` with the length of the entropy if it doesn't fit in `out`,
+    /// in which case nothing is written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    ///
+    /// let mut entropy = [0; 32];
+    /// let len = Mnemonic::to_entropy_into(phrase, Language::English, &mut entropy).unwrap();
+    ///
+    /// assert_eq!(entropy[..len], [0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79]);
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [phrase_to_entropy]: ../fixed/fn.phrase_to_entropy.html
+    pub fn to_entropy_into(phrase: &str, lang: Language, out: &mut [u8]) -> Result<usize, Error> {
+        Mnemonic::check_bounds(phrase)?;
+
+        let mut entropy = Zeroizing::new([0; fixed::MAX_ENTROPY_BYTES]);
+        let len = fixed::phrase_to_entropy(phrase, lang, &mut entropy)?.entropy_len();
+
+        if out.len() < len {
+            return Err(Error::BufferTooSmall(len));
+        }
+
+        out[..len].copy_from_slice(&entropy[..len]);
+
+        Ok(len)
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a lowercase hex string.
+    ///
+    /// The entropy is decoded once when the [`Mnemonic`][Mnemonic] is created, so this never
+    /// re-parses the phrase and can't fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    ///
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.entropy_hex(), "33e46bb13a746ea41cdde45c90846a79");
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn entropy_hex(&self) -> String {
+        format!("{:x}", self)
+    }
+
+    /// Encode the entropy with the standard base64 alphabet of RFC 4648, with padding
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let encoded = mnemonic.entropy_base64();
+    ///
+    /// assert_eq!(&encoded[..], "M+RrsTp0bqQc3eRckIRqeQ==");
+    /// assert_eq!(Mnemonic::from_entropy_base64(&encoded, Language::English).unwrap(), mnemonic);
+    /// ```
+    pub fn entropy_base64(&self) -> Zeroizing<String> {
+        encoding::to_base64(&self.entropy)
+    }
+
+    /// Encode `version` followed by the entropy with Base58Check
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let encoded = mnemonic.entropy_base58check(&[0x80]);
+    /// let decoded = Mnemonic::from_entropy_base58check(&encoded, &[0x80], Language::English);
+    ///
+    /// assert_eq!(decoded.unwrap(), mnemonic);
+    /// ```
+    pub fn entropy_base58check(&self, version: &[u8]) -> Zeroizing<String> {
+        encoding::to_base58check(version, &self.entropy)
+    }
+
+    /// Encode the entropy with bech32, as in BIP173, under the human readable part `hrp`
+    ///
+    /// The output is lowercase. Returns `Error::InvalidEncoding` if `hrp` is empty, or has
+    /// uppercase or non-printable ASCII characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let encoded = mnemonic.entropy_bech32("entropy").unwrap();
+    /// let decoded = Mnemonic::from_entropy_bech32(&encoded, "entropy", Language::English);
+    ///
+    /// assert_eq!(decoded.unwrap(), mnemonic);
+    /// ```
+    pub fn entropy_bech32(&self, hrp: &str) -> Result<Zeroizing<String>, Error> {
+        encoding::to_bech32(hrp, &self.entropy)
+    }
+
+    /// Get the [`Language`][Language]
+    ///
+    /// [Language]: ../language/struct.Language.html
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+
+    /// Write the same entropy with the word list of `target`, for instance to move a backup to a
+    /// word list its owner can read and check
+    ///
+    /// **Note:** the seed is derived from the phrase, not the entropy, so the translated mnemonic
+    /// has another [`Seed`][Seed], and so another wallet. Only wallets that store the entropy,
+    /// and re-derive everything from it in the new language, keep their keys. Every word list has
+    /// 2048 words, so any entropy can be translated, and this never fails for now.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let translated = mnemonic.translate(Language::English).unwrap();
+    ///
+    /// assert_eq!(translated.entropy(), mnemonic.entropy());
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn translate(&self, target: Language) -> Result<Mnemonic, Error> {
+        let entropy = Zeroizing::new(self.entropy.to_vec());
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, target))
+    }
+
+    /// Check if `phrase` is the same phrase as this mnemonic, in constant time.
+    ///
+    /// The supplied phrase is normalized the same way as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()],
+    /// but isn't validated. Only the length of the normalized phrase can be learned from timing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.ct_eq_phrase(" park remain person kitchen mule spell knee armed position rail grid  ankle"));
+    /// assert!(!mnemonic.ct_eq_phrase("park remain person kitchen mule spell knee armed position rail grid anchor"));
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn ct_eq_phrase(&self, phrase: &str) -> bool {
+        let phrase = Mnemonic::normalize_phrase(phrase, self.lang);
+
+        self.phrase.as_bytes().ct_eq(phrase.as_bytes()).into()
+    }
+
+    /// Check if `other` has the same entropy as this mnemonic, in constant time, whatever the
+    /// language of either
+    ///
+    /// Unlike `==`, which also compares the languages, this finds the same backup written in
+    /// two languages, for instance to detect duplicates. Only whether both have the same number
+    /// of words can be learned from timing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let copy = Mnemonic::from_phrase(&phrase.replace(' ', "  "), Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.same_entropy(&copy));
+    /// ```
+    pub fn same_entropy(&self, other: &Mnemonic) -> bool {
+        self.entropy.ct_eq(&other.entropy).into()
+    }
+
+    /// Check a backup of this mnemonic, typically the phrase re-typed by the user after writing
+    /// it down, and tell whether it matches.
+    ///
+    /// The backup is normalized the same way as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()],
+    /// and compared word by word in constant time. Only whether the number of words matches is
+    /// reported beyond that, so the result can be shown as is, see
+    /// [`Mnemonic::confirm_backup_with_position()`][Mnemonic::confirm_backup_with_position()] to
+    /// also point out the first word that's wrong.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{BackupCheck, Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.confirm_backup(phrase), BackupCheck::Match);
+    /// assert_eq!(mnemonic.confirm_backup("park remain person"), BackupCheck::WordCountMismatch);
+    /// assert_eq!(mnemonic.confirm_backup(&phrase.replace("mule", "mile")), BackupCheck::Mismatch);
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Mnemonic::confirm_backup_with_position()]: ../mnemonic/struct.Mnemonic.html#method.confirm_backup_with_position
+    pub fn confirm_backup(&self, backup: &str) -> BackupCheck {
+        match self.confirm_backup_with_position(backup) {
+            BackupCheck::MismatchAt(_) => BackupCheck::Mismatch,
+            check => check,
+        }
+    }
+
+    /// Same as [`Mnemonic::confirm_backup()`][Mnemonic::confirm_backup()], reporting the index of
+    /// the first word that's different with `BackupCheck::MismatchAt`.
+    ///
+    /// Only call this when telling the user where the backup is wrong is worth telling anyone
+    /// watching the screen which words are right.
+    ///
+    /// [Mnemonic::confirm_backup()]: ../mnemonic/struct.Mnemonic.html#method.confirm_backup
+    pub fn confirm_backup_with_position(&self, backup: &str) -> BackupCheck {
+        let backup = Mnemonic::normalize_phrase(backup, self.lang);
+
+        let word_count = self.phrase.split(is_word_separator).count();
+        if backup.split(is_word_separator).count() != word_count {
+            return BackupCheck::WordCountMismatch;
+        }
+
+        let mut differs = Choice::from(0);
+        let mut first = 0u32;
+
+        let words = self
+            .phrase
+            .split(is_word_separator)
+            .zip(backup.split(is_word_separator));
+        for (index, (word, typed)) in words.enumerate() {
+            let different = !word.as_bytes().ct_eq(typed.as_bytes());
+
+            first.conditional_assign(&(index as u32), different & !differs);
+            differs |= different;
+        }
+
+        if bool::from(differs) {
+            BackupCheck::MismatchAt(first as usize)
+        } else {
+            BackupCheck::Match
+        }
+    }
+
+    /// Look for known-weak patterns in the mnemonic, which is valid but unlikely to have been
+    /// generated randomly
+    ///
+    /// Returns an empty list if none are found. Phrases made of one repeated word, of consecutive
+    /// words from the wordlist, or from constant entropy such as all zeros are the ones people
+    /// try out or make up by hand, and their funds get swept by bots watching for them. Wallets
+    /// importing a phrase can warn about these, rather than reject them, as test wallets use them
+    /// on purpose.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{AuditWarning, Mnemonic, Language};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(
+    ///     mnemonic.audit(),
+    ///     [AuditWarning::RepeatedWord("abandon"), AuditWarning::ConstantEntropy(0)],
+    /// );
+    /// ```
+    pub fn audit(&self) -> Vec<AuditWarning> {
+        let indices = self.to_indices();
+        // The last word has checksum bits, so it can't follow any pattern
+        let body = &indices[..indices.len() - 1];
+
+        let mut warnings = Vec::new();
+
+        if body.windows(2).all(|pair| pair[0] == pair[1]) {
+            if let Some(word) = self.lang.word_at(body[0]) {
+                warnings.push(AuditWarning::RepeatedWord(word));
+            }
+        }
+
+        let counts = |step: i32| {
+            body.windows(2)
+                .all(|pair| i32::from(pair[1]) - i32::from(pair[0]) == step)
+        };
+        if counts(1) || counts(-1) {
+            warnings.push(AuditWarning::SequentialWords);
+        }
+
+        if let Some(&first) = self.entropy.first() {
+            if self.entropy.iter().all(|&byte| byte == first) {
+                warnings.push(AuditWarning::ConstantEntropy(first));
+            }
+        }
+
+        warnings
+    }
+
+    /// Find the words of the mnemonic that are in the wordlist of another enabled language too,
+    /// but at another index
+    ///
+    /// Returns an empty list if there are none. Some wordlists share words, English and French
+    /// have a hundred in common, so a phrase can be made of words that are all valid in
+    /// another language, and decode to other entropy there. Recovery tools that guess the
+    /// language of a phrase, or convert it through its entropy, can use this to warn that
+    /// assuming the wrong language would silently give another wallet. Words at the same index
+    /// in both languages decode to the same bits, so they aren't reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// for homograph in mnemonic.homographs() {
+    ///     println!("word {} is also in {:?}", homograph.index + 1, homograph.language);
+    /// }
+    /// ```
+    pub fn homographs(&self) -> Vec<Homograph> {
+        let others: Vec<Language> = Language::known()
+            .into_iter()
+            .filter(|&other| other != self.lang)
+            .collect();
+
+        let mut homographs = Vec::new();
+
+        for (index, &bits) in self.to_indices().iter().enumerate() {
+            let word = match self.lang.word_at(bits) {
+                Some(word) => word,
+                None => continue,
+            };
+
+            for &language in &others {
+                match language.index_of(word) {
+                    Some(other_index) if other_index != bits => homographs.push(Homograph {
+                        index,
+                        word,
+                        language,
+                        other_index,
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        homographs
+    }
+
+    /// Get a short identifier of the mnemonic, to correlate log entries and detect duplicates
+    /// without storing the phrase
+    ///
+    /// The identifier is the first 8 bytes, in lowercase hex, of HMAC-SHA256 keyed with `salt`
+    /// over `"tiny-bip39 fingerprint id v1"` followed by the entropy. This scheme won't change,
+    /// so identifiers can be stored. It depends on the entropy only, so the same entropy in two
+    /// languages has the same identifier. The 128 bits or more of entropy can't be found from the
+    /// identifier, and with a salt kept secret by each system, the identifiers of one system can't
+    /// be matched against those of another.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.fingerprint_id(b"salt"), "9f8e4932fd99ad43");
+    /// ```
+    pub fn fingerprint_id(&self, salt: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(salt).expect("HMAC accepts keys of any size");
+        mac.update(FINGERPRINT_ID_DOMAIN);
+        mac.update(&self.entropy);
+
+        String::from(encoding::to_hex(&mac.finalize().into_bytes()[..8]).as_str())
+    }
+
+    /// Derive the HD wallet [`Seed`][Seed] for this mnemonic and the given passphrase.
+    ///
+    /// The seed is never computed when constructing a [`Mnemonic`][Mnemonic], so validating a
+    /// phrase stays cheap, and the same mnemonic can be combined with any number of passphrases.
+    /// This is equivalent to calling [`Seed::new()`][Seed::new()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = mnemonic.to_seed("passphrase");
+    ///
+    /// assert_eq!(seed.as_bytes().len(), 64);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Seed::new()]: ../seed/struct.Seed.html#method.new
+    pub fn to_seed(&self, passphrase: &str) -> Seed {
+        Seed::new(self, passphrase)
+    }
+
+    /// Derive the HD wallet [`Seed`][Seed] for this mnemonic and the given passphrase, calling
+    /// `progress` with the number of PBKDF2 rounds done and the total number of rounds as it goes
+    ///
+    /// This is equivalent to calling [`Seed::new_with_progress()`][Seed::new_with_progress()] with
+    /// the BIP39 parameters.
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Seed::new_with_progress()]: ../seed/struct.Seed.html#method.new_with_progress
+    pub fn to_seed_with_progress<F>(&self, passphrase: &str, progress: F) -> Seed
+    where
+        F: FnMut(u64, u64),
+    {
+        Seed::new_with_progress(self, passphrase, &SeedDerivationParams::bip39(), progress)
+    }
+
+    /// Derive the HD wallet [`Seed`][Seed] for this mnemonic and the given passphrase, on tokio's
+    /// blocking thread pool
+    ///
+    /// PBKDF2 keeps a thread busy for a while, which stalls every other task of an async runtime
+    /// scheduled on it, so this moves it to a thread meant for blocking work. It must be called
+    /// from within a tokio runtime. The seed is the same as
+    /// [`Mnemonic::to_seed()`][Mnemonic::to_seed()] gives.
+    ///
+    /// Returns `Error::TaskCancelled` if the runtime shuts down before the blocking task is run,
+    /// in which case there's no seed. A panic of the task is resumed in the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let seed = runtime.block_on(mnemonic.to_seed_async("passphrase")).unwrap();
+    ///
+    /// assert_eq!(seed, mnemonic.to_seed("passphrase"));
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Mnemonic::to_seed()]: ../mnemonic/struct.Mnemonic.html#method.to_seed
+    #[cfg(feature = "async")]
+    pub async fn to_seed_async(&self, passphrase: &str) -> Result<Seed, Error> {
+        let mnemonic = self.clone();
+        let passphrase = Zeroizing::new(String::from(passphrase));
+
+        match tokio::task::spawn_blocking(move || Seed::new(&mnemonic, &passphrase)).await {
+            Ok(seed) => Ok(seed),
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(Error::TaskCancelled),
+        }
+    }
+
+    /// Derive the 32 byte mini-secret used by Substrate and Polkadot wallets
+    ///
+    /// Unlike the BIP39 seed, this is derived from the entropy rather than the phrase, so it
+    /// doesn't depend on the language. The salt is the same, `"mnemonic"` followed by the
+    /// passphrase, and the mini-secret is the first half of the PBKDF2-HMAC-SHA512 output, as
+    /// in the `substrate-bip39` crate. As there, the passphrase isn't NFKD normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+    ///
+    /// let mini_secret = mnemonic.to_substrate_mini_secret("Substrate");
+    ///
+    /// assert_eq!(mini_secret[..4], [0x44, 0xe9, 0xd1, 0x25]);
+    /// ```
+    pub fn to_substrate_mini_secret(&self, passphrase: &str) -> Zeroizing<[u8; 32]> {
+        let mut salt = Zeroizing::new(String::with_capacity(8 + passphrase.len()));
+        salt.push_str("mnemonic");
+        salt.push_str(passphrase);
+
+        let params = SeedDerivationParams::bip39();
+        let seed = Zeroizing::new(pbkdf2(&DefaultCrypto, &self.entropy, &salt, &params));
+
+        let mut mini_secret = Zeroizing::new([0u8; 32]);
+        mini_secret.copy_from_slice(&seed[..32]);
+
+        mini_secret
+    }
+
+    /// Derive the 96 byte Cardano master key with the Icarus scheme, enabled with the `cardano`
+    /// feature
+    ///
+    /// As in [CIP-3], this is PBKDF2-HMAC-SHA512 with 4096 rounds over the passphrase, salted
+    /// with the entropy, clamped into a valid extended Ed25519 key. The first 64 bytes are the
+    /// extended private key and the last 32 the chain code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "eight country switch draw meat scout mystery blade tip drift useless good \
+    ///               keep usage title";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let master_key = mnemonic.to_icarus_master_key("");
+    ///
+    /// assert_eq!(master_key.len(), 96);
+    /// ```
+    ///
+    /// [CIP-3]: https://cips.cardano.org/cip/CIP-0003
+    #[cfg(feature = "cardano")]
+    pub fn to_icarus_master_key(&self, passphrase: &str) -> Zeroizing<[u8; 96]> {
+        let mut key = Zeroizing::new([0u8; 96]);
+
+        pbkdf2_sha512(
+            passphrase.as_bytes(),
+            &self.entropy,
+            ICARUS_ROUNDS,
+            &mut key[..],
+        );
+
+        key[0] &= 0b1111_1000;
+        key[31] &= 0b0001_1111;
+        key[31] |= 0b0100_0000;
+
+        key
+    }
+
+    /// Derive a [`Seed`][Seed] with non-standard PBKDF2 parameters, see
+    /// [`SeedDerivationParams`][SeedDerivationParams]
+    ///
+    /// This is equivalent to calling [`Seed::new_with_params()`][Seed::new_with_params()].
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [SeedDerivationParams]: ../seed/struct.SeedDerivationParams.html
+    /// [Seed::new_with_params()]: ../seed/struct.Seed.html#method.new_with_params
+    pub fn to_seed_with_params(&self, passphrase: &str, params: &SeedDerivationParams) -> Seed {
+        Seed::new_with_params(self, passphrase, params)
+    }
+
+    /// Derive a [`Seed`][Seed] with another key derivation function, see [`SeedKdf`][SeedKdf]
+    ///
+    /// This is equivalent to calling [`Seed::new_with_kdf()`][Seed::new_with_kdf()].
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [SeedKdf]: ../seed/enum.SeedKdf.html
+    /// [Seed::new_with_kdf()]: ../seed/struct.Seed.html#method.new_with_kdf
+    pub fn to_seed_with_kdf(&self, passphrase: &str, kdf: &SeedKdf) -> Seed {
+        Seed::new_with_kdf(self, passphrase, kdf)
+    }
+}
+
+impl AsRef<str> for Mnemonic {
+    fn as_ref(&self) -> &str {
+        self.phrase()
+    }
+}
+
+/// Compares the language and the entropy in constant time.
+impl ConstantTimeEq for Mnemonic {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from((self.lang == other.lang) as u8) & self.entropy.ct_eq(&other.entropy)
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Mnemonic {}
+
+/// Hashes the language and the entropy, consistently with `Eq`
+///
+/// Hashing isn't constant time, so keep a mnemonic in a `HashMap` or `HashSet` with a keyed
+/// hasher, such as the default `RandomState`, whose hashes can't be predicted from the entropy.
+impl core::hash::Hash for Mnemonic {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.lang.hash(state);
+        self.entropy.hash(state);
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.phrase(), f)
+    }
+}
+
+/// The phrase is a secret, so it's never printed, use [`Mnemonic::phrase()`][Mnemonic::phrase()]
+/// or `Display` to get it explicitly.
+///
+/// [Mnemonic::phrase()]: ./mnemonic/struct.Mnemonic.html#method.phrase
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Mnemonic(****)")
+    }
+}
+
+impl fmt::LowerHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.entropy() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.entropy() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Serialized as its phrase and language code, e.g. `{"phrase":"...","language":"en"}`
+///
+/// Deserializing validates the phrase again, and derives the entropy from it.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mnemonic {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Mnemonic", 2)?;
+        state.serialize_field("phrase", self.phrase())?;
+        state.serialize_field("language", &self.lang)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mnemonic {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Mnemonic")]
+        struct Fields {
+            phrase: String,
+            language: Language,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        let phrase = Zeroizing::new(fields.phrase);
+
+        Mnemonic::from_phrase(&phrase, fields.language).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A valid mnemonic in a built-in language, with entropy taken from the fuzzer's bytes
+///
+/// Running out of bytes gives zeroed entropy rather than an error, so every input makes a
+/// mnemonic.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Mnemonic {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mtype: MnemonicType = u.arbitrary()?;
+        let lang: Language = u.arbitrary()?;
+        let mut entropy = Zeroizing::new(alloc::vec![0; mtype.entropy_len()]);
+        u.fill_buffer(&mut entropy)?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+}
+
+/// Parses a phrase in any built-in language, with [`Language::detect()`][Language::detect()]
+///
+/// If no language matches, the phrase is parsed as English, so the error says what's wrong with
+/// it. Use [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] to parse a phrase in a known
+/// language.
+///
+/// ```
+/// use bip39::{Language, Mnemonic};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic: Mnemonic = phrase.parse().unwrap();
+///
+/// assert_eq!(mnemonic.language(), Language::English);
+/// ```
+///
+/// [Language::detect()]: ./language/enum.Language.html#method.detect
+/// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Self, Error> {
+        let lang = Language::detect(phrase).unwrap_or_default();
+
+        Mnemonic::from_phrase(phrase, lang)
+    }
+}
+
+/// Same as [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()], in English
+///
+/// [Mnemonic::from_entropy()]: ./mnemonic/struct.Mnemonic.html#method.from_entropy
+impl TryFrom<&[u8]> for Mnemonic {
+    type Error = Error;
+
+    fn try_from(entropy: &[u8]) -> Result<Self, Error> {
+        Mnemonic::from_entropy(entropy, Language::English)
+    }
+}
+
+/// Same as `FromStr`, detecting the language of the phrase
+impl TryFrom<&str> for Mnemonic {
+    type Error = Error;
+
+    fn try_from(phrase: &str) -> Result<Self, Error> {
+        phrase.parse()
+    }
+}
+
+impl From<Mnemonic> for String {
+    fn from(val: Mnemonic) -> String {
+        val.into_phrase()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::RngError;
+    use core::num::NonZeroU32;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    /// Not random at all, only useful to check the output of `Mnemonic::new_with_rng`
+    struct ZeroRng;
+
+    impl RngCore for ZeroRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ZeroRng {}
+
+    /// Fails like a broken hardware RNG would
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            if let Err(err) = self.try_fill_bytes(dest) {
+                panic!("FailingRng always fails, use try_fill_bytes: {}", err);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), rand_core::Error> {
+            let code = NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap();
+
+            Err(rand_core::Error::from(code))
+        }
+    }
+
+    impl CryptoRng for FailingRng {}
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn gen_random_bytes_fails_with_rng() {
+        let code = NonZeroU32::new(rand_core::Error::CUSTOM_START);
+
+        assert_eq!(
+            gen_random_bytes(&mut FailingRng, 16).map(|_| ()),
+            Err(Error::RandomSource(RngError::new(code)))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[should_panic(expected = "random number generator failed to provide entropy")]
+    fn mnemonic_with_failing_rng() {
+        Mnemonic::new_with_rng(&mut FailingRng, MnemonicType::Words12, Language::English);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn try_mnemonic_with_failing_rng() {
+        let code = NonZeroU32::new(rand_core::Error::CUSTOM_START);
+
+        assert_eq!(
+            Mnemonic::try_new_with_rng(&mut FailingRng, MnemonicType::Words12, Language::English)
+                .map(|_| ()),
+            Err(Error::RandomSource(RngError::new(code)))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_with_rng() {
+        let mnemonic =
+            Mnemonic::new_with_rng(&mut ZeroRng, MnemonicType::Words12, Language::English);
+
+        assert_eq!(
+            mnemonic.phrase(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        assert_eq!(mnemonic.entropy(), &[0; 16]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_mixed() {
+        let user_entropy = [0u8; 32];
+
+        let first =
+            Mnemonic::new_mixed(&user_entropy, MnemonicType::Words24, Language::English).unwrap();
+        let second =
+            Mnemonic::new_mixed(&user_entropy, MnemonicType::Words24, Language::English).unwrap();
+
+        assert_eq!(first.phrase().split(' ').count(), 24);
+        assert_ne!(first.entropy(), &user_entropy);
+        assert_ne!(first.entropy(), second.entropy());
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_mixed_invalid_length() {
+        assert_eq!(
+            Mnemonic::new_mixed(&[0; 16], MnemonicType::Words24, Language::English).map(|_| ()),
+            Err(Error::InvalidEntropyLength {
+                got: 128,
+                expected: MnemonicType::Words24
+            })
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn back_to_back() {
+        let m1 = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let m2 = Mnemonic::from_phrase(m1.phrase(), Language::English).unwrap();
+        let m3 = Mnemonic::from_entropy(m1.entropy(), Language::English).unwrap();
+
+        assert_eq!(m1.entropy(), m2.entropy(), "Entropy must be the same");
+        assert_eq!(m1.entropy(), m3.entropy(), "Entropy must be the same");
+        assert_eq!(m1.phrase(), m2.phrase(), "Phrase must be the same");
+        assert_eq!(m1.phrase(), m3.phrase(), "Phrase must be the same");
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate() {
+        let mnemonic = Mnemonic::generate();
+        assert_eq!(mnemonic.word_count(), 12);
+        assert_eq!(mnemonic.language(), Language::English);
+
+        let mnemonic = Mnemonic::generate_in(Language::English, WordCount::Words18).unwrap();
+        assert_eq!(mnemonic.word_count(), 18);
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate_batch() {
+        let mnemonics =
+            Mnemonic::generate_batch(50, MnemonicType::Words15, Language::English).unwrap();
+
+        assert_eq!(mnemonics.len(), 50);
+        for (index, mnemonic) in mnemonics.iter().enumerate() {
+            assert_eq!(mnemonic.word_count(), 15);
+            assert!(Mnemonic::validate(mnemonic.phrase(), Language::English).is_ok());
+            assert!(mnemonics[..index].iter().all(|other| other != mnemonic));
+        }
+
+        let none = Mnemonic::generate_batch(0, MnemonicType::Words12, Language::English);
+        assert_eq!(none.map(|mnemonics| mnemonics.len()), Ok(0));
+
+        let too_many =
+            Mnemonic::generate_batch(usize::MAX, MnemonicType::Words12, Language::English);
+        assert_eq!(too_many.map(|_| ()), Err(Error::BatchTooLarge(usize::MAX)));
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate_where() {
+        let distinct_prefixes = |mnemonic: &Mnemonic| {
+            let mut prefixes: Vec<String> = mnemonic
+                .phrase()
+                .split(' ')
+                .map(|word| word.chars().take(4).collect())
+                .collect();
+            prefixes.sort();
+            prefixes.windows(2).all(|pair| pair[0] != pair[1])
+        };
+
+        let mnemonic =
+            Mnemonic::generate_where(MnemonicType::Words24, Language::English, distinct_prefixes)
+                .unwrap();
+        assert!(distinct_prefixes(&mnemonic));
+
+        let result = Mnemonic::generate_where(MnemonicType::Words12, Language::English, |_| false);
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::NoMatchingMnemonic(Mnemonic::MAX_GENERATE_ATTEMPTS))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_from_entropy() {
+        let entropy = &[
+            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
+            0x6A, 0x79,
+        ];
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+
+        assert_eq!(phrase, mnemonic.phrase());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_from_phrase() {
+        let entropy = &[
+            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
+            0x6A, 0x79,
+        ];
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(entropy, mnemonic.entropy());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_to_seed() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            mnemonic.to_seed("password").as_bytes(),
+            Seed::new(&mnemonic, "password").as_bytes()
+        );
+        assert_ne!(
+            mnemonic.to_seed("password").as_bytes(),
+            mnemonic.to_seed("").as_bytes()
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "japanese")]
+    fn japanese_ideographic_space() {
+        let entropy = &[0u8; 16];
+        let mnemonic = Mnemonic::from_entropy(entropy, Language::Japanese).unwrap();
+
+        assert_eq!(mnemonic.phrase().split('\u{3000}').count(), 12);
+        assert!(!mnemonic.phrase().contains(' '));
+
+        let ascii_phrase = mnemonic.phrase().replace('\u{3000}', " ");
+
+        assert!(Mnemonic::validate(mnemonic.phrase(), Language::Japanese).is_ok());
+        assert!(Mnemonic::validate(&ascii_phrase, Language::Japanese).is_ok());
+
+        let from_ascii = Mnemonic::from_phrase(&ascii_phrase, Language::Japanese).unwrap();
+
+        assert_eq!(from_ascii.phrase(), mnemonic.phrase());
+        assert_eq!(
+            from_ascii.to_seed("").as_bytes(),
+            mnemonic.to_seed("").as_bytes()
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_format() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English);
+
+        assert_eq!(mnemonic.phrase(), format!("{}", mnemonic));
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_eq() {
+        let m1 = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let m2 = Mnemonic::from_phrase(m1.phrase(), Language::English).unwrap();
+        let m3 = Mnemonic::new(MnemonicType::Words12, Language::English);
+
+        assert_eq!(m1, m2);
+        assert_ne!(m1, m3);
+        assert!(m1.ct_eq_phrase(m2.phrase()));
+        assert!(!m1.ct_eq_phrase(m3.phrase()));
+    }
+
+    #[cfg(feature = "rand")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_debug_is_redacted() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+
+        assert_eq!(format!("{:?}", mnemonic), "Mnemonic(****)");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy_array() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mnemonic = Mnemonic::new_with_rng(&mut rng, MnemonicType::Words24, Language::English);
+
+        let entropy = mnemonic.entropy_array::<{ MnemonicType::Words24.entropy_len() }>();
+        assert_eq!(&entropy.unwrap()[..], mnemonic.entropy());
+        assert!(mnemonic.with_entropy(|entropy| entropy == mnemonic.entropy()));
+
+        assert_eq!(
+            mnemonic.entropy_array::<16>().map(|_| ()),
+            Err(Error::InvalidEntropyLength {
+                got: 128,
+                expected: MnemonicType::Words24
+            })
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn validate_batch() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut phrases: Vec<String> = (0..100)
+            .map(|_| Mnemonic::new_with_rng(&mut rng, MnemonicType::Words12, Language::English))
+            .map(Mnemonic::into_phrase)
+            .collect();
+        phrases.push(phrase.replace("park", "remain"));
+        phrases.push(phrase.replace("park", "parc"));
+
+        let phrases: Vec<&str> = phrases.iter().map(String::as_str).collect();
+        let results = Mnemonic::validate_batch(&phrases, Language::English);
+
+        assert_eq!(results.len(), 102);
+        assert!(results[..100].iter().all(Result::is_ok));
+        assert_eq!(results[100], Err(Error::InvalidChecksum));
+        assert_eq!(
+            results[101],
+            Mnemonic::validate(phrases[101], Language::English)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let same = Mnemonic::from_entropy(mnemonic.entropy(), Language::English).unwrap();
+        let other = Mnemonic::new_with_rng(&mut rng, MnemonicType::Words12, Language::English);
+
+        let mut set = HashSet::new();
+        assert!(set.insert(mnemonic.clone()));
+        assert!(!set.insert(same));
+        assert!(set.insert(other));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&mnemonic));
+
+        // The same entropy in another language is another mnemonic
+        #[cfg(feature = "french")]
+        {
+            let french = Mnemonic::from_entropy(mnemonic.entropy(), Language::French).unwrap();
+            assert!(set.insert(french));
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let empty = Mnemonic::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!(empty.entropy(), &[0; 16]);
+        assert_eq!(empty.language(), Language::English);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut bytes = [0; 64];
+
+        for _ in 0..100 {
+            rng.fill_bytes(&mut bytes);
+            let mnemonic = Mnemonic::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+            assert!(Mnemonic::validate(mnemonic.phrase(), mnemonic.language()).is_ok());
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn input_bounds() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+        let padded = format!("{}{}", " ".repeat(Mnemonic::MAX_PHRASE_LEN), phrase);
+        let too_long = Err(Error::PhraseTooLong(padded.len()));
+        assert_eq!(Mnemonic::validate(&padded, Language::English), too_long);
+        assert_eq!(
+            Mnemonic::from_phrase(&padded, Language::English).map(|_| ()),
+            too_long
+        );
+        let options = ValidationOptions::default();
+        assert_eq!(
+            Mnemonic::validate_with(&padded, Language::English, &options),
+            too_long
+        );
+        assert_eq!(
+            Mnemonic::diagnose(&padded, Language::English).issues(),
+            [ValidationIssue::PhraseTooLong(padded.len())]
+        );
+
+        let padded = format!("{}{}", " ".repeat(1000), phrase);
+        assert!(Mnemonic::from_phrase(&padded, Language::English).is_ok());
+
+        // The word count is checked before any word is looked up
+        let repeated = "notaword ".repeat(100);
+        let lang = Language::English;
+        assert_eq!(
+            Mnemonic::validate(&repeated, lang),
+            Err(Error::InvalidWordCount(100))
+        );
+        assert_eq!(
+            Mnemonic::validate_with(&repeated, lang, &options),
+            Err(Error::InvalidWordCount(100))
+        );
+        let mut entropy = [0; 32];
+        assert_eq!(
+            Mnemonic::to_entropy_into(&repeated, lang, &mut entropy),
+            Err(Error::InvalidWordCount(100))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn confirm_backup() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            mnemonic.confirm_backup(&format!("  {} ", phrase)),
+            BackupCheck::Match
+        );
+        assert_eq!(mnemonic.confirm_backup(""), BackupCheck::WordCountMismatch);
+        assert_eq!(
+            mnemonic.confirm_backup(&format!("{} ankle", phrase)),
+            BackupCheck::WordCountMismatch
+        );
+
+        let typo = phrase.replace("knee", "knew").replace("grid", "grit");
+        assert_eq!(mnemonic.confirm_backup(&typo), BackupCheck::Mismatch);
+        assert_eq!(
+            mnemonic.confirm_backup_with_position(&typo),
+            BackupCheck::MismatchAt(6)
+        );
+        assert_eq!(
+            mnemonic.confirm_backup_with_position(phrase),
+            BackupCheck::Match
+        );
+
+        let swapped = phrase.replace("park remain", "remain park");
+        assert_eq!(
+            mnemonic.confirm_backup_with_position(&swapped),
+            BackupCheck::MismatchAt(0)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn index_string() {
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let numbers = mnemonic.to_index_string();
+
+        assert_eq!(
+            &*numbers,
+            "2048 2048 2048 2048 2048 2048 2048 2048 2048 2048 2048 2038"
+        );
+        assert_eq!(
+            Mnemonic::from_index_string(&numbers, Language::English),
+            Ok(mnemonic)
+        );
+
+        let padded = "0001 0001 0001 0001 0001 0001\n0001 0001 0001 0001 0001 0004";
+        let mnemonic = Mnemonic::from_index_string(padded, Language::English).unwrap();
+        assert_eq!(mnemonic.entropy(), &[0; 16]);
 
-impl fmt::LowerHex for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            f.write_str("0x")?;
-        }
+        for (bad, word) in [
+            ("0", "0"),
+            ("2049", "2049"),
+            ("+4", "+4"),
+            ("99999", "99999"),
+        ] {
+            let numbers = format!("1 1 1 1 1 1 1 1 1 1 1 {}", bad);
 
-        for byte in self.entropy() {
-            write!(f, "{:02x}", byte)?;
+            assert_eq!(
+                Mnemonic::from_index_string(&numbers, Language::English).map(|_| ()),
+                Err(Error::InvalidWord {
+                    word: SecretWord::new(word, None),
+                    index: 11,
+                }),
+            );
         }
 
-        Ok(())
+        assert_eq!(
+            Mnemonic::from_index_string("1 1 1", Language::English).map(|_| ()),
+            Err(Error::InvalidWordCount(3)),
+        );
+        assert_eq!(
+            Mnemonic::from_index_string("1 1 1 1 1 1 1 1 1 1 1 1", Language::English).map(|_| ()),
+            Err(Error::InvalidChecksum),
+        );
     }
-}
 
-impl fmt::UpperHex for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            f.write_str("0x")?;
-        }
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn fingerprint_id() {
+        let mnemonic = Mnemonic::from_entropy(&[0xa5; 16], Language::English).unwrap();
+        let id = mnemonic.fingerprint_id(b"salt");
 
-        for byte in self.entropy() {
-            write!(f, "{:02X}", byte)?;
-        }
+        assert_eq!(id.len(), 16);
+        assert_eq!(id, mnemonic.clone().fingerprint_id(b"salt"));
+        assert_ne!(id, mnemonic.fingerprint_id(b"pepper"));
+        assert_ne!(id, mnemonic.fingerprint_id(b""));
 
-        Ok(())
+        let other = Mnemonic::from_entropy(&[0xa6; 16], Language::English).unwrap();
+        assert_ne!(id, other.fingerprint_id(b"salt"));
     }
-}
 
-impl From<Mnemonic> for String {
-    fn from(val: Mnemonic) -> String {
-        val.into_phrase()
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn from_words() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mut words: Vec<&str> = phrase.split(' ').collect();
+
+        let mnemonic = Mnemonic::from_words(&words, Language::English).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+
+        words[4] = "mule ";
+        match Mnemonic::from_words(&words, Language::English) {
+            Err(Error::InvalidWord { index, .. }) => assert_eq!(index, 4),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+
+        words[4] = "spell";
+        let result = Mnemonic::from_words(&words, Language::English);
+        assert_eq!(result.map(|_| ()), Err(Error::InvalidChecksum));
+
+        let result = Mnemonic::from_words(&words[..11], Language::English);
+        assert_eq!(result.map(|_| ()), Err(Error::InvalidWordCount(11)));
+
+        let long = "a".repeat(Mnemonic::MAX_PHRASE_LEN);
+        words[0] = &long;
+        let result = Mnemonic::from_words(&words, Language::English);
+        assert!(matches!(result, Err(Error::PhraseTooLong(_))));
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[cfg(target_arch = "wasm32")]
-    use wasm_bindgen_test::*;
+    #[cfg(feature = "japanese")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn from_words_japanese() {
+        let entropy = [0x5a; 16];
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::Japanese).unwrap();
+        let words: Vec<&str> = mnemonic.phrase().split('\u{3000}').collect();
+        assert_eq!(words.len(), 12);
+
+        let restored = Mnemonic::from_words(&words, Language::Japanese).unwrap();
+        assert_eq!(restored.entropy(), &entropy);
+        assert_eq!(restored.phrase(), mnemonic.phrase());
+    }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
-    fn back_to_back() {
-        let m1 = Mnemonic::new(MnemonicType::Words12, Language::English);
-        let m2 = Mnemonic::from_phrase(m1.phrase(), Language::English).unwrap();
-        let m3 = Mnemonic::from_entropy(m1.entropy(), Language::English).unwrap();
+    fn same_entropy() {
+        let mnemonic = Mnemonic::from_entropy(&[0x5A; 16], Language::English).unwrap();
 
-        assert_eq!(m1.entropy(), m2.entropy(), "Entropy must be the same");
-        assert_eq!(m1.entropy(), m3.entropy(), "Entropy must be the same");
-        assert_eq!(m1.phrase(), m2.phrase(), "Phrase must be the same");
-        assert_eq!(m1.phrase(), m3.phrase(), "Phrase must be the same");
+        let other = |entropy: &[u8]| Mnemonic::from_entropy(entropy, Language::English).unwrap();
+
+        assert!(mnemonic.same_entropy(&mnemonic.clone()));
+        assert!(!mnemonic.same_entropy(&other(&[0x5B; 16])));
+        assert!(!mnemonic.same_entropy(&other(&[0x5A; 32])));
+
+        #[cfg(feature = "spanish")]
+        {
+            let spanish = mnemonic.translate(Language::Spanish).unwrap();
+            assert!(mnemonic.same_entropy(&spanish));
+            assert_ne!(mnemonic, spanish);
+        }
     }
 
+    #[cfg(feature = "spanish")]
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
-    fn mnemonic_from_entropy() {
-        let entropy = &[
-            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
-            0x6A, 0x79,
-        ];
-        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    fn translate() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 
-        let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+        let spanish = mnemonic.translate(Language::Spanish).unwrap();
+        assert_eq!(spanish.language(), Language::Spanish);
+        assert_eq!(spanish.entropy(), mnemonic.entropy());
+        assert_eq!(spanish.to_indices(), mnemonic.to_indices());
+        assert!(Mnemonic::validate(spanish.phrase(), Language::Spanish).is_ok());
+        assert_ne!(spanish.to_seed(""), mnemonic.to_seed(""));
 
-        assert_eq!(phrase, mnemonic.phrase());
+        assert_eq!(
+            spanish.translate(Language::English).unwrap().phrase(),
+            phrase
+        );
     }
 
+    #[cfg(feature = "french")]
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
-    fn mnemonic_from_phrase() {
-        let entropy = &[
-            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
-            0x6A, 0x79,
-        ];
-        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    fn homographs() {
+        let french = |mnemonic: &Mnemonic| -> Vec<Homograph> {
+            let homographs = mnemonic.homographs();
+            homographs
+                .into_iter()
+                .filter(|h| h.language == Language::French)
+                .collect()
+        };
 
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert_eq!(
+            french(&mnemonic),
+            [Homograph {
+                index: 8,
+                word: "position",
+                language: Language::French,
+                other_index: 1524,
+            }]
+        );
 
-        assert_eq!(entropy, mnemonic.entropy());
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+        let homographs = french(&mnemonic);
+        assert_eq!(homographs.len(), 11);
+        assert!(homographs
+            .iter()
+            .all(|h| h.word == "abandon" && h.other_index == 1));
+
+        // The French phrase isn't checked against French
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::French).unwrap();
+        assert!(french(&mnemonic).is_empty());
     }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
-    fn mnemonic_format() {
-        let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English);
+    fn audit() {
+        let audit = |phrase| {
+            Mnemonic::from_phrase(phrase, Language::English)
+                .unwrap()
+                .audit()
+        };
 
-        assert_eq!(mnemonic.phrase(), format!("{}", mnemonic));
+        assert_eq!(
+            audit("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"),
+            [
+                AuditWarning::RepeatedWord("zoo"),
+                AuditWarning::ConstantEntropy(0xff)
+            ],
+        );
+        assert_eq!(
+            audit(
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above"
+            ),
+            [AuditWarning::ConstantEntropy(0x80)],
+        );
+        assert_eq!(
+            audit("park remain person kitchen mule spell knee armed position rail grid ankle"),
+            [],
+        );
+
+        for first in [0, 2047 - 10] {
+            let mut indices: Vec<u16> = (first..first + 11).collect();
+            if first > 0 {
+                indices.reverse();
+            }
+
+            let sequential = (0..2048)
+                .find_map(|last| {
+                    indices.push(last);
+                    let mnemonic = Mnemonic::from_indices(&indices, Language::English);
+                    indices.pop();
+                    mnemonic.ok()
+                })
+                .unwrap();
+
+            assert_eq!(sequential.audit(), [AuditWarning::SequentialWords]);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy_into() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let mut out = [0; 20];
+        assert_eq!(mnemonic.entropy_into(&mut out), Ok(16));
+        assert_eq!(&out[..16], mnemonic.entropy());
+        assert_eq!(out[16..], [0; 4]);
+
+        let mut out = [0; 20];
+        assert_eq!(
+            Mnemonic::to_entropy_into(phrase, Language::English, &mut out),
+            Ok(16)
+        );
+        assert_eq!(&out[..16], mnemonic.entropy());
+
+        let mut out = [0; 15];
+        assert_eq!(
+            mnemonic.entropy_into(&mut out),
+            Err(Error::BufferTooSmall(16))
+        );
+        assert_eq!(
+            Mnemonic::to_entropy_into(phrase, Language::English, &mut out),
+            Err(Error::BufferTooSmall(16))
+        );
+        assert_eq!(out, [0; 15]);
+
+        assert_eq!(
+            Mnemonic::to_entropy_into(&phrase.replace("crop", "cash"), Language::English, &mut out),
+            Err(Error::InvalidChecksum)
+        );
     }
 
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
@@ -400,4 +3275,303 @@ mod test {
             "0x03E46BB13A746EA41CDDE45C90846A79"
         );
     }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn from_entropy_hex_and_base64() {
+        let mnemonic = Mnemonic::from_entropy(&[0x5A; 32], Language::English).unwrap();
+
+        let hex = mnemonic.entropy_hex();
+        assert_eq!(
+            Mnemonic::from_entropy_hex(&hex, Language::English).unwrap(),
+            mnemonic
+        );
+        let upper = hex.to_uppercase();
+        assert_eq!(
+            Mnemonic::from_entropy_hex(&upper, Language::English).unwrap(),
+            mnemonic
+        );
+
+        let base64 = mnemonic.entropy_base64();
+        assert_eq!(
+            Mnemonic::from_entropy_base64(&base64, Language::English).unwrap(),
+            mnemonic
+        );
+
+        let invalid = |result: Result<Mnemonic, Error>| result.map(|_| ());
+        let prefixed = format!("0x{}", hex);
+        assert_eq!(
+            invalid(Mnemonic::from_entropy_hex(&prefixed, Language::English)),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            invalid(Mnemonic::from_entropy_hex(&hex[2..], Language::English)),
+            Err(Error::InvalidKeysize(248))
+        );
+        assert_eq!(
+            invalid(Mnemonic::from_entropy_base64("Wlpa", Language::English)),
+            Err(Error::InvalidKeysize(24))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let json = serde_json::to_string(&mnemonic).unwrap();
+
+        assert_eq!(
+            json,
+            format!(r#"{{"phrase":"{}","language":"en"}}"#, phrase)
+        );
+        assert_eq!(serde_json::from_str::<Mnemonic>(&json).unwrap(), mnemonic);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "serde")]
+    fn serde_revalidates() {
+        let json = r#"{"phrase":"park remain person kitchen mule spell knee armed position rail grid abandon","language":"en"}"#;
+
+        let err = serde_json::from_str::<Mnemonic>(json).unwrap_err();
+        assert_eq!(err.to_string(), "invalid checksum");
+
+        assert!(serde_json::from_str::<Mnemonic>(r#"{"phrase":"","language":"xx"}"#).is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn display_and_from_str() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic: Mnemonic = phrase.parse().unwrap();
+
+        assert_eq!(mnemonic.to_string(), phrase);
+        assert_eq!(mnemonic.to_string().parse::<Mnemonic>().unwrap(), mnemonic);
+        assert_eq!(
+            "park remain person kitchen mule spell knee armed position rail grid abandon"
+                .parse::<Mnemonic>()
+                .map(|_| ()),
+            Err(Error::InvalidChecksum)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn indices() {
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+
+        assert_eq!(
+            &mnemonic.to_indices()[..],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]
+        );
+
+        let entropy: Vec<u8> = (0..32).map(|i| i * 7).collect();
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+        let indices = mnemonic.to_indices();
+        let words: Vec<_> = mnemonic.phrase().split(' ').collect();
+
+        for (&index, word) in indices.iter().zip(&words) {
+            assert_eq!(Language::English.wordlist().get_word(index.into()), *word);
+        }
+
+        assert_eq!(
+            Mnemonic::from_indices(&indices, Language::English).unwrap(),
+            mnemonic
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_indices() {
+        assert_eq!(
+            Mnemonic::from_indices(&[0; 12], Language::English).map(|_| ()),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            Mnemonic::from_indices(&[0; 11], Language::English).map(|_| ()),
+            Err(Error::InvalidWordCount(11))
+        );
+        assert_eq!(
+            Mnemonic::from_indices(&[0, 2048], Language::English).map(|_| ()),
+            Err(Error::InvalidWord {
+                word: SecretWord::default(),
+                index: 1,
+            })
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_xor() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let parts = mnemonic.split_xor_with_rng(&mut ZeroRng, 2).unwrap();
+        assert_eq!(
+            parts[0],
+            Mnemonic::from_entropy(&[0; 16], Language::English).unwrap()
+        );
+        assert_eq!(parts[1], mnemonic);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut parts = mnemonic.split_xor_with_rng(&mut rng, 4).unwrap();
+        assert_eq!(parts.len(), 4);
+        assert!(parts
+            .iter()
+            .all(|part| part.word_count() == 12 && *part != mnemonic));
+
+        parts.reverse();
+        assert_eq!(Mnemonic::combine_xor(&parts).unwrap(), mnemonic);
+        assert_ne!(Mnemonic::combine_xor(&parts[1..]).unwrap(), mnemonic);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_seed_xor() {
+        let short = Mnemonic::from_entropy(&[1; 16], Language::English).unwrap();
+        let long = Mnemonic::from_entropy(&[1; 32], Language::English).unwrap();
+
+        assert_eq!(
+            short.split_xor_with_rng(&mut ZeroRng, 1).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            Mnemonic::combine_xor(&[]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            Mnemonic::combine_xor(&[short, long]).map(|_| ()),
+            Err(Error::MismatchedShares)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn words() {
+        for mtype in [
+            MnemonicType::Words12,
+            MnemonicType::Words18,
+            MnemonicType::Words24,
+        ] {
+            let entropy = vec![0xa5; mtype.entropy_len()];
+            let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+            assert_eq!(mnemonic.word_count(), mtype.word_count());
+            assert_eq!(mnemonic.words().count(), mtype.word_count());
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn word_groups() {
+        let mnemonic = Mnemonic::from_entropy(&[0xa5; 32], Language::English).unwrap();
+        let words: Vec<&str> = mnemonic.words().collect();
+
+        for size in [1, 3, 4, 5, 24, 30] {
+            let groups: Vec<Vec<&str>> = mnemonic.word_groups(size).collect();
+
+            assert_eq!(groups.len(), 24usize.div_ceil(size));
+            assert!(groups.iter().all(|group| group.len() <= size));
+            assert_eq!(groups.concat(), words);
+        }
+
+        assert_eq!(mnemonic.word_groups(0).count(), 24);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "japanese")]
+    fn words_japanese() {
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::Japanese).unwrap();
+
+        assert_eq!(mnemonic.words().count(), 12);
+        assert!(mnemonic.words().all(|word| !word.contains('\u{3000}')));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn substrate_mini_secret() {
+        // Test vectors from the `substrate-bip39` crate
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+
+        let seed = "44e9d125f037ac1d51f0a7d3649689d422c2af8b1ec8e00d71db4d7bf6d127e3";
+        assert_eq!(
+            hex::encode(&mnemonic.to_substrate_mini_secret("Substrate")[..]),
+            seed
+        );
+
+        // Only the entropy matters, not the language of the phrase
+        #[cfg(feature = "french")]
+        {
+            let french = Mnemonic::from_entropy(&[0; 16], Language::French).unwrap();
+            assert_eq!(
+                french.to_substrate_mini_secret("Substrate"),
+                mnemonic.to_substrate_mini_secret("Substrate")
+            );
+        }
+
+        // The passphrase is used as is, so composed and decomposed accents differ
+        let params = SeedDerivationParams::bip39();
+        let expected = pbkdf2(&DefaultCrypto, &[0; 16], "mnemonicCafé", &params);
+        assert_eq!(
+            mnemonic.to_substrate_mini_secret("Café")[..],
+            expected[..32]
+        );
+        assert_ne!(
+            mnemonic.to_substrate_mini_secret("Café"),
+            mnemonic.to_substrate_mini_secret("Cafe\u{301}")
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "cardano")]
+    fn icarus_master_key() {
+        // Test vectors from CIP-3
+        let phrase = "eight country switch draw meat scout mystery blade tip drift useless good \
+                      keep usage title";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            hex::encode(&mnemonic.to_icarus_master_key("")[..]),
+            "c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245\
+             d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a\
+             23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620"
+        );
+        assert_eq!(
+            hex::encode(&mnemonic.to_icarus_master_key("foo")[..]),
+            "70531039904019351e1afb361cd1b312a4d0565d4ff9f8062d38acf4b15cce41\
+             d7b5738d9c893feea55512a3004acb0d222c35d3e3d5cde943a15a9824cbac59\
+             443cf67e589614076ba01e354b1a432e0e6db3b59e37fc56b5fb0222970a010e"
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn try_from() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::try_from(phrase).unwrap();
+
+        assert_eq!(AsRef::<str>::as_ref(&mnemonic), phrase);
+        assert_eq!(Mnemonic::try_from(mnemonic.entropy()).unwrap(), mnemonic);
+        assert_eq!(
+            Mnemonic::try_from(&[0u8; 15][..]).map(|_| ()),
+            Err(Error::InvalidKeysize(120))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "french")]
+    fn from_str_detects_language() {
+        let mnemonic = Mnemonic::from_entropy(&[0x2a; 16], Language::French).unwrap();
+
+        let parsed: Mnemonic = mnemonic.phrase().parse().unwrap();
+
+        assert_eq!(parsed.language(), Language::French);
+        assert_eq!(parsed.entropy(), mnemonic.entropy());
+    }
 }
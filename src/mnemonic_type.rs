@@ -1,9 +1,19 @@
-use std::fmt;
-use anyhow::Error;
-use crate::error::ErrorKind;
+use crate::error::Error;
+use crate::util::is_word_separator;
+use core::convert::TryFrom;
+use core::fmt;
 
 const ENTROPY_OFFSET: usize = 8;
 
+/// Every `MnemonicType`, from the shortest phrase to the longest
+const ALL: [MnemonicType; 5] = [
+    MnemonicType::Words12,
+    MnemonicType::Words15,
+    MnemonicType::Words18,
+    MnemonicType::Words21,
+    MnemonicType::Words24,
+];
+
 /// Determines the number of words that will be present in a [`Mnemonic`][Mnemonic] phrase
 ///
 /// Also directly affects the amount of entropy that will be used to create a [`Mnemonic`][Mnemonic],
@@ -29,9 +39,10 @@ const ENTROPY_OFFSET: usize = 8;
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
 ///
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum MnemonicType {
     //  ... = (entropy_bits << ...)   | checksum_bits
+    #[default]
     Words12 = (128 << ENTROPY_OFFSET) | 4,
     Words15 = (160 << ENTROPY_OFFSET) | 5,
     Words18 = (192 << ENTROPY_OFFSET) | 6,
@@ -40,10 +51,32 @@ pub enum MnemonicType {
 }
 
 impl MnemonicType {
+    /// Iterate over every `MnemonicType`, from 12 to 24 words, for instance to offer a choice of
+    /// phrase lengths without hard-coding them
+    ///
+    /// The accessors such as [`word_count()`][word_count] and [`entropy_bits()`][entropy_bits]
+    /// are `const`, so tables can also be built at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::MnemonicType;
+    ///
+    /// let lengths: Vec<usize> = MnemonicType::all().map(|mtype| mtype.word_count()).collect();
+    ///
+    /// assert_eq!(lengths, [12, 15, 18, 21, 24]);
+    /// ```
+    ///
+    /// [word_count]: #method.word_count
+    /// [entropy_bits]: #method.entropy_bits
+    pub fn all() -> impl ExactSizeIterator<Item = MnemonicType> + Clone {
+        ALL.iter().copied()
+    }
+
     /// Get a `MnemonicType` for a mnemonic phrase with a specific number of words
     ///
-    /// Specifying a word count not provided for by the BIP39 standard will return an `Error`
-    /// of kind `ErrorKind::InvalidWordLength`.
+    /// Specifying a word count not provided for by the BIP39 standard will return
+    /// `Error::InvalidWordCount`.
     ///
     /// # Example
     /// ```
@@ -58,7 +91,7 @@ impl MnemonicType {
             18 => MnemonicType::Words18,
             21 => MnemonicType::Words21,
             24 => MnemonicType::Words24,
-            _ => Err(ErrorKind::InvalidWordLength(size))?,
+            _ => return Err(Error::InvalidWordCount(size)),
         };
 
         Ok(mnemonic_type)
@@ -66,8 +99,8 @@ impl MnemonicType {
 
     /// Get a `MnemonicType` for a mnemonic phrase representing the given key size as bits
     ///
-    /// Specifying a key size not provided for by the BIP39 standard will return an `Error`
-    /// of kind `ErrorKind::InvalidKeysize`.
+    /// Specifying a key size not provided for by the BIP39 standard will return
+    /// `Error::InvalidKeysize`.
     ///
     /// # Example
     /// ```
@@ -82,19 +115,36 @@ impl MnemonicType {
             192 => MnemonicType::Words18,
             224 => MnemonicType::Words21,
             256 => MnemonicType::Words24,
-            _ => Err(ErrorKind::InvalidKeysize(size))?,
+            _ => return Err(Error::InvalidKeysize(size)),
         };
 
         Ok(mnemonic_type)
     }
 
+    /// Get a `MnemonicType` for entropy of a given length in bytes
+    ///
+    /// Specifying a length other than 16, 20, 24, 28 or 32 bytes will return
+    /// `Error::InvalidKeysize`, with the length in bits.
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let mnemonic_type = MnemonicType::for_entropy_len(32).unwrap();
+    ///
+    /// assert_eq!(mnemonic_type, MnemonicType::Words24);
+    /// ```
+    pub fn for_entropy_len(len: usize) -> Result<MnemonicType, Error> {
+        Self::for_key_size(len.saturating_mul(8))
+    }
+
     /// Get a `MnemonicType` for an existing mnemonic phrase
     ///
     /// This can be used when you need information about a mnemonic phrase based on the number of
     /// words, for example you can get the entropy value using [`MnemonicType::entropy_bits`][MnemonicType::entropy_bits()].
     ///
     /// Specifying a phrase that does not match one of the standard BIP39 phrase lengths will return
-    /// an `Error` of kind `ErrorKind::InvalidWordLength`. The phrase will not be validated in any
+    /// `Error::InvalidWordCount`. The phrase will not be validated in any
     /// other way.
     ///
     /// # Example
@@ -110,7 +160,7 @@ impl MnemonicType {
     ///
     /// [MnemonicType::entropy_bits()]: ./enum.MnemonicType.html#method.entropy_bits
     pub fn for_phrase(phrase: &str) -> Result<MnemonicType, Error> {
-        let word_count = phrase.split(" ").count();
+        let word_count = phrase.split(is_word_separator).count();
 
         Self::for_word_count(word_count)
     }
@@ -128,7 +178,7 @@ impl MnemonicType {
     ///
     /// let total_bits = mnemonic_type.total_bits();
     /// ```
-    pub fn total_bits(&self) -> usize {
+    pub const fn total_bits(&self) -> usize {
         self.entropy_bits() + self.checksum_bits() as usize
     }
 
@@ -145,10 +195,25 @@ impl MnemonicType {
     ///
     /// let entropy_bits = mnemonic_type.entropy_bits();
     /// ```
-    pub fn entropy_bits(&self) -> usize {
+    pub const fn entropy_bits(&self) -> usize {
         (*self as usize) >> ENTROPY_OFFSET
     }
 
+    /// Return the number of entropy bytes
+    ///
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let mnemonic_type = MnemonicType::Words12;
+    ///
+    /// assert_eq!(mnemonic_type.entropy_len(), 16);
+    /// ```
+    pub const fn entropy_len(&self) -> usize {
+        self.entropy_bits() / 8
+    }
+
     /// Return the number of checksum bits
     ///
     ///
@@ -162,7 +227,7 @@ impl MnemonicType {
     ///
     /// let checksum_bits = mnemonic_type.checksum_bits();
     /// ```
-    pub fn checksum_bits(&self) -> u8 {
+    pub const fn checksum_bits(&self) -> u8 {
         (*self as usize) as u8
     }
 
@@ -177,17 +242,11 @@ impl MnemonicType {
     ///
     /// let word_count = mnemonic_type.word_count();
     /// ```
-    pub fn word_count(&self) -> usize {
+    pub const fn word_count(&self) -> usize {
         self.total_bits() / 11
     }
 }
 
-impl Default for MnemonicType {
-    fn default() -> MnemonicType {
-        MnemonicType::Words12
-    }
-}
-
 impl fmt::Display for MnemonicType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -199,12 +258,170 @@ impl fmt::Display for MnemonicType {
     }
 }
 
+/// Serialized as `"words"` followed by the number of words, as in `"words12"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for MnemonicType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("words{}", self.word_count()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MnemonicType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <alloc::string::String as serde::Deserialize>::deserialize(deserializer)?;
+
+        name.strip_prefix("words")
+            .and_then(|count| count.parse().ok())
+            .and_then(|count| MnemonicType::for_word_count(count).ok())
+            .ok_or_else(|| {
+                serde::de::Error::custom(alloc::format!("unknown mnemonic type {:?}", name))
+            })
+    }
+}
+
+/// The number of words of a standard BIP39 phrase
+///
+/// Unlike a `usize`, it can only be one of the five standard lengths, so a typo can't make a
+/// constructor taking it fail at runtime. Converts to and from the equivalent
+/// [`MnemonicType`][MnemonicType], and from a `usize` with `WordCount::try_from()`, which returns
+/// `Error::InvalidWordCount` for any other number.
+///
+/// ```
+/// use bip39::{MnemonicType, WordCount};
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(WordCount::try_from(24), Ok(WordCount::Words24));
+/// assert_eq!(MnemonicType::from(WordCount::Words24), MnemonicType::Words24);
+/// assert!(WordCount::try_from(13).is_err());
+/// ```
+///
+/// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum WordCount {
+    #[default]
+    Words12 = 12,
+    Words15 = 15,
+    Words18 = 18,
+    Words21 = 21,
+    Words24 = 24,
+}
+
+impl WordCount {
+    /// Return the number of words
+    pub const fn word_count(&self) -> usize {
+        *self as usize
+    }
+
+    /// Return the equivalent `MnemonicType`
+    pub const fn mnemonic_type(&self) -> MnemonicType {
+        match self {
+            WordCount::Words12 => MnemonicType::Words12,
+            WordCount::Words15 => MnemonicType::Words15,
+            WordCount::Words18 => MnemonicType::Words18,
+            WordCount::Words21 => MnemonicType::Words21,
+            WordCount::Words24 => MnemonicType::Words24,
+        }
+    }
+}
+
+impl TryFrom<usize> for WordCount {
+    type Error = Error;
+
+    fn try_from(count: usize) -> Result<WordCount, Error> {
+        MnemonicType::for_word_count(count).map(WordCount::from)
+    }
+}
+
+impl From<MnemonicType> for WordCount {
+    fn from(mtype: MnemonicType) -> WordCount {
+        match mtype {
+            MnemonicType::Words12 => WordCount::Words12,
+            MnemonicType::Words15 => WordCount::Words15,
+            MnemonicType::Words18 => WordCount::Words18,
+            MnemonicType::Words21 => WordCount::Words21,
+            MnemonicType::Words24 => WordCount::Words24,
+        }
+    }
+}
+
+impl From<WordCount> for MnemonicType {
+    fn from(count: WordCount) -> MnemonicType {
+        count.mnemonic_type()
+    }
+}
+
+impl From<WordCount> for usize {
+    fn from(count: WordCount) -> usize {
+        count.word_count()
+    }
+}
+
+impl fmt::Display for WordCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} words", self.word_count())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MnemonicType {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ])
+        .copied()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
 
+    #[cfg(feature = "serde")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn serde_roundtrip() {
+        assert_eq!(
+            serde_json::to_string(&MnemonicType::Words12).unwrap(),
+            r#""words12""#
+        );
+
+        for mtype in [
+            MnemonicType::Words12,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ] {
+            let json = serde_json::to_string(&mtype).unwrap();
+            assert_eq!(serde_json::from_str::<MnemonicType>(&json).unwrap(), mtype);
+        }
+
+        for json in [r#""words13""#, r#""12""#, r#""words""#, "12"] {
+            assert!(serde_json::from_str::<MnemonicType>(json).is_err());
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn all() {
+        const BITS: [usize; 2] = [
+            MnemonicType::Words12.total_bits(),
+            MnemonicType::Words24.entropy_bits() + MnemonicType::Words24.checksum_bits() as usize,
+        ];
+        assert_eq!(BITS, [132, 264]);
+
+        assert_eq!(MnemonicType::all().len(), 5);
+        assert_eq!(MnemonicType::all().next(), Some(MnemonicType::default()));
+        for mtype in MnemonicType::all() {
+            assert_eq!(MnemonicType::for_word_count(mtype.word_count()), Ok(mtype));
+        }
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn word_count() {
@@ -234,4 +451,42 @@ mod test {
         assert_eq!(MnemonicType::Words21.checksum_bits(), 7);
         assert_eq!(MnemonicType::Words24.checksum_bits(), 8);
     }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn entropy_len() {
+        for len in [16, 20, 24, 28, 32] {
+            let mtype = MnemonicType::for_entropy_len(len).unwrap();
+
+            assert_eq!(mtype.entropy_len(), len);
+            assert_eq!(MnemonicType::for_key_size(mtype.entropy_bits()), Ok(mtype));
+            assert_eq!(MnemonicType::for_word_count(mtype.word_count()), Ok(mtype));
+        }
+
+        assert_eq!(
+            MnemonicType::for_entropy_len(17),
+            Err(Error::InvalidKeysize(136))
+        );
+        let too_long = MnemonicType::for_entropy_len(usize::MAX);
+        assert_eq!(too_long, Err(Error::InvalidKeysize(usize::MAX)));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn word_count_conversions() {
+        for count in [12, 15, 18, 21, 24] {
+            let word_count = WordCount::try_from(count).unwrap();
+            let mtype = MnemonicType::from(word_count);
+
+            assert_eq!(usize::from(word_count), count);
+            assert_eq!(mtype.word_count(), count);
+            assert_eq!(WordCount::from(mtype), word_count);
+        }
+
+        assert_eq!(WordCount::try_from(13), Err(Error::InvalidWordCount(13)));
+        assert_eq!(
+            WordCount::default().mnemonic_type(),
+            MnemonicType::default()
+        );
+    }
 }
@@ -0,0 +1,215 @@
+//! UniFFI bindings for Kotlin and Swift, enabled with the `uniffi` feature
+//!
+//! These are thin wrappers exposing a [`Mnemonic`][Mnemonic] object, a [`Language`][Language]
+//! enum and seed derivation. Generate the foreign bindings from the compiled library with
+//! `uniffi-bindgen generate --library`, the crate needs to be built as a `cdylib` (or `staticlib`
+//! for iOS) for that.
+//!
+//! [Mnemonic]: ./struct.Mnemonic.html
+//! [Language]: ./enum.Language.html
+
+use crate::error::Error;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use std::sync::Arc;
+
+/// The built-in languages, all of which exist in the bindings, whether enabled or not
+///
+/// Using a language that isn't enabled at compile time fails with
+/// `Bip39Error::UnsupportedLanguage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum Language {
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    Spanish,
+}
+
+impl Language {
+    fn to_language(self) -> Result<crate::Language, Bip39Error> {
+        let lang = match self {
+            Language::English => crate::Language::English,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => crate::Language::ChineseSimplified,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => crate::Language::ChineseTraditional,
+            #[cfg(feature = "czech")]
+            Language::Czech => crate::Language::Czech,
+            #[cfg(feature = "french")]
+            Language::French => crate::Language::French,
+            #[cfg(feature = "italian")]
+            Language::Italian => crate::Language::Italian,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => crate::Language::Japanese,
+            #[cfg(feature = "korean")]
+            Language::Korean => crate::Language::Korean,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => crate::Language::Portuguese,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => crate::Language::Spanish,
+            #[allow(unreachable_patterns)]
+            other => return Err(Bip39Error::UnsupportedLanguage(other)),
+        };
+
+        Ok(lang)
+    }
+}
+
+/// The errors thrown by the bindings, as exceptions carrying the message of the [`Error`][Error]
+///
+/// [Error]: ../error/enum.Error.html
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum Bip39Error {
+    #[error(transparent)]
+    InvalidWord(Error),
+    #[error(transparent)]
+    InvalidWordCount(Error),
+    #[error(transparent)]
+    InvalidChecksum(Error),
+    #[error(transparent)]
+    InvalidEntropy(Error),
+    #[error(transparent)]
+    RandomSource(Error),
+    #[error("language {0:?} isn't enabled")]
+    UnsupportedLanguage(Language),
+    #[error(transparent)]
+    Other(Error),
+}
+
+impl From<Error> for Bip39Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::InvalidWord { .. } => Bip39Error::InvalidWord(err),
+            Error::InvalidWordCount(_) => Bip39Error::InvalidWordCount(err),
+            Error::InvalidChecksum => Bip39Error::InvalidChecksum(err),
+            Error::InvalidKeysize(_) | Error::InvalidEntropyLength { .. } => {
+                Bip39Error::InvalidEntropy(err)
+            }
+            Error::RandomSource(_) => Bip39Error::RandomSource(err),
+            _ => Bip39Error::Other(err),
+        }
+    }
+}
+
+/// A mnemonic phrase, wrapping the Rust [`Mnemonic`][Mnemonic]
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+#[derive(uniffi::Object)]
+pub struct Mnemonic {
+    inner: crate::Mnemonic,
+    lang: Language,
+}
+
+#[uniffi::export]
+impl Mnemonic {
+    /// Generate a new mnemonic with `words` words, using the operating system CSPRNG
+    #[uniffi::constructor]
+    pub fn generate(words: u8, lang: Language) -> Result<Arc<Self>, Bip39Error> {
+        let mtype = MnemonicType::for_word_count(words as usize)?;
+        let inner = crate::Mnemonic::try_new(mtype, lang.to_language()?)?;
+
+        Ok(Arc::new(Mnemonic { inner, lang }))
+    }
+
+    /// Create a mnemonic from an existing phrase, checksum included
+    #[uniffi::constructor]
+    pub fn from_phrase(phrase: String, lang: Language) -> Result<Arc<Self>, Bip39Error> {
+        let inner = crate::Mnemonic::from_phrase(&phrase, lang.to_language()?)?;
+
+        Ok(Arc::new(Mnemonic { inner, lang }))
+    }
+
+    /// Create a mnemonic from 16, 20, 24, 28 or 32 bytes of entropy
+    #[uniffi::constructor]
+    pub fn from_entropy(entropy: Vec<u8>, lang: Language) -> Result<Arc<Self>, Bip39Error> {
+        let inner = crate::Mnemonic::from_entropy(&entropy, lang.to_language()?)?;
+
+        Ok(Arc::new(Mnemonic { inner, lang }))
+    }
+
+    pub fn phrase(&self) -> String {
+        self.inner.phrase().into()
+    }
+
+    pub fn entropy(&self) -> Vec<u8> {
+        self.inner.entropy().to_vec()
+    }
+
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+
+    /// Derive the 64 byte BIP39 seed, using `passphrase` as the BIP39 password
+    pub fn to_seed(&self, passphrase: String) -> Vec<u8> {
+        Seed::new(&self.inner, &passphrase).as_bytes().to_vec()
+    }
+}
+
+/// Check whether `phrase` is a valid mnemonic phrase, checksum included
+#[uniffi::export]
+pub fn validate_mnemonic(phrase: String, lang: Language) -> Result<(), Bip39Error> {
+    crate::Mnemonic::validate(&phrase, lang.to_language()?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate() {
+        let mnemonic = Mnemonic::generate(24, Language::English).unwrap();
+
+        assert_eq!(mnemonic.phrase().split(' ').count(), 24);
+        assert_eq!(mnemonic.language(), Language::English);
+        assert!(validate_mnemonic(mnemonic.phrase(), Language::English).is_ok());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn to_seed() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE.into(), Language::English).unwrap();
+        let expected = crate::Mnemonic::from_phrase(PHRASE, crate::Language::English).unwrap();
+
+        assert_eq!(mnemonic.entropy(), expected.entropy());
+        assert_eq!(
+            mnemonic.to_seed("password".into()),
+            Seed::new(&expected, "password").as_bytes()
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn errors() {
+        assert!(matches!(
+            Mnemonic::generate(13, Language::English),
+            Err(Bip39Error::InvalidWordCount(_))
+        ));
+        assert!(matches!(
+            Mnemonic::from_entropy(vec![0; 17], Language::English),
+            Err(Bip39Error::InvalidEntropy(_))
+        ));
+        assert!(matches!(
+            validate_mnemonic(
+                "park remain person kitchen mule spell knee armed position rail grid abandon"
+                    .into(),
+                Language::English
+            ),
+            Err(Bip39Error::InvalidChecksum(_))
+        ));
+    }
+}
@@ -0,0 +1,444 @@
+//! Monero seed phrases, enabled with the `monero` feature
+//!
+//! Monero's phrases have nothing in common with BIP39 besides using words. They encode a 32 byte
+//! spend key directly, 4 bytes for every 3 words out of a 1626 word list, followed by a 25th
+//! checksum word. The checksum word is one of the other 24, picked with the CRC32 of their
+//! unique prefixes, which is also all Monero looks at when reading a phrase back.
+//!
+//! ```
+//! use bip39::monero::{self, Language};
+//!
+//! let phrase = "washing thirsty occur lectures tuesday fainted toxic adapt abnormal memoir nylon \
+//!               mostly building shrugged online ember northern ruby woes dauntless boil family \
+//!               illness inroads northern";
+//!
+//! let spend_key = monero::to_spend_key(phrase, Language::English).unwrap();
+//!
+//! assert_eq!(*monero::from_spend_key(&spend_key, Language::English).unwrap(), phrase);
+//! ```
+//!
+//! The word lists are the ones from Monero's `src/mnemonics`, except for the checksum-less "Old
+//! English" list, which Monero itself only keeps for reading old wallets.
+
+#[cfg(feature = "rand")]
+use crate::crypto::gen_os_random_bytes;
+use crate::crypto::gen_random_bytes;
+use crate::error::{Error, SecretWord};
+use crate::util::{crc32, IterExt};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+/// The number of words encoding the spend key, without the checksum word
+const KEY_WORDS: usize = 24;
+
+/// The reflected polynomial of the CRC-32 used by zlib and Monero
+const CRC32_IEEE: u32 = 0xedb8_8320;
+
+/// The order of the Ed25519 group, little endian, which spend keys must be lower than
+const GROUP_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// The languages of Monero's word lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    ChineseSimplified,
+    Dutch,
+    English,
+    Esperanto,
+    French,
+    German,
+    Italian,
+    Japanese,
+    Lojban,
+    Portuguese,
+    Russian,
+    Spanish,
+}
+
+impl Language {
+    const ALL: [Language; 12] = [
+        Language::ChineseSimplified,
+        Language::Dutch,
+        Language::English,
+        Language::Esperanto,
+        Language::French,
+        Language::German,
+        Language::Italian,
+        Language::Japanese,
+        Language::Lojban,
+        Language::Portuguese,
+        Language::Russian,
+        Language::Spanish,
+    ];
+
+    /// Get the first language in which every word of `phrase` is in the word list
+    ///
+    /// Returns `Error::UnknownLanguage` if there is none.
+    pub fn detect(phrase: &str) -> Result<Language, Error> {
+        Language::ALL
+            .iter()
+            .copied()
+            .find(|&lang| to_spend_key(phrase, lang).is_ok())
+            .ok_or(Error::UnknownLanguage)
+    }
+
+    /// The number of leading characters that tell the words of the list apart
+    pub fn prefix_len(&self) -> usize {
+        match *self {
+            Language::ChineseSimplified => 1,
+            Language::English | Language::Japanese => 3,
+            _ => 4,
+        }
+    }
+
+    fn wordlist(&self) -> &'static [&'static str] {
+        match *self {
+            Language::ChineseSimplified => &lazy::WORDLIST_CHINESE_SIMPLIFIED,
+            Language::Dutch => &lazy::WORDLIST_DUTCH,
+            Language::English => &lazy::WORDLIST_ENGLISH,
+            Language::Esperanto => &lazy::WORDLIST_ESPERANTO,
+            Language::French => &lazy::WORDLIST_FRENCH,
+            Language::German => &lazy::WORDLIST_GERMAN,
+            Language::Italian => &lazy::WORDLIST_ITALIAN,
+            Language::Japanese => &lazy::WORDLIST_JAPANESE,
+            Language::Lojban => &lazy::WORDLIST_LOJBAN,
+            Language::Portuguese => &lazy::WORDLIST_PORTUGUESE,
+            Language::Russian => &lazy::WORDLIST_RUSSIAN,
+            Language::Spanish => &lazy::WORDLIST_SPANISH,
+        }
+    }
+
+    fn prefix<'w>(&self, word: &'w str) -> &'w str {
+        match word.char_indices().nth(self.prefix_len()) {
+            Some((end, _)) => &word[..end],
+            None => word,
+        }
+    }
+
+    /// The index of `word`, by its prefix if `by_prefix` is set
+    fn index_of(&self, word: &str, by_prefix: bool) -> Option<usize> {
+        let wordlist = self.wordlist();
+
+        if by_prefix {
+            let prefix = self.prefix(word);
+
+            wordlist.iter().position(|w| self.prefix(w) == prefix)
+        } else {
+            wordlist.iter().position(|w| *w == word)
+        }
+    }
+}
+
+/// Convert a 32 byte spend key into a 25 word phrase
+///
+/// Returns `Error::InvalidSpendKey` if `spend_key` isn't a reduced Ed25519 scalar, which Monero
+/// wallets wouldn't accept.
+pub fn from_spend_key(spend_key: &[u8; 32], lang: Language) -> Result<Zeroizing<String>, Error> {
+    if !is_reduced(spend_key) {
+        return Err(Error::InvalidSpendKey);
+    }
+
+    let wordlist = lang.wordlist();
+    let n = wordlist.len() as u32;
+
+    let mut indices = Zeroizing::new(Vec::with_capacity(KEY_WORDS + 1));
+
+    for chunk in spend_key.chunks_exact(4) {
+        let x = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+        let w1 = x % n;
+        let w2 = (x / n + w1) % n;
+        let w3 = (x / n / n + w2) % n;
+
+        indices.extend_from_slice(&[w1 as usize, w2 as usize, w3 as usize]);
+    }
+
+    let checksum = indices[checksum_index(&indices, lang)];
+    indices.push(checksum);
+
+    Ok(Zeroizing::new(
+        indices.iter().map(|&index| wordlist[index]).join(" "),
+    ))
+}
+
+/// Convert a Monero phrase back into its 32 byte spend key
+///
+/// The phrase has 25 words, of which only the unique prefixes matter, or 24 full words without
+/// the checksum word.
+pub fn to_spend_key(phrase: &str, lang: Language) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let phrase: Zeroizing<String> = Zeroizing::new(phrase.nfc().collect());
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+
+    let has_checksum = match words.len() {
+        KEY_WORDS => false,
+        len if len == KEY_WORDS + 1 => true,
+        len => return Err(Error::InvalidWordCount(len)),
+    };
+
+    let mut indices = Zeroizing::new(vec![0usize; words.len()]);
+
+    for (index, (word, slot)) in words.iter().zip(indices.iter_mut()).enumerate() {
+        *slot = lang
+            .index_of(word, has_checksum)
+            .ok_or_else(|| Error::InvalidWord {
+                word: SecretWord::new(word, None),
+                index,
+            })?;
+    }
+
+    if has_checksum {
+        let expected = indices[checksum_index(&indices[..KEY_WORDS], lang)];
+
+        if indices[KEY_WORDS] != expected {
+            return Err(Error::InvalidChecksum);
+        }
+    }
+
+    let n = lang.wordlist().len() as u64;
+    let mut spend_key = Zeroizing::new([0u8; 32]);
+
+    for (chunk, out) in indices[..KEY_WORDS]
+        .chunks_exact(3)
+        .zip(spend_key.chunks_exact_mut(4))
+    {
+        let (w1, w2, w3) = (chunk[0] as u64, chunk[1] as u64, chunk[2] as u64);
+
+        let x = w1 + n * ((n - w1 + w2) % n) + n * n * ((n - w2 + w3) % n);
+
+        // Not every triple of words is the encoding of 4 bytes
+        if x % n != w1 || x > u32::MAX as u64 {
+            return Err(Error::InvalidSpendKey);
+        }
+
+        out.copy_from_slice(&(x as u32).to_le_bytes());
+    }
+
+    if !is_reduced(&spend_key) {
+        return Err(Error::InvalidSpendKey);
+    }
+
+    Ok(spend_key)
+}
+
+/// Generate a new 25 word phrase in `lang`, using the operating system CSPRNG
+#[cfg(feature = "rand")]
+pub fn generate(lang: Language) -> Result<Zeroizing<String>, Error> {
+    let bytes = gen_os_random_bytes(32)?;
+
+    generate_from(&bytes, lang)
+}
+
+/// Generate a new 25 word phrase in `lang`, using entropy from `rng`
+pub fn generate_with_rng<R>(rng: &mut R, lang: Language) -> Result<Zeroizing<String>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let bytes = gen_random_bytes(rng, 32)?;
+
+    generate_from(&bytes, lang)
+}
+
+/// Clearing the top 4 bits puts the key below the group order, at the cost of a tiny bias
+fn generate_from(bytes: &[u8], lang: Language) -> Result<Zeroizing<String>, Error> {
+    let mut spend_key = Zeroizing::new([0u8; 32]);
+
+    spend_key.copy_from_slice(bytes);
+    spend_key[31] &= 0x0f;
+
+    from_spend_key(&spend_key, lang)
+}
+
+/// Whether the little endian `key` is lower than the group order
+fn is_reduced(key: &[u8; 32]) -> bool {
+    key.iter().rev().cmp(GROUP_ORDER.iter().rev()).is_lt()
+}
+
+/// The index of the word that is repeated as the checksum word
+fn checksum_index(indices: &[usize], lang: Language) -> usize {
+    let wordlist = lang.wordlist();
+
+    let mut prefixes = Zeroizing::new(String::new());
+    for &index in indices {
+        prefixes.push_str(lang.prefix(wordlist[index]));
+    }
+
+    crc32(CRC32_IEEE, prefixes.as_bytes()) as usize % indices.len()
+}
+
+mod lazy {
+    use crate::util::Lazy;
+    use alloc::vec::Vec;
+
+    fn gen_wordlist(lang_words: &'static str) -> Vec<&'static str> {
+        let inner: Vec<_> = lang_words.lines().collect();
+
+        debug_assert!(inner.len() == 1626, "Invalid wordlist length");
+
+        inner
+    }
+
+    macro_rules! wordlists {
+        ($($name:ident => $file:literal,)*) => {
+            $(
+                pub(super) static $name: Lazy<Vec<&'static str>> =
+                    Lazy::new(|| gen_wordlist(include_str!(concat!("langs/monero/", $file))));
+            )*
+        };
+    }
+
+    wordlists! {
+        WORDLIST_CHINESE_SIMPLIFIED => "chinese_simplified.txt",
+        WORDLIST_DUTCH => "dutch.txt",
+        WORDLIST_ENGLISH => "english.txt",
+        WORDLIST_ESPERANTO => "esperanto.txt",
+        WORDLIST_FRENCH => "french.txt",
+        WORDLIST_GERMAN => "german.txt",
+        WORDLIST_ITALIAN => "italian.txt",
+        WORDLIST_JAPANESE => "japanese.txt",
+        WORDLIST_LOJBAN => "lojban.txt",
+        WORDLIST_PORTUGUESE => "portuguese.txt",
+        WORDLIST_RUSSIAN => "russian.txt",
+        WORDLIST_SPANISH => "spanish.txt",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    // Test vectors from monero-serai, checked against Monero
+    const VECTORS: &[(Language, &str, &str)] = &[
+        (
+            Language::ChineseSimplified,
+            "摇 曲 艺 武 滴 然 效 似 赏 式 祥 歌 买 疑 小 碧 堆 博 键 房 鲜 悲 付 喷 武",
+            "a5e4fff1706ef9212993a69f246f5c95ad6d84371692d63e9bb0ea112a58340d",
+        ),
+        (
+            Language::English,
+            "washing thirsty occur lectures tuesday fainted toxic adapt abnormal memoir nylon \
+             mostly building shrugged online ember northern ruby woes dauntless boil family \
+             illness inroads northern",
+            "c0af65c0dd837e666b9d0dfed62745f4df35aed7ea619b2798a709f0fe545403",
+        ),
+        (
+            Language::German,
+            "Kaliber Gabelung Tapir Liveband Favorit Specht Enklave Nabel Jupiter Foliant Chronik \
+             nisten löten Vase Aussage Rekord Yeti Gesetz Eleganz Alraune Künstler Almweide Jahr \
+             Kastanie Almweide",
+            "79801b7a1b9796856e2397d862a113862e1fdc289a205e79d8d70995b276db06",
+        ),
+        (
+            Language::Japanese,
+            "ぜんぶ どうぐ おたがい せんきょ おうじ そんちょう じゅしん いろえんぴつ かほう \
+             つかれる えらぶ にちじょう くのう にちようび ぬまえび さんきゃく おおや ちぬき \
+             うすめる いがく せつでん さうな すいえい せつだん おおや",
+            "c56e895cdb13007eda8399222974cdbab493640663804b93cbef3d8c3df80b0b",
+        ),
+        (
+            Language::Russian,
+            "шатер икра нация ехать получать инерция доза реальный рыжий таможня лопата душа \
+             веселый клетка атлас лекция обгонять паек наивный лыжный дурак стать ежик задача \
+             паек",
+            "7cb5492df5eb2db4c84af20766391cd3e3662ab1a241c70fc881f3d02c381f05",
+        ),
+        (
+            Language::Spanish,
+            "minero ocupar mirar evadir octubre cal logro miope opaco disco ancla litio clase \
+             cuello nasal clase fiar avance deseo mente grumo negro cordón croqueta clase",
+            "ae2c9bebdddac067d73ec0180147fc92bdf9ac7337f1bcafbbe57dd13558eb02",
+        ),
+    ];
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn vectors() {
+        for &(lang, phrase, spend_key) in VECTORS {
+            let key = to_spend_key(phrase, lang).unwrap();
+
+            assert_eq!(hex::encode(&key[..]), spend_key);
+            assert_eq!(*from_spend_key(&key, lang).unwrap(), phrase);
+            assert_eq!(Language::detect(phrase), Ok(lang));
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn prefixes_and_no_checksum() {
+        let (lang, phrase, spend_key) = VECTORS[1];
+
+        let trimmed: Vec<_> = phrase.split(' ').map(|word| lang.prefix(word)).collect();
+        let key = to_spend_key(&trimmed.join(" "), lang).unwrap();
+        assert_eq!(hex::encode(&key[..]), spend_key);
+
+        let words: Vec<_> = phrase.split(' ').take(KEY_WORDS).collect();
+        let key = to_spend_key(&words.join(" "), lang).unwrap();
+        assert_eq!(hex::encode(&key[..]), spend_key);
+
+        // Without the checksum word, the full words are needed
+        assert!(to_spend_key(&trimmed[..KEY_WORDS].join(" "), lang).is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid() {
+        let (lang, phrase, _) = VECTORS[1];
+
+        let wrong_checksum = phrase.replace("inroads northern", "inroads washing");
+        assert_eq!(
+            to_spend_key(&wrong_checksum, lang).map(|_| ()),
+            Err(Error::InvalidChecksum)
+        );
+
+        let short = "washing thirsty occur";
+        assert_eq!(
+            to_spend_key(short, lang).map(|_| ()),
+            Err(Error::InvalidWordCount(3))
+        );
+
+        let bip39 = phrase.replace("washing", "abandon");
+        assert!(matches!(
+            to_spend_key(&bip39, lang),
+            Err(Error::InvalidWord { index: 0, .. })
+        ));
+
+        assert_eq!(
+            from_spend_key(&[0xff; 32], lang).map(|_| ()),
+            Err(Error::InvalidSpendKey)
+        );
+        assert_eq!(
+            from_spend_key(&GROUP_ORDER, lang).map(|_| ()),
+            Err(Error::InvalidSpendKey)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generate() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for lang in Language::ALL {
+            let phrase = generate_with_rng(&mut rng, lang).unwrap();
+
+            assert_eq!(phrase.split(' ').count(), 25);
+
+            let key = to_spend_key(&phrase, lang).unwrap();
+            assert_eq!(from_spend_key(&key, lang).unwrap(), phrase);
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn crc32_check_value() {
+        assert_eq!(crc32(CRC32_IEEE, b"123456789"), 0xcbf4_3926);
+    }
+}
@@ -0,0 +1,127 @@
+//! Passphrase strength estimation, enabled with the `passphrase` feature
+//!
+//! The BIP39 passphrase, sometimes called the "25th word", is the only secret protecting a wallet
+//! whose mnemonic leaks, so it should be hard to guess. [`estimate_strength()`][estimate_strength]
+//! estimates how hard with [zxcvbn](https://crates.io/crates/zxcvbn), which recognizes common
+//! passwords, dictionary words, keyboard patterns, dates and the like, for wallets to warn about
+//! weak passphrases when they're set.
+//!
+//! ```
+//! use bip39::passphrase;
+//!
+//! let weak = passphrase::estimate_strength("password1");
+//! assert!(weak.is_weak());
+//! assert!(weak.warning().is_some());
+//!
+//! let strong = passphrase::estimate_strength("correct horse battery staple rotor blimp");
+//! assert!(!strong.is_weak());
+//! ```
+//!
+//! zxcvbn copies the passphrase into buffers of its own, which aren't zeroed when dropped, so only
+//! estimate the strength of passphrases that are being chosen, not of ones in use.
+//!
+//! [estimate_strength]: ./fn.estimate_strength.html
+
+use zxcvbn::zxcvbn;
+
+/// The lowest [`StrengthReport::score()`][score] that isn't weak
+///
+/// [score]: ./struct.StrengthReport.html#method.score
+pub const MIN_SCORE: u8 = 3;
+
+/// How hard a passphrase is to guess, as estimated by
+/// [`estimate_strength()`][estimate_strength]
+///
+/// [estimate_strength]: ./fn.estimate_strength.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrengthReport {
+    score: u8,
+    guesses_log10: f64,
+    crack_time: String,
+    warning: Option<String>,
+    suggestions: Vec<String>,
+}
+
+impl StrengthReport {
+    /// The score from 0, too guessable, to 4, very unguessable
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// Whether the score is below [`MIN_SCORE`][MIN_SCORE], so the user should be warned
+    ///
+    /// [MIN_SCORE]: ./constant.MIN_SCORE.html
+    pub fn is_weak(&self) -> bool {
+        self.score < MIN_SCORE
+    }
+
+    /// The base 10 logarithm of the estimated number of guesses needed to find the passphrase
+    pub fn guesses_log10(&self) -> f64 {
+        self.guesses_log10
+    }
+
+    /// How long an offline attack would take to find the passphrase at 10,000 guesses per second,
+    /// about the rate the 2048 rounds of PBKDF2 allow, in words such as `"3 hours"`
+    pub fn crack_time(&self) -> &str {
+        &self.crack_time
+    }
+
+    /// What makes the passphrase weak, if anything in particular does
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
+    /// Hints for choosing a stronger passphrase, empty unless it's weak
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+/// Estimate how hard `passphrase` is to guess
+pub fn estimate_strength(passphrase: &str) -> StrengthReport {
+    let entropy = zxcvbn(passphrase, &[]);
+    let feedback = entropy.feedback();
+
+    StrengthReport {
+        score: entropy.score().into(),
+        guesses_log10: entropy.guesses_log10(),
+        crack_time: entropy
+            .crack_times()
+            .offline_slow_hashing_1e4_per_second()
+            .to_string(),
+        warning: feedback.and_then(|f| f.warning()).map(|w| w.to_string()),
+        suggestions: feedback
+            .map(|f| f.suggestions().iter().map(|s| s.to_string()).collect())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn weak_passphrases() {
+        for passphrase in ["", "password", "qwerty123", "11111111", "letmein"] {
+            let report = estimate_strength(passphrase);
+
+            assert!(report.is_weak(), "{:?} is weak", passphrase);
+            assert!(!report.suggestions().is_empty());
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn strong_passphrase() {
+        let report = estimate_strength("correct horse battery staple rotor blimp");
+
+        assert_eq!(report.score(), 4);
+        assert!(report.guesses_log10() > 10.0);
+        assert_eq!(report.warning(), None);
+        assert!(report.suggestions().is_empty());
+        assert_eq!(report.crack_time(), "centuries");
+    }
+}
@@ -0,0 +1,133 @@
+//! Python bindings, enabled with the `python` feature
+//!
+//! This builds the `bip39` extension module with a single `Mnemonic` class. Build and install it
+//! into the current virtualenv with `maturin develop --features python`, then:
+//!
+//! ```python
+//! from bip39 import Mnemonic
+//!
+//! mnemonic = Mnemonic(12, "en")
+//! assert Mnemonic.validate(mnemonic.phrase)
+//! seed = mnemonic.to_seed("password")
+//! ```
+//!
+//! Languages are passed as the language codes accepted by
+//! [`Language::from_language_code()`][from_language_code], and default to English. Errors are
+//! raised as `ValueError`, with the message of the [`Error`][Error].
+//!
+//! [from_language_code]: ../language/enum.Language.html#method.from_language_code
+//! [Error]: ../error/enum.Error.html
+
+use crate::error::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::borrow::Cow;
+
+fn to_pyerr(err: Error) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn to_language(lang: &str) -> PyResult<Language> {
+    Language::from_language_code(lang)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown language code {:?}", lang)))
+}
+
+/// A mnemonic phrase, wrapping the Rust [`Mnemonic`][Mnemonic]
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+#[pyclass(name = "Mnemonic", module = "bip39", frozen)]
+pub struct PyMnemonic {
+    inner: Mnemonic,
+}
+
+#[pymethods]
+impl PyMnemonic {
+    /// Generate a new mnemonic with `words` words, using the operating system CSPRNG
+    #[new]
+    #[pyo3(signature = (words = 12, lang = "en"))]
+    fn new(words: usize, lang: &str) -> PyResult<Self> {
+        let mtype = MnemonicType::for_word_count(words).map_err(to_pyerr)?;
+        let inner = Mnemonic::try_new(mtype, to_language(lang)?).map_err(to_pyerr)?;
+
+        Ok(PyMnemonic { inner })
+    }
+
+    /// Create a mnemonic from an existing phrase, checksum included
+    #[staticmethod]
+    #[pyo3(signature = (phrase, lang = "en"))]
+    fn from_string(phrase: &str, lang: &str) -> PyResult<Self> {
+        let inner = Mnemonic::from_phrase(phrase, to_language(lang)?).map_err(to_pyerr)?;
+
+        Ok(PyMnemonic { inner })
+    }
+
+    /// Check whether `phrase` is a valid mnemonic phrase, checksum included
+    #[staticmethod]
+    #[pyo3(signature = (phrase, lang = "en"))]
+    fn validate(phrase: &str, lang: &str) -> PyResult<bool> {
+        Ok(Mnemonic::validate(phrase, to_language(lang)?).is_ok())
+    }
+
+    #[getter]
+    fn phrase(&self) -> &str {
+        self.inner.phrase()
+    }
+
+    #[getter]
+    fn entropy(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.inner.entropy())
+    }
+
+    /// Derive the 64 byte BIP39 seed, using `passphrase` as the BIP39 password
+    #[pyo3(signature = (passphrase = ""))]
+    fn to_seed(&self, passphrase: &str) -> Cow<'_, [u8]> {
+        Cow::Owned(Seed::new(&self.inner, passphrase).as_bytes().to_vec())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Mnemonic(<{} words>)",
+            self.inner.phrase().split(' ').count()
+        )
+    }
+}
+
+#[pymodule]
+fn bip39(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMnemonic>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[test]
+    fn new() {
+        let mnemonic = PyMnemonic::new(24, "en").unwrap();
+
+        assert_eq!(mnemonic.phrase().split(' ').count(), 24);
+        assert!(PyMnemonic::validate(mnemonic.phrase(), "en").unwrap());
+        assert!(PyMnemonic::new(13, "en").is_err());
+        assert!(PyMnemonic::new(12, "xx").is_err());
+    }
+
+    #[test]
+    fn from_string() {
+        let mnemonic = PyMnemonic::from_string(PHRASE, "en").unwrap();
+        let expected = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+
+        assert_eq!(mnemonic.entropy(), expected.entropy());
+        assert_eq!(
+            &mnemonic.to_seed("password")[..],
+            Seed::new(&expected, "password").as_bytes()
+        );
+        assert!(!PyMnemonic::validate("park remain", "en").unwrap());
+    }
+}
@@ -0,0 +1,130 @@
+//! QR codes of a [`Mnemonic`][Mnemonic], enabled with the `qr` feature
+//!
+//! [`Mnemonic::to_qr_matrix()`][to_qr_matrix] and [`Mnemonic::to_qr_svg()`][to_qr_svg] render
+//! one of the [`QrPayload`][QrPayload]s, a SeedQR by default, so that it can be scanned by an
+//! air-gapped signer.
+//!
+//! ```
+//! use bip39::qr::QrPayload;
+//! use bip39::{Language, Mnemonic};
+//!
+//! let phrase = "forum undo fragile fade shy sign arrest garment culture tube off merit";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! let matrix = mnemonic.to_qr_matrix(QrPayload::CompactSeedQr);
+//! assert_eq!(matrix.len(), 21);
+//!
+//! let svg = mnemonic.to_qr_svg(QrPayload::SeedQr);
+//! assert!(svg.contains("<svg"));
+//! ```
+//!
+//! **Note:** the `qrcode` crate keeps the payload in buffers of its own while encoding it, which
+//! aren't zeroized like the returned matrix and SVG are.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [to_qr_matrix]: ../mnemonic/struct.Mnemonic.html#method.to_qr_matrix
+//! [to_qr_svg]: ../mnemonic/struct.Mnemonic.html#method.to_qr_svg
+//! [QrPayload]: ./enum.QrPayload.html
+
+use crate::mnemonic::Mnemonic;
+use crate::seedqr;
+use qrcode::bits::Bits;
+use qrcode::{EcLevel, QrCode, Version};
+
+/// What the QR code of a mnemonic holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QrPayload {
+    /// The 4 digit indices of the words, see [`seedqr::to_digits()`][to_digits]
+    ///
+    /// [to_digits]: ../seedqr/fn.to_digits.html
+    #[default]
+    SeedQr,
+    /// The raw entropy, see [`seedqr::to_compact()`][to_compact]
+    ///
+    /// [to_compact]: ../seedqr/fn.to_compact.html
+    CompactSeedQr,
+    /// The phrase itself, as text
+    Phrase,
+}
+
+/// Encode the `payload` of `mnemonic` with the lowest error correction level, as SeedQR does
+pub(crate) fn encode(mnemonic: &Mnemonic, payload: QrPayload) -> QrCode {
+    match payload {
+        QrPayload::SeedQr => {
+            QrCode::with_error_correction_level(&seedqr::to_digits(mnemonic)[..], EcLevel::L)
+        }
+        QrPayload::Phrase => QrCode::with_error_correction_level(mnemonic.phrase(), EcLevel::L),
+        // Byte mode, even if the entropy happens to fit a denser mode
+        QrPayload::CompactSeedQr => (1..=40)
+            .find_map(|version| {
+                let mut bits = Bits::new(Version::Normal(version));
+
+                bits.push_byte_data(&seedqr::to_compact(mnemonic)).ok()?;
+                bits.push_terminator(EcLevel::L).ok()?;
+
+                QrCode::with_bits(bits, EcLevel::L).ok()
+            })
+            .ok_or(qrcode::types::QrError::DataTooLong),
+    }
+    .expect("a mnemonic fits in a QR code")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::language::Language;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE_12: &str =
+        "forum undo fragile fade shy sign arrest garment culture tube off merit";
+    const PHRASE_24: &str = "attack pizza motion avocado network gather crop fresh patrol \
+                             unusual wild holiday candy pony ranch winter theme error hybrid van \
+                             cereal salon goddess expire";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn matrix_sizes() {
+        // The versions SeedSigner uses: 2 and 3 for SeedQR, 1 and 2 for CompactSeedQR
+        let vectors = [
+            (PHRASE_12, QrPayload::SeedQr, 25),
+            (PHRASE_24, QrPayload::SeedQr, 29),
+            (PHRASE_12, QrPayload::CompactSeedQr, 21),
+            (PHRASE_24, QrPayload::CompactSeedQr, 25),
+        ];
+
+        for (phrase, payload, width) in vectors {
+            let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+            let matrix = mnemonic.to_qr_matrix(payload);
+
+            assert_eq!(matrix.len(), width);
+            assert!(matrix.iter().all(|row| row.len() == width));
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn finder_patterns() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE_24, Language::English).unwrap();
+        let matrix = mnemonic.to_qr_matrix(QrPayload::Phrase);
+
+        // The top left finder pattern: a dark ring, a light ring and a dark 3x3 center
+        assert!(matrix[0][..7].iter().all(|&dark| dark));
+        assert_eq!(
+            matrix[1][..7],
+            [true, false, false, false, false, false, true]
+        );
+        assert_eq!(matrix[3][..7], [true, false, true, true, true, false, true]);
+        assert!(!matrix[7][0]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn svg() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE_12, Language::English).unwrap();
+        let svg = mnemonic.to_qr_svg(QrPayload::default());
+
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("<svg"));
+    }
+}
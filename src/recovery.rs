@@ -0,0 +1,679 @@
+//! Recover a [`Mnemonic`][Mnemonic] with missing words
+//!
+//! Mark every word that's lost, or can't be read anymore, with a `?`, and
+//! [`missing_words()`][missing_words] goes through all the words they could be, yielding every
+//! mnemonic with a valid checksum:
+//!
+//! ```
+//! use bip39::{recovery, Language};
+//!
+//! let phrase = "park remain ? kitchen mule spell knee armed position rail grid ankle";
+//!
+//! let candidates: Vec<_> = recovery::missing_words(phrase, Language::English)
+//!     .unwrap()
+//!     .collect();
+//!
+//! assert!(candidates.iter().any(|m| m.phrase().contains(" person ")));
+//! ```
+//!
+//! The checksum only rules out 15 in 16 candidates for 12 words, and 255 in 256 for 24 words, so
+//! there are usually many of them. Check each against something known about the wallet, such as
+//! an address it received funds on. Every missing word multiplies the candidates by 2048: one or
+//! two take moments, three or more are out of reach.
+//!
+//! When a word isn't quite lost, listing the words it could be with [`matching()`][matching]
+//! narrows the search down a lot:
+//!
+//! ```
+//! use bip39::recovery::{self, WordPattern};
+//! use bip39::Language;
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mut pattern: Vec<_> = phrase.split(' ').map(WordPattern::Word).collect();
+//! pattern[2] = WordPattern::Missing;
+//! pattern[7] = WordPattern::OneOf(&["army", "armed", "arm"]);
+//!
+//! let candidates = recovery::matching(&pattern, Language::English).unwrap();
+//! assert_eq!(candidates.combinations(), Some(2048 * 3));
+//! ```
+//!
+//! [`valid_final_words()`][valid_final_words] is the same for the last word only, for making a
+//! mnemonic by hand.
+//!
+//! When a word was written down wrong, [`repair()`][repair] finds the phrases one edit away with a
+//! valid checksum:
+//!
+//! ```
+//! use bip39::{recovery, Language};
+//!
+//! let phrase = "park remain persn kitchen mule spell knee armed position rail grid ankle";
+//! let repaired = recovery::repair(phrase, Language::English).unwrap();
+//!
+//! assert!(repaired.iter().any(|m| m.phrase().contains(" person ")));
+//! ```
+//!
+//! When the words are all known but the passphrase isn't, [`search_passphrases()`][search]
+//! derives the seed of every passphrase it could be until one passes a check.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [missing_words]: ./fn.missing_words.html
+//! [matching]: ./fn.matching.html
+//! [valid_final_words]: ./fn.valid_final_words.html
+//! [repair]: ./fn.repair.html
+//! [search]: ./fn.search_passphrases.html
+
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use crate::util::{edit_distance, is_word_separator};
+use alloc::string::String;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+/// The marker of a missing word
+const MISSING: &str = "?";
+
+/// How far a word that isn't in the word list can be from the words it's repaired to
+const MAX_UNKNOWN_DISTANCE: usize = 2;
+
+/// How far a word of the word list can be from the words it's repaired to
+const MAX_KNOWN_DISTANCE: usize = 1;
+
+/// Go through every word that could be at the positions of `phrase` marked with `?`, and yield
+/// the mnemonics in `lang` with a valid checksum
+///
+/// The phrase is normalized the same way as in [`Mnemonic::from_phrase()`][from_phrase]. Returns
+/// `Error::InvalidWordCount` if the phrase, including the missing words, doesn't have a valid
+/// number of words, `Error::PhraseTooLong` if it's longer than
+/// [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN], and `Error::InvalidWord` for a word that isn't in
+/// the word list. A phrase without missing words yields one mnemonic if it's valid, and none otherwise.
+///
+/// [from_phrase]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+/// [MAX_PHRASE_LEN]: ../mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+pub fn missing_words(phrase: &str, lang: Language) -> Result<Candidates, Error> {
+    Mnemonic::check_bounds(phrase)?;
+
+    let phrase = Mnemonic::normalize_phrase(phrase, lang);
+
+    let pattern: Vec<WordPattern> = phrase
+        .split(is_word_separator)
+        .map(|word| match word {
+            MISSING => WordPattern::Missing,
+            word => WordPattern::Word(word),
+        })
+        .collect();
+
+    matching(&pattern, lang)
+}
+
+/// What's known about a word of a mnemonic, see [`matching()`][matching]
+///
+/// [matching]: ./fn.matching.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordPattern<'a> {
+    /// The word is known
+    Word(&'a str),
+    /// The word is missing, so it could be any word of the word list
+    Missing,
+    /// The word is one of these, for instance words that look alike, or that are half remembered
+    OneOf(&'a [&'a str]),
+}
+
+/// Go through every mnemonic in `lang` matching `pattern`, one [`WordPattern`][WordPattern] for
+/// each word, and yield the ones with a valid checksum
+///
+/// The words are normalized the same way as in [`Mnemonic::from_phrase()`][from_phrase]. Returns
+/// `Error::InvalidWordCount` if the pattern doesn't have a valid number of words, and
+/// `Error::InvalidWord` for a word that isn't in the word list. Nothing is checked until the
+/// iterator is advanced, so it can be stopped after any number of candidates, see
+/// [`Candidates::combinations()`][combinations] for how many there are to go through.
+///
+/// [WordPattern]: ./enum.WordPattern.html
+/// [from_phrase]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+/// [combinations]: ./struct.Candidates.html#method.combinations
+pub fn matching(pattern: &[WordPattern], lang: Language) -> Result<Candidates, Error> {
+    MnemonicType::for_word_count(pattern.len())?;
+
+    let index_of = |word: &str, index: usize| {
+        let word = Mnemonic::normalize_phrase(word, lang);
+
+        lang.wordmap()
+            .get_bits(&word)
+            .map(u16::from)
+            .ok_or_else(|| Error::InvalidWord {
+                word: SecretWord::new(&word, Some(lang)),
+                index,
+            })
+    };
+
+    let mut indices = Zeroizing::new(Vec::with_capacity(pattern.len()));
+    let mut choices = Vec::new();
+
+    for (index, word) in pattern.iter().enumerate() {
+        let options: Vec<u16> = match *word {
+            WordPattern::Word(word) => {
+                indices.push(index_of(word, index)?);
+                continue;
+            }
+            WordPattern::Missing => (0..2048).collect(),
+            WordPattern::OneOf(words) => words
+                .iter()
+                .map(|word| index_of(word, index))
+                .collect::<Result<_, _>>()?,
+        };
+        let options = Zeroizing::new(options);
+
+        indices.push(options.first().copied().unwrap_or(0));
+        choices.push(Choice {
+            position: index,
+            options,
+            current: 0,
+        });
+    }
+
+    Ok(Candidates {
+        lang,
+        indices,
+        done: choices.iter().any(|choice| choice.options.is_empty()),
+        choices,
+    })
+}
+
+/// Every last word in `lang` that completes `words` to a mnemonic with a valid checksum
+///
+/// This is for making a mnemonic by hand, for instance by rolling dice for all the words but the
+/// last one, which carries the checksum. There are 128 of them for 12 words, and 8 for 24 words,
+/// in the order of the word list.
+///
+/// Returns the same errors as [`missing_words()`][missing_words], with the last word counted in.
+/// Words marked with `?` go through all the words they could be too, and the last words of all
+/// of them are returned.
+///
+/// [missing_words]: ./fn.missing_words.html
+pub fn valid_final_words(words: &[&str], lang: Language) -> Result<Vec<&'static str>, Error> {
+    let mut phrase = Zeroizing::new(words.join(" "));
+    phrase.push(' ');
+    phrase.push_str(MISSING);
+
+    let mut last: Vec<u16> = missing_words(&phrase, lang)?
+        .filter_map(|mnemonic| mnemonic.to_indices().last().copied())
+        .collect();
+    last.sort_unstable();
+    last.dedup();
+
+    Ok(last
+        .into_iter()
+        .filter_map(|index| lang.word_at(index))
+        .collect())
+}
+
+/// Find the mnemonics in `lang` with a valid checksum that `phrase` is one mistake away from
+///
+/// Writing one word down wrong is the most common way a backup gets damaged, so this goes through
+/// every phrase that differs from `phrase` by one mistake:
+///
+/// + a word that isn't in the word list is replaced with the words within an edit distance of 2
+///   of it,
+/// + otherwise, any one word is replaced with the words within an edit distance of 1 of it, and
+///   any two neighbouring words are swapped.
+///
+/// The corrections are returned in that order, replacements first, position by position. There's
+/// usually only one for a word that isn't in the word list, and a handful otherwise, so check
+/// each against something known about the wallet. A phrase that is already valid needs no repair
+/// and is returned on its own.
+///
+/// The phrase is normalized the same way as in [`Mnemonic::from_phrase()`][from_phrase]. Returns
+/// `Error::InvalidWordCount` if the phrase doesn't have a valid number of words,
+/// `Error::PhraseTooLong` if it's longer than [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN], and
+/// `Error::InvalidWord` for the second word that isn't in the word list, as one mistake can't
+/// explain two of them.
+///
+/// [from_phrase]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+/// [MAX_PHRASE_LEN]: ../mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+pub fn repair(phrase: &str, lang: Language) -> Result<Vec<Mnemonic>, Error> {
+    Mnemonic::check_bounds(phrase)?;
+
+    let phrase = Mnemonic::normalize_phrase(phrase, lang);
+    let words: Vec<&str> = phrase.split(is_word_separator).collect();
+
+    MnemonicType::for_word_count(words.len())?;
+
+    let mut indices = Zeroizing::new(Vec::with_capacity(words.len()));
+    let mut unknown = None;
+
+    for (index, &word) in words.iter().enumerate() {
+        match lang.wordmap().get_bits(word) {
+            Some(bits) => indices.push(u16::from(bits)),
+            None if unknown.is_none() => {
+                unknown = Some(index);
+                indices.push(0);
+            }
+            None => {
+                return Err(Error::InvalidWord {
+                    word: SecretWord::new(word, Some(lang)),
+                    index,
+                });
+            }
+        }
+    }
+
+    if unknown.is_none() {
+        if let Ok(mnemonic) = Mnemonic::from_indices(&indices, lang) {
+            return Ok(alloc::vec![mnemonic]);
+        }
+    }
+
+    let mut repaired = Vec::new();
+
+    let positions = match unknown {
+        Some(index) => index..index + 1,
+        None => 0..words.len(),
+    };
+    let max_distance = match unknown {
+        Some(_) => MAX_UNKNOWN_DISTANCE,
+        None => MAX_KNOWN_DISTANCE,
+    };
+
+    for position in positions {
+        let original = indices[position];
+        let word = words[position];
+
+        for (index, candidate) in lang.wordlist().iter().enumerate() {
+            if candidate == word || edit_distance(word, candidate) > max_distance {
+                continue;
+            }
+
+            indices[position] = index as u16;
+            repaired.extend(Mnemonic::from_indices(&indices, lang));
+        }
+
+        indices[position] = original;
+    }
+
+    if unknown.is_none() {
+        for position in 1..indices.len() {
+            if indices[position - 1] == indices[position] {
+                continue;
+            }
+
+            indices.swap(position - 1, position);
+            repaired.extend(Mnemonic::from_indices(&indices, lang));
+            indices.swap(position - 1, position);
+        }
+    }
+
+    Ok(repaired)
+}
+
+/// Derive the seed of `mnemonic` with every passphrase of `candidates`, and return the first one
+/// whose seed passes `check`, such as deriving an address the wallet is known to have used
+///
+/// This is the same as [`search_passphrases_with()`][with], without progress reporting.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{recovery, Language, Mnemonic, Seed};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+/// let known = Seed::new(&mnemonic, "hunter2");
+///
+/// let candidates = ["hunter", "hunter1", "hunter2", "hunter3"].iter().map(|p| p.to_string());
+/// let found = recovery::search_passphrases(&mnemonic, candidates, |seed| *seed == known);
+///
+/// assert_eq!(found.as_deref().map(String::as_str), Some("hunter2"));
+/// ```
+///
+/// [with]: ./fn.search_passphrases_with.html
+pub fn search_passphrases<I, C>(
+    mnemonic: &Mnemonic,
+    candidates: I,
+    check: C,
+) -> Option<Zeroizing<String>>
+where
+    I: Iterator<Item = String> + Send,
+    C: Fn(&Seed) -> bool + Sync,
+{
+    search_passphrases_with(mnemonic, candidates, check, |_| true)
+}
+
+/// Derive the seed of `mnemonic` with every passphrase of `candidates`, and return the first one
+/// whose seed passes `check`, reporting progress along the way
+///
+/// `progress` is called with the number of passphrases tried so far after each one, and the
+/// search is cancelled as soon as it returns `false`, in which case `None` is returned like when
+/// no passphrase passes. Every passphrase takes a full PBKDF2 derivation, so checking a few
+/// hundred a second per core is as fast as it goes.
+///
+/// With the `rayon` feature the passphrases are tried in parallel, on rayon's global thread pool,
+/// and any of them that passes may be returned, not necessarily the first one. The passphrases
+/// that don't pass are zeroized once they're tried.
+pub fn search_passphrases_with<I, C, P>(
+    mnemonic: &Mnemonic,
+    candidates: I,
+    check: C,
+    progress: P,
+) -> Option<Zeroizing<String>>
+where
+    I: Iterator<Item = String> + Send,
+    C: Fn(&Seed) -> bool + Sync,
+    P: Fn(u64) -> bool + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+        use rayon::prelude::*;
+
+        let tried = AtomicU64::new(0);
+        let cancelled = AtomicBool::new(false);
+
+        candidates
+            .par_bridge()
+            .find_map_any(|candidate| {
+                if cancelled.load(Ordering::Relaxed) {
+                    return Some(None);
+                }
+
+                let candidate = Zeroizing::new(candidate);
+                if check(&Seed::new(mnemonic, &candidate)) {
+                    return Some(Some(candidate));
+                }
+
+                if !progress(tried.fetch_add(1, Ordering::Relaxed) + 1) {
+                    cancelled.store(true, Ordering::Relaxed);
+                    return Some(None);
+                }
+
+                None
+            })
+            .flatten()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        for (tried, candidate) in (1..).zip(candidates) {
+            let candidate = Zeroizing::new(candidate);
+            if check(&Seed::new(mnemonic, &candidate)) {
+                return Some(candidate);
+            }
+
+            if !progress(tried) {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+/// The mnemonics that match a pattern, see [`matching()`][matching] and
+/// [`missing_words()`][missing_words]
+///
+/// They're yielded in the order of the options at each position that isn't known, the first
+/// one being the slowest to change.
+///
+/// [matching]: ./fn.matching.html
+/// [missing_words]: ./fn.missing_words.html
+pub struct Candidates {
+    lang: Language,
+    indices: Zeroizing<Vec<u16>>,
+    /// The positions that aren't known, with the words they could be
+    choices: Vec<Choice>,
+    done: bool,
+}
+
+/// The words a position could be, the current one being in `Candidates::indices`
+struct Choice {
+    position: usize,
+    options: Zeroizing<Vec<u16>>,
+    current: usize,
+}
+
+impl Candidates {
+    /// The number of word combinations to go through, which is the product of the number of
+    /// words each position could be, or `None` if that overflows a `u64`
+    pub fn combinations(&self) -> Option<u64> {
+        self.choices.iter().try_fold(1u64, |total, choice| {
+            total.checked_mul(choice.options.len() as u64)
+        })
+    }
+
+    /// Move on to the next combination of words, like an odometer
+    fn advance(&mut self) {
+        for choice in self.choices.iter_mut().rev() {
+            choice.current += 1;
+
+            if choice.current < choice.options.len() {
+                self.indices[choice.position] = choice.options[choice.current];
+                return;
+            }
+
+            choice.current = 0;
+            self.indices[choice.position] = choice.options[0];
+        }
+
+        self.done = true;
+    }
+}
+
+impl Iterator for Candidates {
+    type Item = Mnemonic;
+
+    fn next(&mut self) -> Option<Mnemonic> {
+        while !self.done {
+            let candidate = Mnemonic::from_indices(&self.indices, self.lang);
+            self.advance();
+
+            if let Ok(mnemonic) = candidate {
+                return Some(mnemonic);
+            }
+        }
+
+        None
+    }
+}
+
+/// The indices of the words are secret, so they're never printed
+impl core::fmt::Debug for Candidates {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let positions: Vec<usize> = self.choices.iter().map(|choice| choice.position).collect();
+
+        f.debug_struct("Candidates")
+            .field("positions", &positions)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::sync::atomic::{AtomicU64, Ordering};
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn one_missing_word() {
+        let phrase = PHRASE.replace("person", "?");
+        let candidates: Vec<_> = missing_words(&phrase, Language::English).unwrap().collect();
+
+        // Around one in 16 words passes the checksum of 12 words
+        assert!((64..256).contains(&candidates.len()));
+        assert!(candidates
+            .iter()
+            .any(|mnemonic| mnemonic.phrase() == PHRASE));
+        assert!(candidates
+            .iter()
+            .all(|mnemonic| mnemonic.phrase().starts_with("park remain ")));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn missing_last_word() {
+        let phrase = PHRASE.replace("ankle", "?");
+        let candidates = missing_words(&phrase, Language::English).unwrap();
+
+        assert_eq!(candidates.combinations(), Some(2048));
+
+        // The last word has 7 bits of entropy, and the 4 checksum bits that follow from them
+        let candidates: Vec<_> = candidates.collect();
+        assert_eq!(candidates.len(), 128);
+        assert_eq!(
+            candidates
+                .iter()
+                .filter(|mnemonic| mnemonic.phrase() == PHRASE)
+                .count(),
+            1
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn no_missing_words() {
+        assert_eq!(missing_words(PHRASE, Language::English).unwrap().count(), 1);
+
+        let invalid = PHRASE.replace("park", "remain");
+        assert_eq!(
+            missing_words(&invalid, Language::English).unwrap().count(),
+            0
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn word_patterns() {
+        let mut pattern: Vec<_> = PHRASE.split(' ').map(WordPattern::Word).collect();
+        pattern[3] = WordPattern::OneOf(&["kitchen", "kite", "kidney"]);
+        pattern[9] = WordPattern::Missing;
+
+        let candidates = matching(&pattern, Language::English).unwrap();
+        assert_eq!(candidates.combinations(), Some(3 * 2048));
+
+        let candidates: Vec<_> = candidates.collect();
+        assert!(candidates
+            .iter()
+            .any(|mnemonic| mnemonic.phrase() == PHRASE));
+        assert!(candidates.iter().all(|mnemonic| {
+            let word = mnemonic.phrase().split(' ').nth(3).unwrap().to_string();
+            ["kitchen", "kite", "kidney"].contains(&word.as_str())
+        }));
+
+        pattern[3] = WordPattern::OneOf(&[]);
+        let candidates = matching(&pattern, Language::English).unwrap();
+        assert_eq!(candidates.combinations(), Some(0));
+        assert_eq!(candidates.count(), 0);
+
+        pattern[3] = WordPattern::OneOf(&["kitchen", "kitchn"]);
+        match matching(&pattern, Language::English) {
+            Err(Error::InvalidWord { index, .. }) => assert_eq!(index, 3),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn final_words() {
+        let words: Vec<&str> = PHRASE.split(' ').collect();
+
+        let last = valid_final_words(&words[..11], Language::English).unwrap();
+        assert_eq!(last.len(), 128);
+        assert!(last.contains(&"ankle"));
+        assert!(last.windows(2).all(|pair| {
+            Language::English.index_of(pair[0]) < Language::English.index_of(pair[1])
+        }));
+
+        let mut words = words;
+        words.extend_from_slice(&words.clone()[..11]);
+        assert_eq!(
+            valid_final_words(&words[..23], Language::English)
+                .unwrap()
+                .len(),
+            8
+        );
+
+        let result = valid_final_words(&words[..10], Language::English);
+        assert_eq!(result, Err(Error::InvalidWordCount(11)));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_phrases() {
+        let result = missing_words("park remain ? kitchen", Language::English);
+        assert_eq!(result.map(|_| ()), Err(Error::InvalidWordCount(4)));
+
+        let phrase = PHRASE.replace("person", "?").replace("kitchen", "kitchn");
+        match missing_words(&phrase, Language::English) {
+            Err(Error::InvalidWord { index, .. }) => assert_eq!(index, 3),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn passphrase_search() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let known = Seed::new(&mnemonic, "correct horse");
+        let candidates = || {
+            ["", "password", "correct horse", "battery staple"]
+                .iter()
+                .map(|p| p.to_string())
+        };
+
+        let found = search_passphrases(&mnemonic, candidates(), |seed| *seed == known);
+        assert_eq!(found.as_deref().map(String::as_str), Some("correct horse"));
+
+        let tried = AtomicU64::new(0);
+        let none = search_passphrases_with(
+            &mnemonic,
+            candidates(),
+            |_| false,
+            |count| {
+                tried.fetch_max(count, Ordering::Relaxed);
+                true
+            },
+        );
+        assert_eq!(none, None);
+        assert_eq!(tried.load(Ordering::Relaxed), 4);
+
+        // Without cancelling, this would go on forever
+        let endless = (0u64..).map(|n| n.to_string());
+        let cancelled = search_passphrases_with(&mnemonic, endless, |_| false, |count| count < 3);
+        assert_eq!(cancelled, None);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn repair_phrases() {
+        let valid = repair(PHRASE, Language::English).unwrap();
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].phrase(), PHRASE);
+
+        let misspelled = PHRASE.replace("kitchen", "kitchn");
+        let repaired = repair(&misspelled, Language::English).unwrap();
+        assert!(repaired.iter().any(|mnemonic| mnemonic.phrase() == PHRASE));
+        assert!(repaired.len() < 4);
+
+        let swapped = PHRASE.replace("mule spell", "spell mule");
+        let repaired = repair(&swapped, Language::English).unwrap();
+        assert!(repaired.iter().any(|mnemonic| mnemonic.phrase() == PHRASE));
+        assert!(repaired
+            .iter()
+            .all(|mnemonic| { Mnemonic::validate(mnemonic.phrase(), Language::English).is_ok() }));
+
+        let wrong_word = PHRASE.replace("rail", "rain");
+        let repaired = repair(&wrong_word, Language::English).unwrap();
+        assert!(repaired.iter().any(|mnemonic| mnemonic.phrase() == PHRASE));
+
+        let two_unknown = misspelled.replace("spell", "spel");
+        match repair(&two_unknown, Language::English) {
+            Err(Error::InvalidWord { index, .. }) => assert_eq!(index, 5),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Mnemonics sealed under a password, for storing them at rest
+//!
+//! A sealed mnemonic is a header followed by the XChaCha20-Poly1305 ciphertext of the language
+//! code and the entropy, under a key stretched from the password with Argon2id:
+//!
+//! | bytes | field                                          |
+//! |-------|------------------------------------------------|
+//! | 4     | magic, `B39S`                                  |
+//! | 1     | format version, `1`                            |
+//! | 1     | key derivation function, `1` for Argon2id      |
+//! | 12    | Argon2id `m_cost`, `t_cost` and `p_cost`, BE   |
+//! | 16    | salt                                           |
+//! | 24    | nonce                                          |
+//! | rest  | ciphertext of the code length, code, entropy   |
+//!
+//! The whole header is authenticated as associated data, so the parameters can't be changed
+//! without the tag failing to verify. The tag can only be checked once the key is stretched with
+//! them though, so parameters above 1 GiB of memory, 16 iterations or 16 lanes are rejected with
+//! `Error::InvalidKdfParams` before that, both when sealing and unsealing.
+
+use crate::crypto::gen_random_bytes;
+use crate::error::Error;
+use crate::language::Language;
+use crate::seed::Argon2Params;
+use crate::util::nfkd_zeroizing;
+use alloc::vec::Vec;
+use argon2::{Algorithm, Argon2, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use core::convert::TryInto;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+/// Identifies a sealed mnemonic
+const MAGIC: [u8; 4] = *b"B39S";
+
+/// The only version of the format
+const VERSION: u8 = 1;
+
+/// The identifier of Argon2id, the only key derivation function of version 1
+const KDF_ARGON2ID: u8 = 1;
+
+const SALT_LEN: usize = 16;
+
+const NONCE_LEN: usize = 24;
+
+/// Magic, version, KDF, its three parameters, salt and nonce
+const HEADER_LEN: usize = 4 + 1 + 1 + 12 + SALT_LEN + NONCE_LEN;
+
+/// Poly1305 tag length
+const TAG_LEN: usize = 16;
+
+/// The largest Argon2id parameters accepted, in KiB for `m_cost`, so that a crafted header can't
+/// make unsealing take unbounded memory or time
+const MAX_M_COST: u32 = 1 << 20;
+const MAX_T_COST: u32 = 16;
+const MAX_P_COST: u32 = 16;
+
+/// Seal `entropy` and the code of `lang` under `password`
+pub(crate) fn seal<R>(
+    rng: &mut R,
+    entropy: &[u8],
+    lang: Language,
+    password: &str,
+    params: &Argon2Params,
+) -> Result<Vec<u8>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let plaintext = mnemonic_plaintext(entropy, lang)?;
+
+    encrypt(rng, MAGIC, &plaintext, password, params)
+}
+
+/// Unseal the entropy and language of a mnemonic sealed under `password`
+pub(crate) fn unseal(
+    sealed: &[u8],
+    password: &str,
+) -> Result<(Zeroizing<Vec<u8>>, Language), Error> {
+    let plaintext = decrypt(sealed, MAGIC, password, Error::InvalidSealedMnemonic)?;
+
+    parse_mnemonic_plaintext(&plaintext, Error::InvalidSealedMnemonic)
+}
+
+/// The length of the code of `lang`, the code and `entropy`, the plaintext of a sealed mnemonic
+pub(crate) fn mnemonic_plaintext(
+    entropy: &[u8],
+    lang: Language,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let code = lang.code().ok_or(Error::UnknownLanguage)?;
+
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(1 + code.len() + entropy.len()));
+    plaintext.push(code.len() as u8);
+    plaintext.extend_from_slice(code.as_bytes());
+    plaintext.extend_from_slice(entropy);
+
+    Ok(plaintext)
+}
+
+/// Split the plaintext of a sealed mnemonic into its entropy and language
+///
+/// Returns `malformed` if it's too short.
+pub(crate) fn parse_mnemonic_plaintext(
+    plaintext: &[u8],
+    malformed: Error,
+) -> Result<(Zeroizing<Vec<u8>>, Language), Error> {
+    // The plaintext is authentic, so only a newer writer could have made it malformed
+    let (&code_len, rest) = plaintext.split_first().ok_or(malformed.clone())?;
+    if rest.len() < code_len as usize {
+        return Err(malformed);
+    }
+    let (code, entropy) = rest.split_at(code_len as usize);
+    let lang = core::str::from_utf8(code)
+        .ok()
+        .and_then(Language::from_language_code)
+        .ok_or(Error::UnknownLanguage)?;
+
+    Ok((Zeroizing::new(entropy.to_vec()), lang))
+}
+
+/// Encrypt `plaintext` under `password`, after a header starting with `magic`
+pub(crate) fn encrypt<R>(
+    rng: &mut R,
+    magic: [u8; 4],
+    plaintext: &[u8],
+    password: &str,
+    params: &Argon2Params,
+) -> Result<Vec<u8>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    check_ceiling(params.m_cost, params.t_cost, params.p_cost)?;
+
+    let salt = gen_random_bytes(rng, SALT_LEN)?;
+    let nonce = gen_random_bytes(rng, NONCE_LEN)?;
+
+    let mut sealed = Vec::with_capacity(HEADER_LEN + plaintext.len() + TAG_LEN);
+    sealed.extend_from_slice(&magic);
+    sealed.push(VERSION);
+    sealed.push(KDF_ARGON2ID);
+    sealed.extend_from_slice(&params.m_cost.to_be_bytes());
+    sealed.extend_from_slice(&params.t_cost.to_be_bytes());
+    sealed.extend_from_slice(&params.p_cost.to_be_bytes());
+    sealed.extend_from_slice(&salt);
+    sealed.extend_from_slice(&nonce);
+
+    let key = stretch(password, &salt, params)?;
+    let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+        .encrypt(
+            XNonce::from_slice(&nonce),
+            Payload {
+                msg: plaintext,
+                aad: &sealed,
+            },
+        )
+        .expect("the plaintext is short enough for XChaCha20-Poly1305");
+
+    sealed.extend_from_slice(&ciphertext);
+
+    Ok(sealed)
+}
+
+/// Decrypt the plaintext encrypted under `password` by `encrypt()` with `magic`
+///
+/// Returns `malformed` if `sealed` is too short or doesn't start with `magic`.
+pub(crate) fn decrypt(
+    sealed: &[u8],
+    magic: [u8; 4],
+    password: &str,
+    malformed: Error,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    if sealed.len() < HEADER_LEN + TAG_LEN || sealed[..4] != magic {
+        return Err(malformed);
+    }
+    if sealed[4] != VERSION {
+        return Err(Error::UnsupportedVersion(sealed[4]));
+    }
+    if sealed[5] != KDF_ARGON2ID {
+        return Err(Error::InvalidKdfParams);
+    }
+
+    let (header, ciphertext) = sealed.split_at(HEADER_LEN);
+    let cost = |at: usize| u32::from_be_bytes(header[at..at + 4].try_into().unwrap());
+    check_ceiling(cost(6), cost(10), cost(14))?;
+    let params = Argon2Params::new(cost(6), cost(10), cost(14))?;
+    let salt = &header[18..18 + SALT_LEN];
+    let nonce = &header[18 + SALT_LEN..];
+
+    let key = stretch(password, salt, &params)?;
+    let plaintext = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| Error::InvalidPassphrase)?;
+
+    Ok(Zeroizing::new(plaintext))
+}
+
+/// Reject parameters above `MAX_M_COST`, `MAX_T_COST` or `MAX_P_COST`
+fn check_ceiling(m_cost: u32, t_cost: u32, p_cost: u32) -> Result<(), Error> {
+    if m_cost > MAX_M_COST || t_cost > MAX_T_COST || p_cost > MAX_P_COST {
+        return Err(Error::InvalidKdfParams);
+    }
+
+    Ok(())
+}
+
+/// Stretch the password into the XChaCha20-Poly1305 key
+fn stretch(
+    password: &str,
+    salt: &[u8],
+    params: &Argon2Params,
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let params = argon2::Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32))
+        .map_err(|_| Error::InvalidKdfParams)?;
+    let password = nfkd_zeroizing(password);
+    let mut key = Zeroizing::new([0; 32]);
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key[..])
+        .map_err(|_| Error::InvalidKdfParams)?;
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mnemonic::Mnemonic;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    fn sealed(password: &str) -> Vec<u8> {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let params = Argon2Params::new(64, 1, 1).unwrap();
+
+        mnemonic
+            .seal_with_rng(&mut StdRng::seed_from_u64(0), password, &params)
+            .unwrap()
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn roundtrip() {
+        let sealed = sealed("hunter2");
+
+        assert_eq!(&sealed[..6], b"B39S\x01\x01");
+        assert_eq!(sealed.len(), HEADER_LEN + 1 + 2 + 16 + TAG_LEN);
+
+        let unsealed = Mnemonic::unseal(&sealed, "hunter2").unwrap();
+        assert_eq!(unsealed.phrase(), PHRASE);
+        assert_eq!(unsealed.language(), Language::English);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn errors() {
+        let sealed = sealed("hunter2");
+
+        assert_eq!(
+            Mnemonic::unseal(&sealed, "hunter3"),
+            Err(Error::InvalidPassphrase)
+        );
+
+        // Any change to the parameters, salt, nonce or ciphertext fails authentication
+        for at in [9, 18, HEADER_LEN - 1, sealed.len() - 1] {
+            let mut altered = sealed.clone();
+            altered[at] ^= 1;
+            assert_eq!(
+                Mnemonic::unseal(&altered, "hunter2"),
+                Err(Error::InvalidPassphrase)
+            );
+        }
+
+        let mut newer = sealed.clone();
+        newer[4] = 2;
+        assert_eq!(
+            Mnemonic::unseal(&newer, "hunter2"),
+            Err(Error::UnsupportedVersion(2))
+        );
+
+        assert_eq!(
+            Mnemonic::unseal(&sealed[..HEADER_LEN], "hunter2"),
+            Err(Error::InvalidSealedMnemonic),
+        );
+        assert_eq!(
+            Mnemonic::unseal(b"not sealed", ""),
+            Err(Error::InvalidSealedMnemonic)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn oversized_params() {
+        let sealed = sealed("hunter2");
+
+        // m_cost, t_cost and p_cost, each set to the most a u32 holds
+        for at in [6, 10, 14] {
+            let mut oversized = sealed.clone();
+            oversized[at..at + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+            assert_eq!(
+                Mnemonic::unseal(&oversized, "hunter2"),
+                Err(Error::InvalidKdfParams)
+            );
+        }
+
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let params = Argon2Params::new(MAX_M_COST + 1, 1, 1).unwrap();
+        let result = mnemonic.seal_with_rng(&mut StdRng::seed_from_u64(0), "hunter2", &params);
+        assert_eq!(result, Err(Error::InvalidKdfParams));
+    }
+}
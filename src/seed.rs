@@ -1,8 +1,245 @@
-use std::fmt;
-use unicode_normalization::UnicodeNormalization;
-use zeroize::{Zeroize, Zeroizing};
-use crate::crypto::pbkdf2;
+#[cfg(feature = "bip32")]
+use crate::bip32::{DerivationPath, Ed25519PrivKey, ExtendedPrivKey, Fingerprint, Network};
+use crate::crypto::SEED_BYTES;
+use crate::crypto::{derive_seed, pbkdf2, pbkdf2_with_progress, PBKDF2_ROUNDS};
+use crate::crypto::{CryptoBackend, DefaultCrypto};
+use crate::encoding;
+use crate::error::Error;
+#[cfg(feature = "ethereum")]
+use crate::ethereum::EthereumKey;
+#[cfg(feature = "mlock")]
+use crate::locked::LockedBytes;
 use crate::mnemonic::Mnemonic;
+#[cfg(feature = "slip21")]
+use crate::slip21::Slip21Node;
+use crate::util::nfkd_zeroizing;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::NonZeroU32;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, Zeroizing};
+
+const BIP39_SALT_PREFIX: &str = "mnemonic";
+
+/// The bytes of a seed, in locked memory with the `mlock` feature
+#[cfg(feature = "mlock")]
+type SeedBytes = LockedBytes;
+#[cfg(not(feature = "mlock"))]
+type SeedBytes = Vec<u8>;
+
+/// The HMAC used as the pseudorandom function of PBKDF2, see
+/// [`SeedDerivationParams`][SeedDerivationParams]
+///
+/// [SeedDerivationParams]: ./seed/struct.SeedDerivationParams.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeedHmac {
+    /// HMAC-SHA512, as specified by BIP39
+    #[default]
+    Sha512,
+    /// HMAC-SHA256
+    Sha256,
+}
+
+/// Parameters of the PBKDF2 key derivation that turns a [`Mnemonic`][Mnemonic] into a
+/// [`Seed`][Seed]
+///
+/// The [`Default`][Default] is the BIP39 standard of 2048 rounds of HMAC-SHA512. Fewer rounds can
+/// speed up tests, more make brute forcing the passphrase slower, but a seed derived with anything
+/// but the default won't match the one other wallets derive from the same phrase.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, Language, SeedDerivationParams};
+/// use std::num::NonZeroU32;
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+///
+/// let params = SeedDerivationParams::bip39().rounds(NonZeroU32::new(100_000).unwrap());
+/// let seed = mnemonic.to_seed_with_params("", &params);
+///
+/// assert_ne!(seed, mnemonic.to_seed(""));
+/// ```
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [Seed]: ./seed/struct.Seed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedDerivationParams {
+    pub(crate) rounds: NonZeroU32,
+    pub(crate) hmac: SeedHmac,
+    pub(crate) salt_prefix: &'static str,
+}
+
+impl SeedDerivationParams {
+    /// The parameters specified by BIP39, 2048 rounds of HMAC-SHA512, salted with `"mnemonic"`
+    pub fn bip39() -> SeedDerivationParams {
+        SeedDerivationParams {
+            rounds: PBKDF2_ROUNDS,
+            hmac: SeedHmac::Sha512,
+            salt_prefix: BIP39_SALT_PREFIX,
+        }
+    }
+
+    /// The number of PBKDF2 rounds (2048 by default), which can't be zero
+    pub fn rounds(mut self, rounds: NonZeroU32) -> SeedDerivationParams {
+        self.rounds = rounds;
+        self
+    }
+
+    /// The HMAC used by PBKDF2 (HMAC-SHA512 by default)
+    pub fn hmac(mut self, hmac: SeedHmac) -> SeedDerivationParams {
+        self.hmac = hmac;
+        self
+    }
+
+    /// The prefix of the salt, which is followed by the password (`"mnemonic"` by default)
+    ///
+    /// Electrum seeds, for example, use `"electrum"` instead.
+    pub fn salt_prefix(mut self, salt_prefix: &'static str) -> SeedDerivationParams {
+        self.salt_prefix = salt_prefix;
+        self
+    }
+
+    /// Whether these are the standard BIP39 parameters
+    pub fn is_bip39(&self) -> bool {
+        *self == SeedDerivationParams::bip39()
+    }
+}
+
+impl Default for SeedDerivationParams {
+    fn default() -> SeedDerivationParams {
+        SeedDerivationParams::bip39()
+    }
+}
+
+/// The key derivation function that turns a [`Mnemonic`][Mnemonic] into a [`Seed`][Seed]
+///
+/// The [`Default`][Default] is PBKDF2 with the standard BIP39 parameters, which is the only choice
+/// compatible with other BIP39 wallets. The others exist for wallets that went their own way.
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [Seed]: ./seed/struct.Seed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeedKdf {
+    /// PBKDF2, as specified by BIP39 when used with [`SeedDerivationParams::bip39()`][bip39]
+    ///
+    /// [bip39]: ./seed/struct.SeedDerivationParams.html#method.bip39
+    Pbkdf2(SeedDerivationParams),
+    /// scrypt, as used instead of PBKDF2 by some altcoin wallets, enabled with the `scrypt`
+    /// feature
+    #[cfg(feature = "scrypt")]
+    Scrypt(ScryptParams),
+    /// Argon2id, enabled with the `argon2` feature
+    ///
+    /// This is **not** a BIP39 seed, no wallet will derive the same one from the phrase. It's meant
+    /// for application secrets, where a memory-hard function makes brute forcing the passphrase
+    /// more costly.
+    #[cfg(feature = "argon2")]
+    Argon2id(Argon2Params),
+}
+
+impl SeedKdf {
+    /// The salt is this prefix followed by the password
+    fn salt_prefix(&self) -> &'static str {
+        match self {
+            SeedKdf::Pbkdf2(params) => params.salt_prefix,
+            #[allow(unreachable_patterns)]
+            _ => BIP39_SALT_PREFIX,
+        }
+    }
+}
+
+impl Default for SeedKdf {
+    fn default() -> SeedKdf {
+        SeedKdf::Pbkdf2(SeedDerivationParams::bip39())
+    }
+}
+
+/// Parameters of scrypt, for [`SeedKdf::Scrypt`][SeedKdf::Scrypt]
+///
+/// The [`Default`][Default] is `log_n = 17`, `r = 8` and `p = 1`, as recommended by the `scrypt`
+/// crate. Wallets using scrypt each pick their own, so check which ones yours uses.
+///
+/// [SeedKdf::Scrypt]: ./seed/enum.SeedKdf.html#variant.Scrypt
+#[cfg(feature = "scrypt")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryptParams {
+    pub(crate) log_n: u8,
+    pub(crate) r: u32,
+    pub(crate) p: u32,
+}
+
+#[cfg(feature = "scrypt")]
+impl ScryptParams {
+    /// Create scrypt parameters, with a cost of `2^log_n`, a block size of `r` and a
+    /// parallelization of `p`
+    ///
+    /// Returns `Error::InvalidKdfParams` if scrypt doesn't accept them, e.g. `r` or `p` is `0`, or
+    /// the memory needed overflows.
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<ScryptParams, Error> {
+        scrypt::Params::new(log_n, r, p, SEED_BYTES).map_err(|_| Error::InvalidKdfParams)?;
+
+        Ok(ScryptParams { log_n, r, p })
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl Default for ScryptParams {
+    fn default() -> ScryptParams {
+        ScryptParams {
+            log_n: scrypt::Params::RECOMMENDED_LOG_N,
+            r: scrypt::Params::RECOMMENDED_R,
+            p: scrypt::Params::RECOMMENDED_P,
+        }
+    }
+}
+
+/// Parameters of Argon2id, for [`SeedKdf::Argon2id`][SeedKdf::Argon2id]
+///
+/// The [`Default`][Default] is 19 MiB of memory, 2 iterations and 1 degree of parallelism, as
+/// recommended by OWASP and the `argon2` crate.
+///
+/// [SeedKdf::Argon2id]: ./seed/enum.SeedKdf.html#variant.Argon2id
+#[cfg(feature = "argon2")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub(crate) m_cost: u32,
+    pub(crate) t_cost: u32,
+    pub(crate) p_cost: u32,
+}
+
+#[cfg(feature = "argon2")]
+impl Argon2Params {
+    /// Create Argon2id parameters, using `m_cost` KiB of memory, `t_cost` iterations and `p_cost`
+    /// lanes
+    ///
+    /// Returns `Error::InvalidKdfParams` if Argon2 doesn't accept them, e.g. `t_cost` is `0`, or
+    /// `m_cost` is less than 8 KiB per lane.
+    pub fn new(m_cost: u32, t_cost: u32, p_cost: u32) -> Result<Argon2Params, Error> {
+        argon2::Params::new(m_cost, t_cost, p_cost, Some(SEED_BYTES))
+            .map_err(|_| Error::InvalidKdfParams)?;
+
+        Ok(Argon2Params {
+            m_cost,
+            t_cost,
+            p_cost,
+        })
+    }
+}
+
+#[cfg(feature = "argon2")]
+impl Default for Argon2Params {
+    fn default() -> Argon2Params {
+        Argon2Params {
+            m_cost: argon2::Params::DEFAULT_M_COST,
+            t_cost: argon2::Params::DEFAULT_T_COST,
+            p_cost: argon2::Params::DEFAULT_P_COST,
+        }
+    }
+}
 
 /// The secret value used to derive HD wallet addresses from a [`Mnemonic`][Mnemonic] phrase.
 ///
@@ -11,8 +248,8 @@ use crate::mnemonic::Mnemonic;
 /// a valid, intact mnemonic phrase can be used to derive HD wallet addresses.
 ///
 /// To get the raw byte value use [`Seed::as_bytes()`][Seed::as_bytes()]. These can be used to derive
-/// HD wallet addresses using another crate (deriving HD wallet addresses is outside the scope of this
-/// crate and the BIP39 standard).
+/// HD wallet addresses using another crate, or with `Seed::to_root_xprv()` and
+/// `Seed::derive_path()`, with the `bip32` feature.
 ///
 /// [`Seed`][Seed] implements [`Zeroize`][Zeroize], so it's bytes will be zeroed when it's dropped.
 ///
@@ -23,7 +260,7 @@ use crate::mnemonic::Mnemonic;
 #[derive(Clone, Zeroize)]
 #[zeroize(drop)]
 pub struct Seed {
-    bytes: Vec<u8>,
+    bytes: SeedBytes,
 }
 
 impl Seed {
@@ -31,17 +268,555 @@ impl Seed {
     ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
     pub fn new(mnemonic: &Mnemonic, password: &str) -> Self {
-        let salt = Zeroizing::new(format!("mnemonic{}", password));
-        let normalized_salt = Zeroizing::new(salt.nfkd().to_string());
-        let bytes = pbkdf2(mnemonic.phrase().as_bytes(), &normalized_salt);
+        Seed::new_with_params(mnemonic, password, &SeedDerivationParams::bip39())
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password, with PBKDF2 set up
+    /// by `params`
+    ///
+    /// **Note:** only a seed derived with [`SeedDerivationParams::bip39()`][bip39], the default,
+    /// is compatible with other BIP39 wallets.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [bip39]: ./seed/struct.SeedDerivationParams.html#method.bip39
+    pub fn new_with_params(
+        mnemonic: &Mnemonic,
+        password: &str,
+        params: &SeedDerivationParams,
+    ) -> Self {
+        Seed::new_with_kdf(mnemonic, password, &SeedKdf::Pbkdf2(*params))
+    }
+
+    /// The same as [`Seed::new_with_params()`][Seed::new_with_params()], with PBKDF2 run by
+    /// `backend`
+    ///
+    /// [Seed::new_with_params()]: ./seed/struct.Seed.html#method.new_with_params
+    pub fn new_with_backend<B: CryptoBackend + ?Sized>(
+        mnemonic: &Mnemonic,
+        password: &str,
+        params: &SeedDerivationParams,
+        backend: &B,
+    ) -> Self {
+        let (phrase, salt) = Seed::normalize(mnemonic, password, params.salt_prefix);
+        let bytes = pbkdf2(backend, phrase.as_bytes(), &salt, params);
+
+        Seed::from_bytes(bytes)
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password, using `kdf`
+    ///
+    /// The phrase and the salt, `"mnemonic"` (or the prefix set in the
+    /// [`SeedDerivationParams`][SeedDerivationParams]) followed by the password, are NFKD
+    /// normalized as BIP39 specifies, whatever the key derivation function.
+    ///
+    /// **Note:** only a seed derived with the default [`SeedKdf`][SeedKdf] is compatible with
+    /// other BIP39 wallets.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [SeedKdf]: ./seed/enum.SeedKdf.html
+    /// [SeedDerivationParams]: ./seed/struct.SeedDerivationParams.html
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(words = mnemonic.word_count(), kdf = ?kdf)
+        )
+    )]
+    pub fn new_with_kdf(mnemonic: &Mnemonic, password: &str, kdf: &SeedKdf) -> Self {
+        let (phrase, salt) = Seed::normalize(mnemonic, password, kdf.salt_prefix());
+        let bytes = derive_seed(&DefaultCrypto, phrase.as_bytes(), &salt, kdf);
+
+        Seed::from_bytes(bytes)
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password, with PBKDF2 set up
+    /// by `params`, calling `progress` with the number of rounds done and the total number of
+    /// rounds every so often
+    ///
+    /// This is for showing a progress bar while deriving the seed takes a while, with many
+    /// rounds or on a slow CPU. The seed is the same as
+    /// [`Seed::new_with_params()`][Seed::new_with_params()] gives. With the `ring` feature, it's
+    /// still derived with the PBKDF2 of RustCrypto, as ring only returns once all the rounds are
+    /// done, so it may take longer than without progress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, Seed, SeedDerivationParams};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let params = SeedDerivationParams::bip39();
+    ///
+    /// let mut reported = Vec::new();
+    /// let seed = Seed::new_with_progress(&mnemonic, "", &params, |done, total| {
+    ///     reported.push((done, total));
+    /// });
+    ///
+    /// assert_eq!(reported.last(), Some(&(2048, 2048)));
+    /// assert_eq!(seed, Seed::new(&mnemonic, ""));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new_with_params()]: ./seed/struct.Seed.html#method.new_with_params
+    pub fn new_with_progress<F>(
+        mnemonic: &Mnemonic,
+        password: &str,
+        params: &SeedDerivationParams,
+        mut progress: F,
+    ) -> Self
+    where
+        F: FnMut(u64, u64),
+    {
+        let (phrase, salt) = Seed::normalize(mnemonic, password, params.salt_prefix);
+        let bytes = pbkdf2_with_progress(
+            &DefaultCrypto,
+            phrase.as_bytes(),
+            &salt,
+            params,
+            &mut progress,
+        );
+
+        Seed::from_bytes(bytes)
+    }
+
+    /// The NFKD normalized phrase of `mnemonic`, and salt made of `prefix` and `password`
+    fn normalize(
+        mnemonic: &Mnemonic,
+        password: &str,
+        prefix: &str,
+    ) -> (Zeroizing<String>, Zeroizing<String>) {
+        let mut salt = Zeroizing::new(String::with_capacity(prefix.len() + password.len()));
+        salt.push_str(prefix);
+        salt.push_str(password);
+
+        // The phrase is already made of NFKD words, but the separator needs normalizing as well,
+        // the ideographic space used by Japanese phrases decomposes into an ASCII space.
+        (nfkd_zeroizing(mnemonic.phrase()), nfkd_zeroizing(&salt))
+    }
+
+    /// Generates the seeds of many [`Mnemonic`][Mnemonic] and password pairs, in the same order
+    ///
+    /// PBKDF2 is CPU bound, so with the `rayon` feature the seeds are derived in parallel, on
+    /// rayon's global thread pool. Every seed is the same as [`Seed::new()`][Seed::new()] gives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seeds = Seed::new_batch(&[(&mnemonic, ""), (&mnemonic, "password")]);
+    ///
+    /// assert_eq!(seeds[1], Seed::new(&mnemonic, "password"));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/struct.Seed.html#method.new
+    pub fn new_batch(pairs: &[(&Mnemonic, &str)]) -> Vec<Seed> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            pairs
+                .par_iter()
+                .map(|&(mnemonic, password)| Seed::new(mnemonic, password))
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            pairs
+                .iter()
+                .map(|&(mnemonic, password)| Seed::new(mnemonic, password))
+                .collect()
+        }
+    }
+
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Self {
+        #[cfg(feature = "mlock")]
+        let bytes = LockedBytes::from(bytes);
 
         Self { bytes }
     }
 
+    /// Parse a seed exported with [`Seed::to_base58check()`][Seed::to_base58check()], which must
+    /// start with `version`
+    ///
+    /// Returns `Error::InvalidEncoding` if `string` isn't Base58Check, or has another version, and
+    /// `Error::InvalidKeysize` with the number of bits if the seed isn't 64 bytes long.
+    ///
+    /// [Seed::to_base58check()]: ./seed/struct.Seed.html#method.to_base58check
+    pub fn from_base58check(string: &str, version: &[u8]) -> Result<Seed, Error> {
+        Seed::from_decoded(encoding::from_base58check(string, version)?)
+    }
+
+    /// Parse a seed exported with [`Seed::to_bech32()`][Seed::to_bech32()], under the human
+    /// readable part `hrp`
+    ///
+    /// Returns `Error::InvalidEncoding` if `string` isn't bech32, or has another human readable
+    /// part, and `Error::InvalidKeysize` with the number of bits if the seed isn't 64 bytes long.
+    ///
+    /// [Seed::to_bech32()]: ./seed/struct.Seed.html#method.to_bech32
+    pub fn from_bech32(string: &str, hrp: &str) -> Result<Seed, Error> {
+        Seed::from_decoded(encoding::from_bech32(string, hrp)?)
+    }
+
+    fn from_decoded(bytes: Zeroizing<Vec<u8>>) -> Result<Seed, Error> {
+        if bytes.len() != SEED_BYTES {
+            return Err(Error::InvalidKeysize(bytes.len() * 8));
+        }
+
+        Ok(Seed::from_bytes(bytes.to_vec()))
+    }
+
+    /// Derive the BIP32 master key of the seed, serialized as an `xprv` (or `tprv`) for `network`
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::bip32::Network;
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let xprv = mnemonic.to_seed("").to_root_xprv(Network::Mainnet).unwrap();
+    ///
+    /// assert!(xprv.starts_with("xprv9s21ZrQH143K"));
+    /// ```
+    #[cfg(feature = "bip32")]
+    pub fn to_root_xprv(&self, network: Network) -> Result<Zeroizing<String>, Error> {
+        Ok(ExtendedPrivKey::new_master(&self.bytes, &DefaultCrypto)?.to_master_xprv(network))
+    }
+
+    /// Get the fingerprint of the BIP32 master key of the seed
+    ///
+    /// Showing it lets users check they typed the right phrase and passphrase, as any mistake
+    /// gives a different fingerprint, without deriving addresses. Returns
+    /// `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let fingerprint = mnemonic.to_seed("").fingerprint().unwrap();
+    ///
+    /// assert_eq!(fingerprint.to_string(), "73c5da0a");
+    /// ```
+    #[cfg(feature = "bip32")]
+    pub fn fingerprint(&self) -> Result<Fingerprint, Error> {
+        Ok(ExtendedPrivKey::new_master(&self.bytes, &DefaultCrypto)?.fingerprint())
+    }
+
+    /// Derive the private key and chain code along a BIP32 derivation `path`, such as the BIP44
+    /// path `m/44'/0'/0'/0/0`
+    ///
+    /// See [`DerivationPath`][DerivationPath] for the syntax of the path, an invalid one returns
+    /// `Error::InvalidDerivationPath`. `Error::InvalidDerivedKey` is returned if a key along the
+    /// path is invalid, which happens for one in 2^127 keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let key = mnemonic.to_seed("").derive_path("m/44'/0'/0'/0/0").unwrap();
+    ///
+    /// assert_eq!(key.private_key()[..4], [0xe2, 0x84, 0x12, 0x9c]);
+    /// ```
+    ///
+    /// [DerivationPath]: ./bip32/struct.DerivationPath.html
+    #[cfg(feature = "bip32")]
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedPrivKey, Error> {
+        self.derive_path_with_backend(path, &DefaultCrypto)
+    }
+
+    /// The same as [`Seed::derive_path()`][Seed::derive_path()], with the HMACs of BIP32 run by
+    /// `backend`
+    ///
+    /// [Seed::derive_path()]: ./seed/struct.Seed.html#method.derive_path
+    #[cfg(feature = "bip32")]
+    pub fn derive_path_with_backend<B: CryptoBackend + ?Sized>(
+        &self,
+        path: &str,
+        backend: &B,
+    ) -> Result<ExtendedPrivKey, Error> {
+        let path: DerivationPath = path.parse()?;
+
+        ExtendedPrivKey::new_master(&self.bytes, backend)?.derive_path(&path, backend)
+    }
+
+    /// Derive an ed25519 private key and chain code along a SLIP-0010 derivation `path`, such as
+    /// `m/44'/501'/0'/0'` for Solana
+    ///
+    /// Only hardened indices can be used with ed25519 keys, any other index, or an invalid path,
+    /// returns `Error::InvalidDerivationPath`. The private key is the 32 byte ed25519 secret key
+    /// that the public key is computed from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let key = mnemonic.to_seed("").derive_ed25519("m/44'/501'/0'/0'").unwrap();
+    ///
+    /// assert_eq!(key.private_key().len(), 32);
+    /// ```
+    #[cfg(feature = "bip32")]
+    pub fn derive_ed25519(&self, path: &str) -> Result<Ed25519PrivKey, Error> {
+        self.derive_ed25519_with_backend(path, &DefaultCrypto)
+    }
+
+    /// The same as [`Seed::derive_ed25519()`][Seed::derive_ed25519()], with the HMACs of
+    /// SLIP-0010 run by `backend`
+    ///
+    /// [Seed::derive_ed25519()]: ./seed/struct.Seed.html#method.derive_ed25519
+    #[cfg(feature = "bip32")]
+    pub fn derive_ed25519_with_backend<B: CryptoBackend + ?Sized>(
+        &self,
+        path: &str,
+        backend: &B,
+    ) -> Result<Ed25519PrivKey, Error> {
+        let path: DerivationPath = path.parse()?;
+
+        Ed25519PrivKey::new_master(&self.bytes, backend).derive_path(&path, backend)
+    }
+
+    /// Derive the BIP32 master key of the seed as a rust-bitcoin [`Xpriv`][Xpriv] for `network`
+    ///
+    /// Returns `Error::InvalidDerivedKey` for the one in 2^127 seeds that have no valid master key.
+    /// Unlike the seed, the `Xpriv` isn't zeroed when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let xpriv = mnemonic.to_seed("").to_xpriv(bitcoin::Network::Bitcoin).unwrap();
+    ///
+    /// assert_eq!(xpriv.depth, 0);
+    /// ```
+    ///
+    /// [Xpriv]: https://docs.rs/bitcoin/0.32/bitcoin/bip32/struct.Xpriv.html
+    #[cfg(feature = "bitcoin")]
+    pub fn to_xpriv(&self, network: bitcoin::Network) -> Result<bitcoin::bip32::Xpriv, Error> {
+        bitcoin::bip32::Xpriv::new_master(network, &self.bytes)
+            .map_err(|_| Error::InvalidDerivedKey)
+    }
+
+    /// Derive the private key and address of Ethereum account `index`, along the BIP44 path
+    /// `m/44'/60'/0'/0/{index}` that MetaMask and most other Ethereum wallets use
+    ///
+    /// Returns `Error::InvalidDerivationPath` if `index` has the hardened bit set, and
+    /// `Error::InvalidDerivedKey` for the one in 2^127 keys along the path that are invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let key = mnemonic.to_seed("").derive_ethereum(0).unwrap();
+    ///
+    /// assert_eq!(key.address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    /// ```
+    #[cfg(feature = "ethereum")]
+    pub fn derive_ethereum(&self, index: u32) -> Result<EthereumKey, Error> {
+        let key = self.derive_path(&alloc::format!("m/44'/60'/0'/0/{}", index))?;
+
+        Ok(EthereumKey::new(&key))
+    }
+
+    /// Derive the SLIP-0021 node along `path`, a list of labels starting from the master node,
+    /// to get symmetric keys such as the one a wallet encrypts its storage with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+    ///
+    /// let key = seed.slip21_node(&["SLIP-0021", "Master encryption key"]).key().to_vec();
+    /// ```
+    #[cfg(feature = "slip21")]
+    pub fn slip21_node(&self, path: &[&str]) -> Slip21Node {
+        self.slip21_node_with_backend(path, &DefaultCrypto)
+    }
+
+    /// The same as [`Seed::slip21_node()`][Seed::slip21_node()], with the HMACs of SLIP-0021
+    /// run by `backend`
+    ///
+    /// [Seed::slip21_node()]: ./seed/struct.Seed.html#method.slip21_node
+    #[cfg(feature = "slip21")]
+    pub fn slip21_node_with_backend<B: CryptoBackend + ?Sized>(
+        &self,
+        path: &[&str],
+        backend: &B,
+    ) -> Slip21Node {
+        let master = Slip21Node::master_with_backend(self, backend);
+
+        path.iter().fold(master, |node, label| {
+            node.derive_with_backend(label, backend)
+        })
+    }
+
     /// Get the seed value as a byte slice
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
+
+    /// Lend the seed to `f` for the duration of the call, and return what it returns
+    ///
+    /// No copy of the seed is made, so nothing is left to zeroize afterwards, and the seed can't
+    /// outlive the closure unless `f` copies it out. This is the safer way to pass the seed to
+    /// code that only needs it for a moment, such as a key derivation function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+    ///
+    /// let first = seed.with_bytes(|bytes| bytes[0]);
+    ///
+    /// assert_eq!(first, seed.as_bytes()[0]);
+    /// ```
+    pub fn with_bytes<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        f(&self.bytes)
+    }
+
+    /// Copy the seed into a 64 byte array, which can stay on the stack
+    pub fn seed_bytes(&self) -> Zeroizing<[u8; SEED_BYTES]> {
+        let mut bytes = Zeroizing::new([0; SEED_BYTES]);
+        bytes.copy_from_slice(&self.bytes);
+
+        bytes
+    }
+
+    /// Copy the first 32 bytes of the seed into an array, which SLIP-0010 and BIP32-style
+    /// derivations use as the key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+    ///
+    /// let (key, chain_code) = (seed.left_half(), seed.right_half());
+    ///
+    /// assert_eq!([&key[..], &chain_code[..]].concat(), seed.as_bytes());
+    /// ```
+    pub fn left_half(&self) -> Zeroizing<[u8; SEED_BYTES / 2]> {
+        self.half(0)
+    }
+
+    /// Copy the last 32 bytes of the seed into an array, which SLIP-0010 and BIP32-style
+    /// derivations use as the chain code
+    pub fn right_half(&self) -> Zeroizing<[u8; SEED_BYTES / 2]> {
+        self.half(SEED_BYTES / 2)
+    }
+
+    fn half(&self, start: usize) -> Zeroizing<[u8; SEED_BYTES / 2]> {
+        let mut half = Zeroizing::new([0; SEED_BYTES / 2]);
+        half.copy_from_slice(&self.bytes[start..start + SEED_BYTES / 2]);
+
+        half
+    }
+
+    /// Encode `version` followed by the seed with Base58Check, for formats that expect it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+    ///
+    /// let encoded = seed.to_base58check(&[0x80]);
+    ///
+    /// assert_eq!(Seed::from_base58check(&encoded, &[0x80]).unwrap(), seed);
+    /// ```
+    pub fn to_base58check(&self, version: &[u8]) -> Zeroizing<String> {
+        encoding::to_base58check(version, &self.bytes)
+    }
+
+    /// Encode the seed with bech32, as in BIP173, under the human readable part `hrp`
+    ///
+    /// The output is lowercase, and, at 103 characters plus `hrp`, longer than the 90 characters
+    /// BIP173 allows for addresses. Returns `Error::InvalidEncoding` if `hrp` is empty, or has
+    /// uppercase or non-printable ASCII characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+    ///
+    /// let encoded = seed.to_bech32("seed").unwrap();
+    ///
+    /// assert!(encoded.starts_with("seed1"));
+    /// assert_eq!(Seed::from_bech32(&encoded, "seed").unwrap(), seed);
+    /// ```
+    pub fn to_bech32(&self, hrp: &str) -> Result<Zeroizing<String>, Error> {
+        encoding::to_bech32(hrp, &self.bytes)
+    }
+
+    /// Move the seed into a [`SecretBox`][SecretBox], so the bytes can only be reached through
+    /// [`ExposeSecret::expose_secret()`][ExposeSecret::expose_secret()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    /// use secrecy::ExposeSecret;
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = mnemonic.to_seed("").into_secret();
+    ///
+    /// assert_eq!(seed.expose_secret().as_bytes().len(), 64);
+    /// ```
+    ///
+    /// [SecretBox]: https://docs.rs/secrecy/latest/secrecy/struct.SecretBox.html
+    /// [ExposeSecret::expose_secret()]: https://docs.rs/secrecy/latest/secrecy/trait.ExposeSecret.html#tymethod.expose_secret
+    #[cfg(feature = "secrecy")]
+    pub fn into_secret(self) -> secrecy::SecretBox<Seed> {
+        secrecy::SecretBox::new(alloc::boxed::Box::new(self))
+    }
 }
 
 impl AsRef<[u8]> for Seed {
@@ -50,9 +825,25 @@ impl AsRef<[u8]> for Seed {
     }
 }
 
+/// Compares the seed bytes in constant time.
+impl ConstantTimeEq for Seed {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+impl PartialEq for Seed {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Seed {}
+
+/// The seed is a secret, so it's never printed, use hex formatting to get it explicitly.
 impl fmt::Debug for Seed {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:#X}", self)
+        f.write_str("Seed(****)")
     }
 }
 
@@ -62,7 +853,7 @@ impl fmt::LowerHex for Seed {
             f.write_str("0x")?;
         }
 
-        for byte in &self.bytes {
+        for byte in self.bytes.iter() {
             write!(f, "{:02x}", byte)?;
         }
 
@@ -76,7 +867,7 @@ impl fmt::UpperHex for Seed {
             f.write_str("0x")?;
         }
 
-        for byte in &self.bytes {
+        for byte in self.bytes.iter() {
             write!(f, "{:02X}", byte)?;
         }
 
@@ -108,7 +899,252 @@ mod test {
         assert_eq!(format!("{:#X}", seed), "0x0BDE96F14C35A66235478E0C16C152FCAF6301E4D9A81D3FEBC50879FE7E5438E6A8DD3E39BDF3AB7B12D6B44218710E17D7A2844EE9633FAB0E03D9A6C8569B");
     }
 
-    fn test_unicode_normalization(lang: Language, phrase: &str, password: &str, expected_seed_hex: &str) {
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_encodings() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password");
+
+        let base58check = seed.to_base58check(&[0x04, 0x88]);
+        assert_eq!(
+            Seed::from_base58check(&base58check, &[0x04, 0x88]).unwrap(),
+            seed
+        );
+        assert_eq!(
+            Seed::from_base58check(&base58check, &[0x04]).map(|_| ()),
+            Err(Error::InvalidKeysize(520))
+        );
+
+        let bech32 = seed.to_bech32("seed").unwrap();
+        assert_eq!(bech32.len(), 5 + 103 + 6);
+        assert_eq!(Seed::from_bech32(&bech32, "seed").unwrap(), seed);
+        assert_eq!(
+            Seed::from_bech32(&mnemonic.entropy_bech32("seed").unwrap(), "seed").map(|_| ()),
+            Err(Error::InvalidKeysize(128))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_eq() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            Seed::new(&mnemonic, "password"),
+            Seed::new(&mnemonic, "password")
+        );
+        assert_ne!(Seed::new(&mnemonic, "password"), Seed::new(&mnemonic, ""));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn new_batch() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let passwords = ["", "password", "TREZOR", "パスワード"];
+
+        let pairs: Vec<_> = passwords
+            .iter()
+            .map(|&password| (&mnemonic, password))
+            .collect();
+        let seeds = Seed::new_batch(&pairs);
+
+        assert_eq!(seeds.len(), passwords.len());
+        for (seed, password) in seeds.iter().zip(passwords) {
+            assert_eq!(seed, &Seed::new(&mnemonic, password));
+        }
+        assert!(Seed::new_batch(&[]).is_empty());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn new_with_progress() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let mut reported = Vec::new();
+        let seed =
+            mnemonic.to_seed_with_progress("TREZOR", |done, total| reported.push((done, total)));
+        assert_eq!(seed, Seed::new(&mnemonic, "TREZOR"));
+        assert_eq!(reported, [(1024, 2048), (2048, 2048)]);
+
+        // The 64 bytes of the seed are two SHA256 blocks
+        let params = SeedDerivationParams::bip39()
+            .hmac(SeedHmac::Sha256)
+            .rounds(NonZeroU32::new(1000).unwrap());
+        let mut reported = Vec::new();
+        let seed = Seed::new_with_progress(&mnemonic, "TREZOR", &params, |done, total| {
+            reported.push((done, total))
+        });
+        assert_eq!(seed, Seed::new_with_params(&mnemonic, "TREZOR", &params));
+        assert_eq!(reported, [(1000, 2000), (2000, 2000)]);
+    }
+
+    // Only the seed without progress is derived by ring
+    #[cfg(feature = "ring")]
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn new_with_progress_matches_ring() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        for hmac in [SeedHmac::Sha512, SeedHmac::Sha256] {
+            for rounds in [1, 2048, 5000] {
+                let rounds = NonZeroU32::new(rounds).unwrap();
+                let params = SeedDerivationParams::bip39().hmac(hmac).rounds(rounds);
+
+                let seed = Seed::new_with_progress(&mnemonic, "TREZOR", &params, |_, _| ());
+                assert_eq!(seed, Seed::new_with_params(&mnemonic, "TREZOR", &params));
+            }
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_bytes() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let seed = Seed::new(
+            &Mnemonic::from_phrase(phrase, Language::English).unwrap(),
+            "",
+        );
+
+        assert_eq!(&seed.seed_bytes()[..], seed.as_bytes());
+        assert!(seed.with_bytes(|bytes| bytes == seed.as_bytes()));
+        assert_eq!(&seed.left_half()[..], &seed.as_bytes()[..32]);
+        assert_eq!(&seed.right_half()[..], &seed.as_bytes()[32..]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_debug_is_redacted() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password");
+
+        assert_eq!(format!("{:?}", seed), "Seed(****)");
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn seed_with_params() {
+        use hmac::{Hmac, Mac};
+
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let bip39 = Seed::new_with_params(&mnemonic, "password", &SeedDerivationParams::default());
+        assert_eq!(bip39, Seed::new(&mnemonic, "password"));
+        assert!(SeedDerivationParams::default().is_bip39());
+
+        // A single round of PBKDF2 is a single HMAC of the salt and the block index
+        let params = SeedDerivationParams::bip39().rounds(NonZeroU32::MIN);
+        let mut mac = Hmac::<sha2::Sha512>::new_from_slice(phrase.as_bytes()).unwrap();
+        mac.update(b"mnemonicpassword\x00\x00\x00\x01");
+
+        let seed = Seed::new_with_params(&mnemonic, "password", &params);
+        assert_eq!(seed.as_bytes(), &mac.finalize().into_bytes()[..]);
+        assert!(!params.is_bip39());
+
+        let params = SeedDerivationParams::bip39().salt_prefix("electrum");
+        let mut mac = Hmac::<sha2::Sha512>::new_from_slice(phrase.as_bytes()).unwrap();
+        mac.update(b"electrumpassword\x00\x00\x00\x01");
+
+        let seed = Seed::new_with_params(&mnemonic, "password", &params.rounds(NonZeroU32::MIN));
+        assert_eq!(seed.as_bytes(), &mac.finalize().into_bytes()[..]);
+        assert!(!params.is_bip39());
+
+        let params = SeedDerivationParams::bip39().hmac(SeedHmac::Sha256);
+        let seed = Seed::new_with_params(&mnemonic, "password", &params);
+        assert_eq!(seed.as_bytes().len(), 64);
+        assert_ne!(seed, bip39);
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "std")]
+    fn calibrate_kdf() {
+        use std::time::Duration;
+
+        let rounds = crate::calibrate_kdf(Duration::from_millis(20));
+        assert!(rounds >= PBKDF2_ROUNDS);
+        assert!(crate::calibrate_kdf(Duration::from_millis(200)) > rounds);
+
+        assert_eq!(crate::calibrate_kdf(Duration::ZERO), PBKDF2_ROUNDS);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "scrypt")]
+    fn seed_with_scrypt() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let kdf = SeedKdf::Scrypt(ScryptParams::new(10, 8, 1).unwrap());
+        let seed = Seed::new_with_kdf(&mnemonic, "password", &kdf);
+
+        let mut expected = [0u8; 64];
+        let params = scrypt::Params::new(10, 8, 1, 64).unwrap();
+        scrypt::scrypt(
+            phrase.as_bytes(),
+            b"mnemonicpassword",
+            &params,
+            &mut expected,
+        )
+        .unwrap();
+
+        assert_eq!(seed.as_bytes(), &expected[..]);
+        assert_ne!(seed, Seed::new(&mnemonic, "password"));
+        assert_eq!(ScryptParams::new(10, 0, 1), Err(Error::InvalidKdfParams));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "argon2")]
+    fn seed_with_argon2id() {
+        use argon2::{Algorithm, Argon2, Version};
+
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let kdf = SeedKdf::Argon2id(Argon2Params::new(64, 1, 1).unwrap());
+        let seed = Seed::new_with_kdf(&mnemonic, "password", &kdf);
+
+        let mut expected = [0u8; 64];
+        let params = argon2::Params::new(64, 1, 1, Some(64)).unwrap();
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(phrase.as_bytes(), b"mnemonicpassword", &mut expected)
+            .unwrap();
+
+        assert_eq!(seed.as_bytes(), &expected[..]);
+        assert_ne!(seed, Seed::new(&mnemonic, "password"));
+        assert_eq!(Argon2Params::new(64, 0, 1), Err(Error::InvalidKdfParams));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "bitcoin")]
+    fn seed_to_xpriv() {
+        // The root key of the BIP84 test vectors
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = Mnemonic::from_phrase(phrase, Language::English)
+            .unwrap()
+            .to_seed("");
+
+        let xpriv = seed.to_xpriv(bitcoin::Network::Bitcoin).unwrap();
+        assert_eq!(xpriv.to_string(), "xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVpxR86QEC8w35uxmGoggxtQTPvfUu");
+
+        let tpriv = seed.to_xpriv(bitcoin::Network::Testnet).unwrap();
+        assert!(tpriv.to_string().starts_with("tprv"));
+        assert_eq!(tpriv.private_key, xpriv.private_key);
+    }
+
+    fn test_unicode_normalization(
+        lang: Language,
+        phrase: &str,
+        password: &str,
+        expected_seed_hex: &str,
+    ) {
         let mnemonic = Mnemonic::from_phrase(phrase, lang).unwrap();
         let seed = Seed::new(&mnemonic, password);
         assert_eq!(format!("{:x}", seed), expected_seed_hex);
@@ -139,6 +1175,18 @@ mod test {
         );
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn password_composition_does_not_matter() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let composed = Seed::new(&mnemonic, "caf\u{e9}");
+        let decomposed = Seed::new(&mnemonic, "cafe\u{301}");
+
+        assert_eq!(composed.as_bytes(), decomposed.as_bytes());
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     /// https://github.com/bip32JP/bip32JP.github.io/commit/360c05a6439e5c461bbe5e84c7567ec38eb4ac5f
@@ -0,0 +1,165 @@
+//! Convert a [`Mnemonic`][Mnemonic] to and from the payloads of SeedQR codes, as used by
+//! SeedSigner and other air-gapped signers
+//!
+//! A SeedQR holds the indices of the words as a stream of digits, 4 for every word. A
+//! CompactSeedQR holds the raw entropy bytes instead, without the checksum, which makes for a
+//! smaller code.
+//!
+//! ```
+//! use bip39::{seedqr, Language, Mnemonic};
+//!
+//! let phrase = "forum undo fragile fade shy sign arrest garment culture tube off merit";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! let digits = seedqr::to_digits(&mnemonic);
+//! assert_eq!(&digits[..], "073318950739065415961602009907670428187212261116");
+//!
+//! assert_eq!(seedqr::from_digits(&digits, Language::English).unwrap(), mnemonic);
+//!
+//! let compact = seedqr::to_compact(&mnemonic);
+//! assert_eq!(seedqr::from_compact(&compact, Language::English).unwrap(), mnemonic);
+//! ```
+//!
+//! Only English SeedQRs exist in practice, but since they only hold indices, any language works.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+
+use crate::error::{Error, SecretWord};
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use zeroize::Zeroizing;
+
+/// The number of digits of every word index
+const DIGITS_PER_WORD: usize = 4;
+
+/// Convert `mnemonic` to the digit stream of a SeedQR
+pub fn to_digits(mnemonic: &Mnemonic) -> Zeroizing<String> {
+    let indices = mnemonic.to_indices();
+    let mut digits = Zeroizing::new(String::with_capacity(indices.len() * DIGITS_PER_WORD));
+
+    for index in indices.iter() {
+        write!(digits, "{:04}", index).expect("writing to a String doesn't fail");
+    }
+
+    digits
+}
+
+/// Parse the digit stream of a SeedQR into a [`Mnemonic`][Mnemonic] in `lang`
+///
+/// Returns `Error::InvalidWordCount` if the number of digits isn't 4 times a valid word count,
+/// `Error::InvalidWord` with the position of the word and an empty word if its 4 digits aren't
+/// an index of the word list, and `Error::InvalidChecksum` like for a phrase.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+pub fn from_digits(digits: &str, lang: Language) -> Result<Mnemonic, Error> {
+    if !digits.len().is_multiple_of(DIGITS_PER_WORD) {
+        return Err(Error::InvalidWordCount(
+            digits.len().div_ceil(DIGITS_PER_WORD),
+        ));
+    }
+
+    let mut indices = Zeroizing::new(Vec::with_capacity(digits.len() / DIGITS_PER_WORD));
+
+    for (index, chunk) in digits.as_bytes().chunks(DIGITS_PER_WORD).enumerate() {
+        let word_index = chunk.iter().try_fold(0u16, |acc, &digit| match digit {
+            b'0'..=b'9' => Some(acc * 10 + (digit - b'0') as u16),
+            _ => None,
+        });
+
+        match word_index {
+            Some(word_index) if word_index < 2048 => indices.push(word_index),
+            _ => {
+                return Err(Error::InvalidWord {
+                    word: SecretWord::default(),
+                    index,
+                })
+            }
+        }
+    }
+
+    Mnemonic::from_indices(&indices, lang)
+}
+
+/// Convert `mnemonic` to the bytes of a CompactSeedQR, which are its entropy
+pub fn to_compact(mnemonic: &Mnemonic) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(mnemonic.entropy().to_vec())
+}
+
+/// Parse the bytes of a CompactSeedQR into a [`Mnemonic`][Mnemonic] in `lang`
+///
+/// SeedSigner only makes them for 12 and 24 words, 16 and 32 bytes, but all the BIP39 entropy
+/// lengths are accepted. Returns `Error::InvalidKeysize` for any other length.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+pub fn from_compact(bytes: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+    Mnemonic::from_entropy(bytes, lang)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    // Test vector from SeedSigner's SeedQR specification
+    const PHRASE: &str =
+        "attack pizza motion avocado network gather crop fresh patrol unusual wild \
+                          holiday candy pony ranch winter theme error hybrid van cereal salon \
+                          goddess expire";
+    const DIGITS: &str = "011513251154012711900771041507421289190620080870026613431420201617920614089619290300152408010643";
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn digits() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+
+        assert_eq!(&to_digits(&mnemonic)[..], DIGITS);
+        assert_eq!(from_digits(DIGITS, Language::English).unwrap(), mnemonic);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn compact() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let compact = to_compact(&mnemonic);
+
+        assert_eq!(
+            hex::encode(&compact[..]),
+            "0e74b64107f94cc0ccfae6a13dcbec3662154fec67e0e00999c07892597d190a"
+        );
+        assert_eq!(from_compact(&compact, Language::English).unwrap(), mnemonic);
+        assert_eq!(
+            from_compact(&compact[..31], Language::English).map(|_| ()),
+            Err(Error::InvalidKeysize(248))
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_digits() {
+        let invalid_word = |index| Error::InvalidWord {
+            word: SecretWord::default(),
+            index,
+        };
+
+        let parse = |digits: &str| from_digits(digits, Language::English).map(|_| ());
+
+        assert_eq!(parse(&DIGITS[..95]), Err(Error::InvalidWordCount(24)));
+        assert_eq!(parse(&DIGITS[..88]), Err(Error::InvalidWordCount(22)));
+        assert_eq!(
+            parse(&DIGITS.replacen("0115", "2048", 1)),
+            Err(invalid_word(0))
+        );
+        assert_eq!(
+            parse(&DIGITS.replacen("1325", "13 5", 1)),
+            Err(invalid_word(1))
+        );
+        assert_eq!(
+            parse(&DIGITS.replacen("0115", "0116", 1)),
+            Err(Error::InvalidChecksum)
+        );
+    }
+}
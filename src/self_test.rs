@@ -0,0 +1,107 @@
+use crate::error::SelfTestError;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use crate::test_vectors::{TestVector, TREZOR, TREZOR_PASSPHRASE};
+use alloc::vec::Vec;
+
+/// Run a known-answer test of the crate, for instance at power-on in high-assurance deployments
+///
+/// This checks that:
+///
+/// - the word lists of the languages enabled at compile time match the BIP39 reference lists, as
+///   [`Language::verify_integrity()`][verify_integrity] does
+/// - every Trezor reference vector turns its entropy into its phrase, and its phrase and the
+///   passphrase `"TREZOR"` into its seed
+/// - for every enabled language and [`MnemonicType`][MnemonicType], a phrase made from entropy
+///   parses back into the same entropy and derives the same seed
+///
+/// Everything is computed with [`DefaultCrypto`][DefaultCrypto], check another
+/// [`CryptoBackend`][CryptoBackend] against [`test_vectors`][test_vectors]. This takes a few dozen
+/// PBKDF2 derivations of 2048 rounds, which is well under a second on most hardware.
+///
+/// # Example
+///
+/// ```
+/// bip39::self_test().expect("bip39 self-test failed");
+/// ```
+///
+/// [verify_integrity]: ./enum.Language.html#method.verify_integrity
+/// [MnemonicType]: ./mnemonic_type/enum.MnemonicType.html
+/// [DefaultCrypto]: ./struct.DefaultCrypto.html
+/// [CryptoBackend]: ./trait.CryptoBackend.html
+/// [test_vectors]: ./test_vectors/index.html
+pub fn self_test() -> Result<(), SelfTestError> {
+    Language::verify_integrity().map_err(|_| SelfTestError::WordList)?;
+
+    check_vectors(TREZOR)?;
+
+    for lang in Language::all() {
+        for mtype in MnemonicType::all() {
+            check_round_trip(lang, mtype)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_vectors(vectors: &[TestVector]) -> Result<(), SelfTestError> {
+    for (index, vector) in vectors.iter().enumerate() {
+        let mnemonic = Mnemonic::from_entropy(vector.entropy, Language::English)
+            .map_err(|_| SelfTestError::Phrase(index))?;
+        if mnemonic.phrase() != vector.phrase {
+            return Err(SelfTestError::Phrase(index));
+        }
+
+        if Seed::new(&mnemonic, TREZOR_PASSPHRASE).as_bytes() != vector.seed {
+            return Err(SelfTestError::Seed(index));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_round_trip(lang: Language, mtype: MnemonicType) -> Result<(), SelfTestError> {
+    let failed = SelfTestError::RoundTrip {
+        lang,
+        words: mtype.word_count(),
+    };
+
+    let entropy: Vec<u8> = (0..mtype.entropy_len() as u8)
+        .map(|i| i.wrapping_mul(0x9d))
+        .collect();
+    let mnemonic = Mnemonic::from_entropy(&entropy, lang).map_err(|_| failed)?;
+    let parsed = Mnemonic::from_phrase(mnemonic.phrase(), lang).map_err(|_| failed)?;
+
+    if parsed.entropy() != &entropy[..] || parsed.to_seed("") != mnemonic.to_seed("") {
+        return Err(failed);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn wrong_answers() {
+        let mut vectors = [TREZOR[0], TREZOR[1]];
+
+        vectors[1].seed[0] ^= 1;
+        assert_eq!(check_vectors(&vectors), Err(SelfTestError::Seed(1)));
+
+        vectors[1].phrase = TREZOR[2].phrase;
+        assert_eq!(check_vectors(&vectors), Err(SelfTestError::Phrase(1)));
+    }
+}
@@ -0,0 +1,174 @@
+//! Shamir's secret sharing over GF(256), with the digest share of SLIP-0039, which SSKR uses
+//! as well
+
+use crate::crypto::gen_random_bytes;
+use crate::error::Error;
+use alloc::vec;
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+use rand_core::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// The most groups, and the most shares in a group
+pub(crate) const MAX_SHARE_COUNT: usize = 16;
+
+/// The x coordinates of the secret and of its digest, on the polynomial the shares are points of
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LEN: usize = 4;
+
+/// GF(256) with the polynomial of AES, as log and exp tables generated by 3
+const GF256: ([u8; 255], [u8; 256]) = gf256_tables();
+
+const fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+
+        poly ^= poly << 1;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+
+        i += 1;
+    }
+
+    (exp, log)
+}
+
+/// Evaluate at `x` the polynomial through the points, with Lagrange interpolation
+fn interpolate(points: &[(u8, &[u8])], x: u8) -> Zeroizing<Vec<u8>> {
+    let (exp, log) = &GF256;
+
+    if let Some((_, value)) = points.iter().find(|(index, _)| *index == x) {
+        return Zeroizing::new(value.to_vec());
+    }
+
+    let mut result = Zeroizing::new(vec![0u8; points[0].1.len()]);
+
+    for &(xi, value) in points {
+        let mut log_basis = 0usize;
+
+        for &(xj, _) in points.iter().filter(|(xj, _)| *xj != xi) {
+            log_basis += log[(x ^ xj) as usize] as usize;
+            log_basis += 255 - log[(xi ^ xj) as usize] as usize;
+        }
+
+        for (out, &y) in result.iter_mut().zip(value) {
+            if y != 0 {
+                *out ^= exp[(log[y as usize] as usize + log_basis) % 255];
+            }
+        }
+    }
+
+    result
+}
+
+/// The digest that lets a recovered secret be checked, from the random part of the digest share
+fn digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_LEN] {
+    let mut mac =
+        Hmac::<sha2::Sha256>::new_from_slice(random_part).expect("HMAC accepts keys of any size");
+    mac.update(secret);
+
+    let mut digest = [0u8; DIGEST_LEN];
+    digest.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LEN]);
+    digest
+}
+
+/// A shared secret, with its x coordinate
+pub(crate) type IndexedSecret = (u8, Zeroizing<Vec<u8>>);
+
+/// Split `secret` into `count` shares, of which `threshold` are needed to recover it
+pub(crate) fn split_secret<R>(
+    rng: &mut R,
+    threshold: u8,
+    count: usize,
+    secret: &[u8],
+) -> Result<Vec<IndexedSecret>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    if threshold == 0 || threshold as usize > count || count > MAX_SHARE_COUNT {
+        return Err(Error::InvalidThreshold);
+    }
+
+    if threshold == 1 {
+        return Ok((0..count as u8)
+            .map(|index| (index, Zeroizing::new(secret.to_vec())))
+            .collect());
+    }
+
+    let mut shares = Vec::with_capacity(count);
+
+    for index in 0..threshold - 2 {
+        shares.push((index, gen_random_bytes(rng, secret.len())?));
+    }
+
+    let random_part = gen_random_bytes(rng, secret.len() - DIGEST_LEN)?;
+    let mut digest_share = Zeroizing::new(digest(&random_part, secret).to_vec());
+    digest_share.extend_from_slice(&random_part);
+
+    let mut points: Vec<_> = shares
+        .iter()
+        .map(|(index, value)| (*index, &value[..]))
+        .collect();
+    points.push((DIGEST_INDEX, &digest_share));
+    points.push((SECRET_INDEX, secret));
+
+    let computed: Vec<_> = (threshold - 2..count as u8)
+        .map(|index| (index, interpolate(&points, index)))
+        .collect();
+
+    shares.extend(computed);
+
+    Ok(shares)
+}
+
+/// Recover the secret from `threshold` shares, and check it against the digest
+pub(crate) fn recover_secret(
+    threshold: u8,
+    points: &[(u8, &[u8])],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    if threshold == 1 {
+        return Ok(Zeroizing::new(points[0].1.to_vec()));
+    }
+
+    let secret = interpolate(points, SECRET_INDEX);
+    let digest_share = interpolate(points, DIGEST_INDEX);
+
+    let expected = digest(&digest_share[DIGEST_LEN..], &secret);
+
+    if !bool::from(expected.ct_eq(&digest_share[..DIGEST_LEN])) {
+        return Err(Error::MismatchedShares);
+    }
+
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn gf256() {
+        let (exp, log) = &GF256;
+
+        // 3 generates the whole multiplicative group
+        let mut seen = [false; 256];
+        for &value in exp.iter() {
+            assert!(!seen[value as usize]);
+            seen[value as usize] = true;
+            assert_eq!(exp[log[value as usize] as usize], value);
+        }
+        assert!(!seen[0]);
+    }
+}
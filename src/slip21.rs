@@ -0,0 +1,123 @@
+//! SLIP-0021 symmetric key derivation, enabled with the `slip21` feature
+//!
+//! [SLIP-0021](https://github.com/satoshilabs/slips/blob/master/slip-0021.md) derives symmetric
+//! keys from a seed along paths of labels, such as the key a wallet encrypts its local storage
+//! with. Unlike BIP32, every node is hardened, and its key is the second half of the node.
+//!
+//! ```
+//! use bip39::{Language, Mnemonic};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let seed = Mnemonic::from_phrase(phrase, Language::English).unwrap().to_seed("");
+//!
+//! let node = seed.slip21_node(&["SLIP-0021", "Master encryption key"]);
+//!
+//! assert_eq!(node.key().len(), 32);
+//! ```
+
+use crate::crypto::{CryptoBackend, DefaultCrypto};
+use crate::seed::Seed;
+use core::convert::TryInto;
+use core::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The HMAC key of the master node
+const MASTER_KEY: &[u8] = b"Symmetric key seed";
+
+/// A node of the SLIP-0021 tree, the master node of a seed or one of its descendants
+#[derive(Clone, Zeroize)]
+pub struct Slip21Node {
+    bytes: Zeroizing<[u8; 64]>,
+}
+
+impl Slip21Node {
+    /// Derive the master node of `seed`
+    pub fn master(seed: &Seed) -> Slip21Node {
+        Slip21Node::master_with_backend(seed, &DefaultCrypto)
+    }
+
+    /// Derive the master node of `seed`, with the HMAC run by `backend`
+    pub fn master_with_backend<B: CryptoBackend + ?Sized>(seed: &Seed, backend: &B) -> Slip21Node {
+        Slip21Node::hmac(backend, MASTER_KEY, &[seed.as_bytes()])
+    }
+
+    /// Derive the child node of `label`
+    pub fn derive(&self, label: &str) -> Slip21Node {
+        self.derive_with_backend(label, &DefaultCrypto)
+    }
+
+    /// Derive the child node of `label`, with the HMAC run by `backend`
+    pub fn derive_with_backend<B: CryptoBackend + ?Sized>(
+        &self,
+        label: &str,
+        backend: &B,
+    ) -> Slip21Node {
+        Slip21Node::hmac(backend, &self.bytes[..32], &[&[0], label.as_bytes()])
+    }
+
+    /// Get the 32 byte symmetric key of the node
+    pub fn key(&self) -> &[u8; 32] {
+        self.bytes[32..]
+            .try_into()
+            .expect("nodes are 64 bytes long")
+    }
+
+    fn hmac<B: CryptoBackend + ?Sized>(backend: &B, key: &[u8], data: &[&[u8]]) -> Slip21Node {
+        Slip21Node {
+            bytes: Zeroizing::new(backend.hmac_sha512(key, data)),
+        }
+    }
+}
+
+/// The node is a secret, so it's never printed
+impl fmt::Debug for Slip21Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Slip21Node(****)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::language::Language;
+    use crate::mnemonic::Mnemonic;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    // The vectors of SLIP-0021
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn slip21_vectors() {
+        let phrase = "all all all all all all all all all all all all";
+        let seed = Mnemonic::from_phrase(phrase, Language::English)
+            .unwrap()
+            .to_seed("");
+
+        assert_eq!(
+            hex(seed.slip21_node(&[]).key()),
+            "dbf12b44133eaab506a740f6565cc117228cbf1dd70635cfa8ddfdc9af734756"
+        );
+        assert_eq!(
+            hex(seed.slip21_node(&["SLIP-0021"]).key()),
+            "1d065e3ac1bbe5c7fad32cf2305f7d709dc070d672044a19e610c77cdf33de0d"
+        );
+        assert_eq!(
+            hex(seed
+                .slip21_node(&["SLIP-0021", "Master encryption key"])
+                .key()),
+            "ea163130e35bbafdf5ddee97a17b39cef2be4b4f390180d65b54cf05c6a82fde"
+        );
+        assert_eq!(
+            hex(Slip21Node::master(&seed)
+                .derive("SLIP-0021")
+                .derive("Authentication key")
+                .key()),
+            "47194e938ab24cc82bfa25f6486ed54bebe79c40ae2a5a32ea6db294d81861a6"
+        );
+        assert_eq!(format!("{:?}", seed.slip21_node(&[])), "Slip21Node(****)");
+    }
+}
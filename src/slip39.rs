@@ -0,0 +1,657 @@
+//! SLIP-0039 Shamir backups, enabled with the `slip39` feature
+//!
+//! SLIP-0039 splits a master secret into shares, organized in groups: the secret can be recovered
+//! from enough shares of enough groups, while fewer shares reveal nothing about it. Every share is
+//! a phrase of at least 20 words, from a separate 1024 word list, with an RS1024 checksum.
+//!
+//! ```
+//! use bip39::slip39;
+//!
+//! let master_secret = b"sixteen byte key";
+//!
+//! // One group, of which 2 of the 3 shares are needed
+//! let groups = slip39::split(master_secret, "TREZOR", 1, &[(2, 3)]).unwrap();
+//!
+//! let phrases: Vec<_> = groups[0].iter().map(|share| share.to_phrase()).collect();
+//!
+//! let shares = [
+//!     slip39::Share::from_phrase(&phrases[0]).unwrap(),
+//!     slip39::Share::from_phrase(&phrases[2]).unwrap(),
+//! ];
+//!
+//! assert_eq!(&slip39::combine(&shares, "TREZOR").unwrap()[..], &master_secret[..]);
+//! ```
+//!
+//! The master secret is encrypted with the passphrase before it's split, any passphrase recovers
+//! some secret and there is no way to tell whether it's the right one.
+
+use crate::crypto::{gen_random_bytes, pbkdf2_sha256};
+use crate::error::{Error, SecretWord};
+use crate::shamir::{recover_secret, split_secret};
+use crate::util::IterExt;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::NonZeroU32;
+#[cfg(feature = "rand")]
+use rand_core::OsRng;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+/// The number of bits of every word
+const RADIX_BITS: usize = 10;
+
+/// The number of words of the share header and of the checksum
+const METADATA_WORDS: usize = 7;
+
+/// The number of checksum words
+const CHECKSUM_WORDS: usize = 3;
+
+/// The shortest master secret, in bytes, and with it the shortest share, in words
+const MIN_SECRET_LEN: usize = 16;
+const MIN_WORDS: usize = METADATA_WORDS + (MIN_SECRET_LEN * 8).div_ceil(RADIX_BITS);
+
+/// The iteration exponent of new shares, for 20000 PBKDF2 rounds in total
+const ITERATION_EXPONENT: u8 = 1;
+
+/// One share of a master secret, as encoded in its phrase
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// Decode a share from its phrase
+    ///
+    /// Returns `Error::InvalidWord` for words that aren't in the SLIP-0039 word list,
+    /// `Error::InvalidWordCount` for phrases that are too short, or can't hold a whole number of
+    /// bytes, `Error::InvalidChecksum` if the checksum doesn't match and `Error::InvalidShare` if
+    /// the header or the padding of the value is invalid.
+    pub fn from_phrase(phrase: &str) -> Result<Share, Error> {
+        let wordlist = wordlist();
+
+        let mut indices = Zeroizing::new(Vec::new());
+        for (index, word) in phrase.split_whitespace().enumerate() {
+            let word = word.to_lowercase();
+
+            match wordlist.binary_search(&word.as_str()) {
+                Ok(position) => indices.push(position as u16),
+                Err(_) => {
+                    return Err(Error::InvalidWord {
+                        word: SecretWord::new(&word, None),
+                        index,
+                    })
+                }
+            }
+        }
+
+        let len = indices.len();
+        let padding_bits = (RADIX_BITS * len.saturating_sub(METADATA_WORDS)) % 16;
+
+        if len < MIN_WORDS || padding_bits > 8 {
+            return Err(Error::InvalidWordCount(len));
+        }
+
+        let identifier_and_exponent = (indices[0] as u32) << RADIX_BITS | indices[1] as u32;
+        let extendable = identifier_and_exponent >> 4 & 1 == 1;
+
+        if rs1024_polymod(customization(extendable), &indices) != 1 {
+            return Err(Error::InvalidChecksum);
+        }
+
+        let params = (indices[2] as u32) << RADIX_BITS | indices[3] as u32;
+
+        let share = Share {
+            identifier: (identifier_and_exponent >> 5) as u16,
+            extendable,
+            iteration_exponent: (identifier_and_exponent & 0xf) as u8,
+            group_index: (params >> 16 & 0xf) as u8,
+            group_threshold: (params >> 12 & 0xf) as u8 + 1,
+            group_count: (params >> 8 & 0xf) as u8 + 1,
+            member_index: (params >> 4 & 0xf) as u8,
+            member_threshold: (params & 0xf) as u8 + 1,
+            value: words_to_bytes(&indices[4..len - CHECKSUM_WORDS], padding_bits)?,
+        };
+
+        if share.group_threshold > share.group_count {
+            return Err(Error::InvalidShare);
+        }
+
+        Ok(share)
+    }
+
+    /// Encode the share as its phrase
+    pub fn to_phrase(&self) -> Zeroizing<String> {
+        let identifier_and_exponent = (self.identifier as u32) << 5
+            | (self.extendable as u32) << 4
+            | self.iteration_exponent as u32;
+
+        let params = (self.group_index as u32) << 16
+            | (self.group_threshold as u32 - 1) << 12
+            | (self.group_count as u32 - 1) << 8
+            | (self.member_index as u32) << 4
+            | (self.member_threshold as u32 - 1);
+
+        let mut indices = Zeroizing::new(vec![
+            (identifier_and_exponent >> RADIX_BITS) as u16,
+            (identifier_and_exponent & 0x3ff) as u16,
+            (params >> RADIX_BITS) as u16,
+            (params & 0x3ff) as u16,
+        ]);
+        indices.extend(bytes_to_words(&self.value).iter());
+
+        let checksum = rs1024_checksum(customization(self.extendable), &indices);
+        indices.extend_from_slice(&checksum);
+
+        let wordlist = wordlist();
+
+        Zeroizing::new(
+            indices
+                .iter()
+                .map(|&index| wordlist[index as usize])
+                .join(" "),
+        )
+    }
+
+    /// The random identifier shared by all shares of a master secret
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Whether the master secret is encrypted without using the identifier, so that more shares
+    /// with a new identifier could be made for it
+    pub fn extendable(&self) -> bool {
+        self.extendable
+    }
+
+    /// The exponent of the number of PBKDF2 rounds, which are `10000 * 2^e` in total
+    pub fn iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+
+    /// The index of the group of the share, from 0
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// The number of groups needed to recover the master secret
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    /// The total number of groups
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    /// The index of the share in its group, from 0
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    /// The number of shares of the group needed to recover the group
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("identifier", &self.identifier)
+            .field("group_index", &self.group_index)
+            .field("member_index", &self.member_index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Split `master_secret` into groups of shares, using the operating system CSPRNG
+///
+/// See [`split_with_rng()`][split_with_rng] for the arguments.
+///
+/// [split_with_rng]: ./fn.split_with_rng.html
+#[cfg(feature = "rand")]
+pub fn split(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error> {
+    split_with_rng(
+        &mut OsRng,
+        master_secret,
+        passphrase,
+        group_threshold,
+        groups,
+    )
+}
+
+/// Split `master_secret` into groups of shares, using randomness from `rng`
+///
+/// `groups` has the member threshold and the share count of every group, and `group_threshold` of
+/// them are needed to recover the secret. The master secret is encrypted with `passphrase` first.
+///
+/// Returns `Error::InvalidKeysize` if the master secret is shorter than 16 bytes or has an odd
+/// length, and `Error::InvalidThreshold` if a threshold is 0 or larger than its count, if there
+/// are more than 16 groups or shares in a group, or if a group of several shares has a member
+/// threshold of 1, which SLIP-0039 forbids since any one of them would do.
+pub fn split_with_rng<R>(
+    rng: &mut R,
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    if master_secret.len() < MIN_SECRET_LEN || !master_secret.len().is_multiple_of(2) {
+        return Err(Error::InvalidKeysize(master_secret.len() * 8));
+    }
+
+    let invalid_group = |&(threshold, count): &(u8, u8)| threshold == 1 && count > 1;
+
+    if groups.iter().any(invalid_group) {
+        return Err(Error::InvalidThreshold);
+    }
+
+    let random = gen_random_bytes(rng, 2)?;
+    let identifier = u16::from_be_bytes([random[0], random[1]]) & 0x7fff;
+    let extendable = true;
+
+    let encrypted = feistel(
+        master_secret,
+        passphrase.as_bytes(),
+        ITERATION_EXPONENT,
+        identifier,
+        extendable,
+        true,
+    );
+
+    let group_secrets = split_secret(rng, group_threshold, groups.len(), &encrypted)?;
+
+    let mut shares = Vec::with_capacity(groups.len());
+
+    for ((group_index, group_secret), &(member_threshold, count)) in
+        group_secrets.into_iter().zip(groups)
+    {
+        let member_secrets = split_secret(rng, member_threshold, count.into(), &group_secret)?;
+
+        let group = member_secrets
+            .into_iter()
+            .map(|(member_index, value)| Share {
+                identifier,
+                extendable,
+                iteration_exponent: ITERATION_EXPONENT,
+                group_index,
+                group_threshold,
+                group_count: groups.len() as u8,
+                member_index,
+                member_threshold,
+                value,
+            })
+            .collect();
+
+        shares.push(group);
+    }
+
+    Ok(shares)
+}
+
+/// Recover the master secret from `shares`, and decrypt it with `passphrase`
+///
+/// The shares can be in any order, and there can be more than needed. Returns
+/// `Error::MismatchedShares` if the shares aren't all from the same split, or repeat an index, and
+/// `Error::NotEnoughShares` with the number of complete groups if there aren't enough of them.
+pub fn combine(shares: &[Share], passphrase: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let first = shares
+        .first()
+        .ok_or(Error::NotEnoughShares { got: 0, needed: 1 })?;
+
+    let same_split = |share: &&Share| {
+        share.identifier == first.identifier
+            && share.extendable == first.extendable
+            && share.iteration_exponent == first.iteration_exponent
+            && share.group_threshold == first.group_threshold
+            && share.group_count == first.group_count
+            && share.value.len() == first.value.len()
+    };
+
+    if !shares.iter().all(|share| same_split(&share)) {
+        return Err(Error::MismatchedShares);
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+
+    for share in shares {
+        let group = groups.entry(share.group_index).or_default();
+
+        let mismatched = group.iter().any(|other| {
+            other.member_index == share.member_index
+                || other.member_threshold != share.member_threshold
+        });
+
+        if mismatched {
+            return Err(Error::MismatchedShares);
+        }
+
+        group.push(share);
+    }
+
+    let group_threshold = first.group_threshold as usize;
+
+    let complete: Vec<_> = groups
+        .iter()
+        .filter(|(_, members)| members.len() >= members[0].member_threshold as usize)
+        .collect();
+
+    if complete.len() < group_threshold {
+        return Err(Error::NotEnoughShares {
+            got: complete.len(),
+            needed: group_threshold,
+        });
+    }
+
+    let mut group_secrets = Vec::with_capacity(group_threshold);
+
+    for (&group_index, members) in complete.into_iter().take(group_threshold) {
+        let threshold = members[0].member_threshold;
+        let points: Vec<_> = members
+            .iter()
+            .take(threshold.into())
+            .map(|share| (share.member_index, &share.value[..]))
+            .collect();
+
+        group_secrets.push((group_index, recover_secret(threshold, &points)?));
+    }
+
+    let points: Vec<_> = group_secrets
+        .iter()
+        .map(|(index, secret)| (*index, &secret[..]))
+        .collect();
+    let encrypted = recover_secret(first.group_threshold, &points)?;
+
+    Ok(feistel(
+        &encrypted,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        false,
+    ))
+}
+
+/// The customization string of the checksum, which differs for extendable shares
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+/// The Reed-Solomon code over GF(1024) that the checksum is the remainder of
+fn rs1024_polymod(customization: &[u8], values: &[u16]) -> u32 {
+    const GEN: [u32; 10] = [
+        0x00e0_e040,
+        0x01c1_c080,
+        0x0383_8100,
+        0x0707_0200,
+        0x0e0e_0009,
+        0x1c0c_2412,
+        0x3808_6c24,
+        0x3090_fc48,
+        0x21b1_f890,
+        0x03f3_f120,
+    ];
+
+    let mut chk = 1u32;
+    let values = customization
+        .iter()
+        .map(|&c| c as u32)
+        .chain(values.iter().map(|&v| v as u32));
+
+    for value in values {
+        let b = chk >> 20;
+        chk = (chk & 0xf_ffff) << 10 ^ value;
+
+        for (i, gen) in GEN.iter().enumerate() {
+            if b >> i & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+
+    chk
+}
+
+fn rs1024_checksum(customization: &[u8], values: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let mut padded = Zeroizing::new(Vec::with_capacity(values.len() + CHECKSUM_WORDS));
+    padded.extend_from_slice(values);
+    padded.extend_from_slice(&[0; CHECKSUM_WORDS]);
+
+    let polymod = rs1024_polymod(customization, &padded) ^ 1;
+
+    [
+        (polymod >> 20 & 0x3ff) as u16,
+        (polymod >> 10 & 0x3ff) as u16,
+        (polymod & 0x3ff) as u16,
+    ]
+}
+
+/// Split the bits of the words into bytes, after the leading zero padding
+fn words_to_bytes(words: &[u16], padding_bits: usize) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut bytes = Zeroizing::new(Vec::with_capacity(words.len() * RADIX_BITS / 8));
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+
+    if words[0] >> (RADIX_BITS - padding_bits) != 0 {
+        return Err(Error::InvalidShare);
+    }
+
+    for (i, &word) in words.iter().enumerate() {
+        acc = acc << RADIX_BITS | word as u32;
+        acc_bits += RADIX_BITS;
+
+        if i == 0 {
+            acc_bits -= padding_bits;
+        }
+
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes.push((acc >> acc_bits) as u8);
+        }
+
+        acc &= (1 << acc_bits) - 1;
+    }
+
+    Ok(bytes)
+}
+
+/// Group the bits of the bytes into words, with leading zero padding
+fn bytes_to_words(bytes: &[u8]) -> Zeroizing<Vec<u16>> {
+    let word_count = (bytes.len() * 8).div_ceil(RADIX_BITS);
+    let mut words = Zeroizing::new(Vec::with_capacity(word_count));
+    let mut acc = 0u32;
+    let mut acc_bits = word_count * RADIX_BITS - bytes.len() * 8;
+
+    for &byte in bytes {
+        acc = acc << 8 | byte as u32;
+        acc_bits += 8;
+
+        if acc_bits >= RADIX_BITS {
+            acc_bits -= RADIX_BITS;
+            words.push((acc >> acc_bits) as u16);
+            acc &= (1 << acc_bits) - 1;
+        }
+    }
+
+    words
+}
+
+/// The 4 round Feistel network that encrypts the master secret with the passphrase
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    encrypt: bool,
+) -> Zeroizing<Vec<u8>> {
+    const ROUNDS: u8 = 4;
+
+    let half = input.len() / 2;
+    let mut left = Zeroizing::new(input[..half].to_vec());
+    let mut right = Zeroizing::new(input[half..].to_vec());
+
+    let mut salt = Zeroizing::new(Vec::with_capacity(8 + half));
+    if !extendable {
+        salt.extend_from_slice(b"shamir");
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let salt_prefix_len = salt.len();
+
+    let mut password = Zeroizing::new(Vec::with_capacity(1 + passphrase.len()));
+    password.push(0);
+    password.extend_from_slice(passphrase);
+
+    let iterations = NonZeroU32::new((10_000u32 << iteration_exponent) / ROUNDS as u32)
+        .expect("the 4 bit iteration exponent gives at least 2500 iterations");
+    let mut round_output = Zeroizing::new(vec![0u8; half]);
+
+    for round in 0..ROUNDS {
+        password[0] = if encrypt { round } else { ROUNDS - 1 - round };
+
+        salt.truncate(salt_prefix_len);
+        salt.extend_from_slice(&right);
+
+        pbkdf2_sha256(&password, &salt, iterations, &mut round_output);
+
+        for (byte, round_byte) in left.iter_mut().zip(round_output.iter()) {
+            *byte ^= round_byte;
+        }
+
+        core::mem::swap(&mut left, &mut right);
+    }
+
+    right.extend_from_slice(&left);
+    right
+}
+
+fn wordlist() -> &'static [&'static str] {
+    &lazy::WORDLIST
+}
+
+mod lazy {
+    use crate::util::Lazy;
+    use alloc::vec::Vec;
+
+    pub(super) static WORDLIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
+        let inner: Vec<_> = include_str!("langs/slip39/english.txt").lines().collect();
+
+        debug_assert!(inner.len() == 1024, "Invalid wordlist length");
+
+        inner
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let secret = [0x42u8; 32];
+
+        let groups =
+            split_with_rng(&mut rng, &secret, "pass", 2, &[(1, 1), (2, 3), (3, 5)]).unwrap();
+
+        assert_eq!(groups.iter().map(Vec::len).collect::<Vec<_>>(), [1, 3, 5]);
+
+        for share in groups.iter().flatten() {
+            let phrase = share.to_phrase();
+
+            assert_eq!(phrase.split(' ').count(), 33);
+            assert_eq!(&Share::from_phrase(&phrase).unwrap(), share);
+        }
+
+        let shares = [
+            groups[2][4].clone(),
+            groups[1][2].clone(),
+            groups[2][0].clone(),
+            groups[1][0].clone(),
+            groups[2][1].clone(),
+        ];
+        assert_eq!(&combine(&shares, "pass").unwrap()[..], &secret);
+        assert_ne!(&combine(&shares, "").unwrap()[..], &secret);
+
+        let shares = [
+            groups[0][0].clone(),
+            groups[1][1].clone(),
+            groups[1][2].clone(),
+        ];
+        assert_eq!(&combine(&shares, "pass").unwrap()[..], &secret);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_split() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 15], "", 1, &[(1, 1)]).map(|_| ()),
+            Err(Error::InvalidKeysize(120))
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 16], "", 2, &[(1, 1)]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 16], "", 1, &[(1, 2)]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 16], "", 1, &[(2, 17)]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn not_enough_shares() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let groups = split_with_rng(&mut rng, &[7; 16], "", 2, &[(2, 3), (2, 3)]).unwrap();
+
+        let shares = [
+            groups[0][0].clone(),
+            groups[0][1].clone(),
+            groups[1][0].clone(),
+        ];
+        assert_eq!(
+            combine(&shares, "").map(|_| ()),
+            Err(Error::NotEnoughShares { got: 1, needed: 2 })
+        );
+
+        let shares = [groups[0][0].clone(), groups[0][0].clone()];
+        assert_eq!(
+            combine(&shares, "").map(|_| ()),
+            Err(Error::MismatchedShares)
+        );
+    }
+}
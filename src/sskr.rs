@@ -0,0 +1,671 @@
+//! Blockchain Commons SSKR shares, enabled with the `sskr` feature
+//!
+//! [SSKR](https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-011-sskr.md)
+//! (Sharded Secret Key Reconstruction) splits a master secret, such as the entropy of a
+//! [`Mnemonic`][Mnemonic], into shares organized in groups, like SLIP-0039, and is what some
+//! hardware wallets back up to. Shares are written as
+//! [ByteWords](https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-012-bytewords.md),
+//! or as `ur:crypto-sskr` URs for QR codes. Unlike SLIP-0039, the secret isn't encrypted with a
+//! passphrase.
+//!
+//! ```
+//! use bip39::{sskr, Language, Mnemonic};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//!
+//! // One group, of which 2 of the 3 shares are needed
+//! let groups = sskr::split(mnemonic.entropy(), 1, &[(2, 3)]).unwrap();
+//!
+//! let words: Vec<_> = groups[0].iter().map(|share| share.to_bytewords()).collect();
+//!
+//! let shares = [
+//!     sskr::Share::from_bytewords(&words[0]).unwrap(),
+//!     sskr::Share::from_bytewords(&words[2]).unwrap(),
+//! ];
+//!
+//! assert_eq!(&sskr::combine(&shares).unwrap()[..], mnemonic.entropy());
+//! ```
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+
+use crate::crypto::gen_random_bytes;
+use crate::error::Error;
+use crate::shamir::{recover_secret, split_secret};
+use crate::util::{crc32, IterExt};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "rand")]
+use rand_core::OsRng;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+/// The length of the share header, before the value
+const METADATA_LEN: usize = 5;
+
+/// The shortest and the longest master secrets, in bytes
+const MIN_SECRET_LEN: usize = 16;
+const MAX_SECRET_LEN: usize = 32;
+
+/// The CBOR header of tag 309, which SSKR shares are tagged with
+const CBOR_TAG: [u8; 3] = [0xd9, 0x01, 0x35];
+
+/// The UR type of SSKR shares
+const UR_PREFIX: &str = "ur:crypto-sskr/";
+
+const CRC32_IEEE: u32 = 0xedb8_8320;
+
+/// One share of a master secret
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    identifier: u16,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// Decode a share from its binary form, the 5 byte header followed by the value
+    ///
+    /// Returns `Error::InvalidShare` if the header is invalid, or the value isn't an even number
+    /// of bytes between 16 and 32.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Share, Error> {
+        if bytes.len() < METADATA_LEN {
+            return Err(Error::InvalidShare);
+        }
+
+        let (header, value) = bytes.split_at(METADATA_LEN);
+
+        let share = Share {
+            identifier: u16::from_be_bytes([header[0], header[1]]),
+            group_threshold: (header[2] >> 4) + 1,
+            group_count: (header[2] & 0xf) + 1,
+            group_index: header[3] >> 4,
+            member_threshold: (header[3] & 0xf) + 1,
+            member_index: header[4] & 0xf,
+            value: Zeroizing::new(value.to_vec()),
+        };
+
+        let valid = valid_secret_len(value.len())
+            && header[4] >> 4 == 0
+            && share.group_threshold <= share.group_count
+            && share.group_index < share.group_count;
+
+        if !valid {
+            return Err(Error::InvalidShare);
+        }
+
+        Ok(share)
+    }
+
+    /// Encode the share in its binary form
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(METADATA_LEN + self.value.len()));
+
+        bytes.extend_from_slice(&self.identifier.to_be_bytes());
+        bytes.push((self.group_threshold - 1) << 4 | (self.group_count - 1));
+        bytes.push(self.group_index << 4 | (self.member_threshold - 1));
+        bytes.push(self.member_index);
+        bytes.extend_from_slice(&self.value);
+
+        bytes
+    }
+
+    /// Decode a share from its ByteWords, as written on a backup
+    ///
+    /// The words are separated by whitespace, in any case. Returns `Error::InvalidEncoding` for
+    /// words that aren't ByteWords or a payload that isn't a tagged SSKR share,
+    /// `Error::InvalidChecksum` if the checksum doesn't match, and the errors of
+    /// [`Share::from_bytes()`][from_bytes] for the share itself.
+    ///
+    /// [from_bytes]: ./struct.Share.html#method.from_bytes
+    pub fn from_bytewords(words: &str) -> Result<Share, Error> {
+        let bytes = words
+            .split_whitespace()
+            .map(|word| {
+                let word = word.to_ascii_lowercase();
+                let index = BYTEWORDS.binary_search(&word.as_str());
+
+                index
+                    .map(|index| index as u8)
+                    .map_err(|_| Error::InvalidEncoding)
+            })
+            .collect::<Result<Vec<u8>, Error>>()?;
+
+        Share::from_payload(&Zeroizing::new(bytes))
+    }
+
+    /// Encode the share as ByteWords, four letter words separated by spaces, ending with a
+    /// checksum
+    pub fn to_bytewords(&self) -> Zeroizing<String> {
+        let payload = self.to_payload();
+
+        Zeroizing::new(
+            payload
+                .iter()
+                .map(|&byte| BYTEWORDS[byte as usize])
+                .join(" "),
+        )
+    }
+
+    /// Decode a share from a `ur:crypto-sskr` UR, in any case
+    ///
+    /// Returns `Error::InvalidEncoding` if it isn't such a UR, and the same errors as
+    /// [`Share::from_bytewords()`][from_bytewords] otherwise.
+    ///
+    /// [from_bytewords]: ./struct.Share.html#method.from_bytewords
+    pub fn from_ur(ur: &str) -> Result<Share, Error> {
+        let ur = Zeroizing::new(ur.trim().to_ascii_lowercase());
+        let body = ur.strip_prefix(UR_PREFIX).ok_or(Error::InvalidEncoding)?;
+
+        if !body.len().is_multiple_of(2) {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let bytes = body
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| {
+                let index = BYTEWORDS.iter().position(|word| {
+                    let word = word.as_bytes();
+                    word[0] == pair[0] && word[3] == pair[1]
+                });
+
+                index.map(|index| index as u8).ok_or(Error::InvalidEncoding)
+            })
+            .collect::<Result<Vec<u8>, Error>>()?;
+
+        Share::from_payload(&Zeroizing::new(bytes))
+    }
+
+    /// Encode the share as a `ur:crypto-sskr` UR, with minimal ByteWords, which can be put in
+    /// a QR code once uppercased
+    pub fn to_ur(&self) -> Zeroizing<String> {
+        let payload = self.to_payload();
+
+        let mut ur = Zeroizing::new(String::from(UR_PREFIX));
+        for &byte in payload.iter() {
+            let word = BYTEWORDS[byte as usize].as_bytes();
+            ur.push(word[0] as char);
+            ur.push(word[3] as char);
+        }
+
+        ur
+    }
+
+    /// The random identifier of the split, the same for all of its shares
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// The index of the group of the share
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// The number of groups needed to recover the secret
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    /// The number of groups
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    /// The index of the share in its group
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    /// The number of shares of the group needed to recover the group
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    /// The share as tagged CBOR, followed by its CRC-32, as encoded in ByteWords
+    fn to_payload(&self) -> Zeroizing<Vec<u8>> {
+        let bytes = self.to_bytes();
+
+        let mut payload = Zeroizing::new(CBOR_TAG.to_vec());
+        if bytes.len() < 24 {
+            payload.push(0x40 | bytes.len() as u8);
+        } else {
+            payload.extend_from_slice(&[0x58, bytes.len() as u8]);
+        }
+        payload.extend_from_slice(&bytes);
+
+        let checksum = crc32(CRC32_IEEE, &payload);
+        payload.extend_from_slice(&checksum.to_be_bytes());
+
+        payload
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Share, Error> {
+        if payload.len() < CBOR_TAG.len() + 5 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let (cbor, checksum) = payload.split_at(payload.len() - 4);
+        if crc32(CRC32_IEEE, cbor).to_be_bytes() != checksum {
+            return Err(Error::InvalidChecksum);
+        }
+
+        // A byte string, with its length in the header below 24 bytes and after it otherwise
+        let (len, bytes) = match cbor.strip_prefix(&CBOR_TAG[..]) {
+            Some([0x58, len, bytes @ ..]) if *len >= 24 => (*len, bytes),
+            Some([header @ 0x40..=0x57, bytes @ ..]) => (header - 0x40, bytes),
+            _ => return Err(Error::InvalidEncoding),
+        };
+
+        if bytes.len() != len as usize {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Share::from_bytes(bytes)
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("identifier", &self.identifier)
+            .field("group_index", &self.group_index)
+            .field("member_index", &self.member_index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Split `master_secret` into groups of shares, using the operating system CSPRNG
+///
+/// See [`split_with_rng()`][split_with_rng] for the arguments.
+///
+/// [split_with_rng]: ./fn.split_with_rng.html
+#[cfg(feature = "rand")]
+pub fn split(
+    master_secret: &[u8],
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error> {
+    split_with_rng(&mut OsRng, master_secret, group_threshold, groups)
+}
+
+/// Split `master_secret` into groups of shares, using randomness from `rng`
+///
+/// `groups` has the member threshold and the share count of every group, and `group_threshold` of
+/// them are needed to recover the secret.
+///
+/// Returns `Error::InvalidKeysize` if the master secret isn't an even number of bytes between 16
+/// and 32, and `Error::InvalidThreshold` if a threshold is 0 or larger than its count, or if there
+/// are more than 16 groups or shares in a group.
+pub fn split_with_rng<R>(
+    rng: &mut R,
+    master_secret: &[u8],
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    if !valid_secret_len(master_secret.len()) {
+        return Err(Error::InvalidKeysize(master_secret.len() * 8));
+    }
+
+    let group_secrets = split_secret(rng, group_threshold, groups.len(), master_secret)?;
+
+    let random = gen_random_bytes(rng, 2)?;
+    let identifier = u16::from_be_bytes([random[0], random[1]]);
+
+    let mut shares = Vec::with_capacity(groups.len());
+
+    for ((group_index, group_secret), &(member_threshold, count)) in
+        group_secrets.into_iter().zip(groups)
+    {
+        let member_secrets = split_secret(rng, member_threshold, count.into(), &group_secret)?;
+
+        let group = member_secrets
+            .into_iter()
+            .map(|(member_index, value)| Share {
+                identifier,
+                group_index,
+                group_threshold,
+                group_count: groups.len() as u8,
+                member_index,
+                member_threshold,
+                value,
+            })
+            .collect();
+
+        shares.push(group);
+    }
+
+    Ok(shares)
+}
+
+/// Recover the master secret from `shares`
+///
+/// The shares can be in any order, and there can be more than needed. Returns
+/// `Error::MismatchedShares` if the shares aren't all from the same split, or repeat an index, and
+/// `Error::NotEnoughShares` with the number of complete groups if there aren't enough of them.
+pub fn combine(shares: &[Share]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let first = shares
+        .first()
+        .ok_or(Error::NotEnoughShares { got: 0, needed: 1 })?;
+
+    let same_split = |share: &&Share| {
+        share.identifier == first.identifier
+            && share.group_threshold == first.group_threshold
+            && share.group_count == first.group_count
+            && share.value.len() == first.value.len()
+    };
+
+    if !shares.iter().all(|share| same_split(&share)) {
+        return Err(Error::MismatchedShares);
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+
+    for share in shares {
+        let group = groups.entry(share.group_index).or_default();
+
+        let mismatched = group.iter().any(|other| {
+            other.member_index == share.member_index
+                || other.member_threshold != share.member_threshold
+        });
+
+        if mismatched {
+            return Err(Error::MismatchedShares);
+        }
+
+        group.push(share);
+    }
+
+    let group_threshold = first.group_threshold as usize;
+
+    let complete: Vec<_> = groups
+        .iter()
+        .filter(|(_, members)| members.len() >= members[0].member_threshold as usize)
+        .collect();
+
+    if complete.len() < group_threshold {
+        return Err(Error::NotEnoughShares {
+            got: complete.len(),
+            needed: group_threshold,
+        });
+    }
+
+    let mut group_secrets = Vec::with_capacity(group_threshold);
+
+    for (&group_index, members) in complete.into_iter().take(group_threshold) {
+        let threshold = members[0].member_threshold;
+        let points: Vec<_> = members
+            .iter()
+            .take(threshold.into())
+            .map(|share| (share.member_index, &share.value[..]))
+            .collect();
+
+        group_secrets.push((group_index, recover_secret(threshold, &points)?));
+    }
+
+    let points: Vec<_> = group_secrets
+        .iter()
+        .map(|(index, secret)| (*index, &secret[..]))
+        .collect();
+
+    recover_secret(first.group_threshold, &points)
+}
+
+fn valid_secret_len(len: usize) -> bool {
+    (MIN_SECRET_LEN..=MAX_SECRET_LEN).contains(&len) && len.is_multiple_of(2)
+}
+
+/// The 256 ByteWords, in the order of the bytes they stand for, which is alphabetical
+#[rustfmt::skip]
+const BYTEWORDS: [&str; 256] = [
+    "able", "acid", "also", "apex", "aqua", "arch", "atom", "aunt", "away", "axis",
+    "back", "bald", "barn", "belt", "beta", "bias", "blue", "body", "brag", "brew",
+    "bulb", "buzz", "calm", "cash", "cats", "chef", "city", "claw", "code", "cola",
+    "cook", "cost", "crux", "curl", "cusp", "cyan", "dark", "data", "days", "deli",
+    "dice", "diet", "door", "down", "draw", "drop", "drum", "dull", "duty", "each",
+    "easy", "echo", "edge", "epic", "even", "exam", "exit", "eyes", "fact", "fair",
+    "fern", "figs", "film", "fish", "fizz", "flap", "flew", "flux", "foxy", "free",
+    "frog", "fuel", "fund", "gala", "game", "gear", "gems", "gift", "girl", "glow",
+    "good", "gray", "grim", "guru", "gush", "gyro", "half", "hang", "hard", "hawk",
+    "heat", "help", "high", "hill", "holy", "hope", "horn", "huts", "iced", "idea",
+    "idle", "inch", "inky", "into", "iris", "iron", "item", "jade", "jazz", "join",
+    "jolt", "jowl", "judo", "jugs", "jump", "junk", "jury", "keep", "keno", "kept",
+    "keys", "kick", "kiln", "king", "kite", "kiwi", "knob", "lamb", "lava", "lazy",
+    "leaf", "legs", "liar", "limp", "lion", "list", "logo", "loud", "love", "luau",
+    "luck", "lung", "main", "many", "math", "maze", "memo", "menu", "meow", "mild",
+    "mint", "miss", "monk", "nail", "navy", "need", "news", "next", "noon", "note",
+    "numb", "obey", "oboe", "omit", "onyx", "open", "oval", "owls", "paid", "part",
+    "peck", "play", "plus", "poem", "pool", "pose", "puff", "puma", "purr", "quad",
+    "quiz", "race", "ramp", "real", "redo", "rich", "road", "rock", "roof", "ruby",
+    "ruin", "runs", "rust", "safe", "saga", "scar", "sets", "silk", "skew", "slot",
+    "soap", "solo", "song", "stub", "surf", "swan", "taco", "task", "taxi", "tent",
+    "tied", "time", "tiny", "toil", "tomb", "toys", "trip", "tuna", "twin", "ugly",
+    "undo", "unit", "urge", "user", "vast", "very", "veto", "vial", "vibe", "view",
+    "visa", "void", "vows", "wall", "wand", "warm", "wasp", "wave", "waxy", "webs",
+    "what", "when", "whiz", "wolf", "work", "yank", "yawn", "yell", "yoga", "yurt",
+    "zaps", "zero", "zest", "zinc", "zone", "zoom",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    /// The fake RNG of the bc-shamir and bc-sskr tests, which fills every buffer with 0, 17, 34…
+    struct FakeRng;
+
+    impl RngCore for FakeRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for (byte, value) in dest.iter_mut().zip((0..=255).step_by(17).cycle()) {
+                *byte = value;
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for FakeRng {}
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn bc_sskr_vectors() {
+        let secret = hex::decode("0ff784df000c4380a5ed683f7e6e3dcf").unwrap();
+
+        let groups = split_with_rng(&mut FakeRng, &secret, 1, &[(3, 5)]).unwrap();
+
+        // The values are the shares of the 3 of 5 vector of bc-shamir, each after a header of
+        // identifier 0x0011, one group and a member threshold of 3
+        let expected = [
+            ("0011000200", "00112233445566778899aabbccddeeff"),
+            ("0011000201", "d43099fe444807c46921a4f33a2a798b"),
+            ("0011000202", "d9ad4e3bec2e1a7485698823abf05d36"),
+            ("0011000203", "0d8cf5f6ec337bc764d1866b5d07ca42"),
+            ("0011000204", "1aa7fe3199bc5092ef3816b074cabdf2"),
+        ];
+        for (share, (header, value)) in groups[0].iter().zip(&expected) {
+            let bytes = share.to_bytes();
+            assert_eq!(hex::encode(&bytes[..METADATA_LEN]), *header);
+            assert_eq!(hex::encode(&bytes[METADATA_LEN..]), *value);
+        }
+
+        assert_eq!(
+            groups[0][1].to_bytewords().as_str(),
+            "tuna acid epic gyro able body able also acid tiny duty nail zone foxy fund aunt sets \
+             iron curl onyx wolf fact door kick luau iced gala race hawk"
+        );
+        assert_eq!(
+            groups[0][1].to_ur().as_str(),
+            "ur:crypto-sskr/taadecgoaebyaeaoadtydynlzefyfdatssincloxwfftdrkkluidgarehk"
+        );
+
+        let ur = "ur:crypto-sskr/taadecgoaebyaeaoaeaebycpeofygoiyktlonlpkrksfutwyzmylfrlbto";
+        let shares = [
+            Share::from_ur(ur).unwrap(),
+            groups[0][3].clone(),
+            groups[0][4].clone(),
+        ];
+        assert_eq!(combine(&shares).unwrap()[..], secret[..]);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let secret = [0x42u8; 32];
+
+        let groups = split_with_rng(&mut rng, &secret, 2, &[(1, 1), (2, 3), (3, 5)]).unwrap();
+
+        assert_eq!(groups.iter().map(Vec::len).collect::<Vec<_>>(), [1, 3, 5]);
+
+        for share in groups.iter().flatten() {
+            assert_eq!(&Share::from_bytes(&share.to_bytes()).unwrap(), share);
+            assert_eq!(
+                &Share::from_bytewords(&share.to_bytewords()).unwrap(),
+                share
+            );
+            assert_eq!(
+                &Share::from_ur(&share.to_ur().to_uppercase()).unwrap(),
+                share
+            );
+        }
+
+        let shares = [
+            groups[2][4].clone(),
+            groups[1][2].clone(),
+            groups[2][0].clone(),
+            groups[1][0].clone(),
+            groups[2][1].clone(),
+        ];
+        assert_eq!(&combine(&shares).unwrap()[..], &secret);
+
+        let shares = [
+            groups[0][0].clone(),
+            groups[1][1].clone(),
+            groups[1][2].clone(),
+        ];
+        assert_eq!(&combine(&shares).unwrap()[..], &secret);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn encoding() {
+        let share = Share {
+            identifier: 0x1234,
+            group_index: 1,
+            group_threshold: 2,
+            group_count: 3,
+            member_index: 4,
+            member_threshold: 5,
+            value: Zeroizing::new(vec![0; 16]),
+        };
+
+        let bytes = share.to_bytes();
+        assert_eq!(&bytes[..METADATA_LEN], [0x12, 0x34, 0x12, 0x14, 0x04]);
+
+        // Tag 309 and a 21 byte string
+        let words = share.to_bytewords();
+        assert!(words.starts_with("tuna acid epic gyro brag edge"));
+        assert_eq!(words.split(' ').count(), 4 + 21 + 4);
+        assert!(share.to_ur().starts_with("ur:crypto-sskr/taadecgobgee"));
+
+        // The example of the ByteWords specification
+        let mut payload = vec![0, 1, 2, 128, 255];
+        payload.extend_from_slice(&crc32(CRC32_IEEE, &payload).to_be_bytes());
+        assert_eq!(
+            payload
+                .iter()
+                .map(|&byte| BYTEWORDS[byte as usize])
+                .join::<String>(" "),
+            "able acid also lava zoom jade need echo taxi"
+        );
+
+        let mut corrupted = words.replacen("brag", "able", 1);
+        assert_eq!(
+            Share::from_bytewords(&corrupted),
+            Err(Error::InvalidChecksum)
+        );
+        corrupted.push_str(" abel");
+        assert_eq!(
+            Share::from_bytewords(&corrupted),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            Share::from_ur("ur:crypto-seed/taad"),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn invalid_shares() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 15], 1, &[(1, 1)]).map(|_| ()),
+            Err(Error::InvalidKeysize(120))
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 34], 1, &[(1, 1)]).map(|_| ()),
+            Err(Error::InvalidKeysize(272))
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 16], 2, &[(1, 1)]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            split_with_rng(&mut rng, &[0; 16], 1, &[(2, 17)]).map(|_| ()),
+            Err(Error::InvalidThreshold)
+        );
+
+        // A group index past the group count, and a value of 17 bytes
+        let header = [0x12, 0x34, 0x00, 0x10, 0x00];
+        assert_eq!(
+            Share::from_bytes(&[&header[..], &[0; 16]].concat()),
+            Err(Error::InvalidShare)
+        );
+        let header = [0x12, 0x34, 0x00, 0x00, 0x00];
+        assert!(Share::from_bytes(&[&header[..], &[0; 16]].concat()).is_ok());
+        assert_eq!(
+            Share::from_bytes(&[&header[..], &[0; 17]].concat()),
+            Err(Error::InvalidShare)
+        );
+
+        let groups = split_with_rng(&mut rng, &[7; 16], 2, &[(2, 3), (2, 3)]).unwrap();
+
+        let shares = [
+            groups[0][0].clone(),
+            groups[0][1].clone(),
+            groups[1][0].clone(),
+        ];
+        assert_eq!(
+            combine(&shares).map(|_| ()),
+            Err(Error::NotEnoughShares { got: 1, needed: 2 })
+        );
+
+        let shares = [groups[0][0].clone(), groups[0][0].clone()];
+        assert_eq!(combine(&shares).map(|_| ()), Err(Error::MismatchedShares));
+    }
+}
@@ -0,0 +1,143 @@
+//! [proptest](https://crates.io/crates/proptest) strategies, enabled with the `proptest` feature
+//!
+//! They generate valid mnemonics in the built-in languages enabled at compile time, and phrases
+//! with typos in them, for property tests of code that handles mnemonics.
+//!
+//! ```
+//! use bip39::strategies::{any_mnemonic, phrase_with_typos};
+//! use bip39::{Language, Mnemonic};
+//! use proptest::prelude::*;
+//!
+//! // Mark them `#[test]` in a test module
+//! proptest! {
+//!     fn roundtrip(mnemonic in any_mnemonic()) {
+//!         let parsed = Mnemonic::from_phrase(mnemonic.phrase(), mnemonic.language()).unwrap();
+//!         prop_assert_eq!(parsed, mnemonic);
+//!     }
+//!
+//!     fn typos(phrase in phrase_with_typos(Language::English)) {
+//!         prop_assert!(Mnemonic::validate(&phrase, Language::English).is_err());
+//!     }
+//! }
+//! # roundtrip();
+//! # typos();
+//! ```
+
+use crate::language::{Language, BUILTIN_LANGUAGES};
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use ::proptest::collection::vec;
+use ::proptest::prelude::*;
+use ::proptest::sample::{select, Index};
+use alloc::string::String;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+/// Any of the built-in languages enabled at compile time
+pub fn any_language() -> impl Strategy<Value = Language> {
+    select(BUILTIN_LANGUAGES)
+}
+
+/// Any of the five mnemonic types
+pub fn any_mnemonic_type() -> impl Strategy<Value = MnemonicType> {
+    select(
+        &[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ][..],
+    )
+}
+
+/// Valid mnemonics of any type, in any of the built-in languages
+pub fn any_mnemonic() -> impl Strategy<Value = Mnemonic> {
+    any_language().prop_flat_map(mnemonic_in)
+}
+
+/// Valid mnemonics of any type in `lang`
+pub fn mnemonic_in(lang: Language) -> impl Strategy<Value = Mnemonic> {
+    any_mnemonic_type()
+        .prop_flat_map(|mtype| vec(any::<u8>(), mtype.entropy_len()))
+        .prop_map(move |entropy| Mnemonic::from_entropy_unchecked(Zeroizing::new(entropy), lang))
+}
+
+/// Phrases in `lang` with one to three typos, a deleted, swapped or replaced character each
+///
+/// Each phrase is made from a valid mnemonic, and is itself invalid: phrases whose typos happen to
+/// give other words that are on the list, and a valid checksum, aren't generated.
+pub fn phrase_with_typos(lang: Language) -> impl Strategy<Value = String> {
+    let typos = vec((any::<Index>(), any::<Index>(), 0..3u8), 1..=3);
+
+    (mnemonic_in(lang), typos)
+        .prop_map(move |(mnemonic, typos)| {
+            let mut words: Vec<Vec<char>> = mnemonic
+                .words()
+                .map(|word| word.chars().collect())
+                .collect();
+
+            for (word, at, kind) in typos {
+                let len = words.len();
+                typo(&mut words[word.index(len)], at, kind);
+            }
+
+            let words: Vec<String> = words
+                .into_iter()
+                .map(|word| word.into_iter().collect())
+                .collect();
+
+            words.join(lang.separator())
+        })
+        .prop_filter("the typos gave a valid phrase", move |phrase| {
+            Mnemonic::validate(phrase, lang).is_err()
+        })
+}
+
+/// Delete, swap with the next one or replace the character at `at`
+fn typo(word: &mut Vec<char>, at: Index, kind: u8) {
+    let at = at.index(word.len());
+
+    match kind {
+        0 if word.len() > 1 => {
+            word.remove(at);
+        }
+        1 if word.len() > 1 => {
+            let next = (at + 1) % word.len();
+            word.swap(at, next);
+        }
+        _ => {
+            word[at] = match word[at] {
+                'a'..='y' => char::from(word[at] as u8 + 1),
+                _ => 'a',
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn mnemonics_are_valid(mnemonic in any_mnemonic()) {
+            prop_assert!(Mnemonic::validate(mnemonic.phrase(), mnemonic.language()).is_ok());
+        }
+
+        #[test]
+        fn mnemonics_are_in_lang(mnemonic in mnemonic_in(Language::English)) {
+            prop_assert_eq!(mnemonic.language(), Language::English);
+        }
+
+        #[test]
+        fn typos_are_invalid(
+            (lang, phrase) in any_language().prop_flat_map(|lang| {
+                (Just(lang), phrase_with_typos(lang))
+            })
+        ) {
+            prop_assert!(Mnemonic::validate(&phrase, lang).is_err());
+            prop_assert!(MnemonicType::for_word_count(phrase.split_whitespace().count()).is_ok());
+        }
+    }
+}
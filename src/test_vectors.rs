@@ -0,0 +1,460 @@
+//! The BIP39 test vectors of the Trezor reference implementation, enabled with the `test-utils`
+//! feature
+//!
+//! These are the English vectors of
+//! [python-mnemonic](https://github.com/trezor/python-mnemonic/blob/master/vectors.json), all
+//! with the passphrase `"TREZOR"`, as typed constants for the tests of code built on this crate.
+//!
+//! ```
+//! use bip39::test_vectors::{TREZOR, TREZOR_PASSPHRASE};
+//! use bip39::Seed;
+//!
+//! for vector in TREZOR {
+//!     let mnemonic = vector.mnemonic();
+//!
+//!     assert_eq!(mnemonic.entropy(), vector.entropy);
+//!     assert_eq!(Seed::new(&mnemonic, TREZOR_PASSPHRASE).as_bytes(), &vector.seed[..]);
+//! }
+//! ```
+
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+
+/// The passphrase of every vector
+pub const TREZOR_PASSPHRASE: &str = "TREZOR";
+
+/// An English test vector, with the seed and root key derived with
+/// [`TREZOR_PASSPHRASE`][TREZOR_PASSPHRASE]
+///
+/// [TREZOR_PASSPHRASE]: ./constant.TREZOR_PASSPHRASE.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector {
+    /// The entropy of the mnemonic
+    pub entropy: &'static [u8],
+    /// The mnemonic phrase
+    pub phrase: &'static str,
+    /// The seed
+    pub seed: [u8; 64],
+    /// The BIP32 root key of the seed, serialized for the main network
+    pub xprv: &'static str,
+}
+
+impl TestVector {
+    /// The mnemonic of the vector
+    #[cfg_attr(not(feature = "test-utils"), allow(dead_code))]
+    pub fn mnemonic(&self) -> Mnemonic {
+        Mnemonic::from_phrase(self.phrase, Language::English).expect("test vectors are valid")
+    }
+}
+
+/// The 24 vectors, from 12 to 24 words
+pub const TREZOR: &[TestVector] = &[
+    TestVector {
+        entropy: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        seed: [
+            0xc5, 0x52, 0x57, 0xc3, 0x60, 0xc0, 0x7c, 0x72, 0x02, 0x9a, 0xeb, 0xc1, 0xb5, 0x3c,
+            0x05, 0xed, 0x03, 0x62, 0xad, 0xa3, 0x8e, 0xad, 0x3e, 0x3e, 0x9e, 0xfa, 0x37, 0x08,
+            0xe5, 0x34, 0x95, 0x53, 0x1f, 0x09, 0xa6, 0x98, 0x75, 0x99, 0xd1, 0x82, 0x64, 0xc1,
+            0xe1, 0xc9, 0x2f, 0x2c, 0xf1, 0x41, 0x63, 0x0c, 0x7a, 0x3c, 0x4a, 0xb7, 0xc8, 0x1b,
+            0x2f, 0x00, 0x16, 0x98, 0xe7, 0x46, 0x3b, 0x04,
+        ],
+        xprv: "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF",
+    },
+    TestVector {
+        entropy: &[
+            0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f,
+            0x7f, 0x7f,
+        ],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        seed: [
+            0x2e, 0x89, 0x05, 0x81, 0x9b, 0x87, 0x23, 0xfe, 0x2c, 0x1d, 0x16, 0x18, 0x60, 0xe5,
+            0xee, 0x18, 0x30, 0x31, 0x8d, 0xbf, 0x49, 0xa8, 0x3b, 0xd4, 0x51, 0xcf, 0xb8, 0x44,
+            0x0c, 0x28, 0xbd, 0x6f, 0xa4, 0x57, 0xfe, 0x12, 0x96, 0x10, 0x65, 0x59, 0xa3, 0xc8,
+            0x09, 0x37, 0xa1, 0xc1, 0x06, 0x9b, 0xe3, 0xa3, 0xa5, 0xbd, 0x38, 0x1e, 0xe6, 0x26,
+            0x0e, 0x8d, 0x97, 0x39, 0xfc, 0xe1, 0xf6, 0x07,
+        ],
+        xprv: "xprv9s21ZrQH143K2gA81bYFHqU68xz1cX2APaSq5tt6MFSLeXnCKV1RVUJt9FWNTbrrryem4ZckN8k4Ls1H6nwdvDTvnV7zEXs2HgPezuVccsq",
+    },
+    TestVector {
+        entropy: &[
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+            0x80, 0x80,
+        ],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        seed: [
+            0xd7, 0x1d, 0xe8, 0x56, 0xf8, 0x1a, 0x8a, 0xcc, 0x65, 0xe6, 0xfc, 0x85, 0x1a, 0x38,
+            0xd4, 0xd7, 0xec, 0x21, 0x6f, 0xd0, 0x79, 0x6d, 0x0a, 0x68, 0x27, 0xa3, 0xad, 0x6e,
+            0xd5, 0x51, 0x1a, 0x30, 0xfa, 0x28, 0x0f, 0x12, 0xeb, 0x2e, 0x47, 0xed, 0x2a, 0xc0,
+            0x3b, 0x5c, 0x46, 0x2a, 0x03, 0x58, 0xd1, 0x8d, 0x69, 0xfe, 0x4f, 0x98, 0x5e, 0xc8,
+            0x17, 0x78, 0xc1, 0xb3, 0x70, 0xb6, 0x52, 0xa8,
+        ],
+        xprv: "xprv9s21ZrQH143K2shfP28KM3nr5Ap1SXjz8gc2rAqqMEynmjt6o1qboCDpxckqXavCwdnYds6yBHZGKHv7ef2eTXy461PXUjBFQg6PrwY4Gzq",
+    },
+    TestVector {
+        entropy: &[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff,
+        ],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        seed: [
+            0xac, 0x27, 0x49, 0x54, 0x80, 0x22, 0x52, 0x22, 0x07, 0x9d, 0x7b, 0xe1, 0x81, 0x58,
+            0x37, 0x51, 0xe8, 0x6f, 0x57, 0x10, 0x27, 0xb0, 0x49, 0x7b, 0x5b, 0x5d, 0x11, 0x21,
+            0x8e, 0x0a, 0x8a, 0x13, 0x33, 0x25, 0x72, 0x91, 0x7f, 0x0f, 0x8e, 0x5a, 0x58, 0x96,
+            0x20, 0xc6, 0xf1, 0x5b, 0x11, 0xc6, 0x1d, 0xee, 0x32, 0x76, 0x51, 0xa1, 0x4c, 0x34,
+            0xe1, 0x82, 0x31, 0x05, 0x2e, 0x48, 0xc0, 0x69,
+        ],
+        xprv: "xprv9s21ZrQH143K2V4oox4M8Zmhi2Fjx5XK4Lf7GKRvPSgydU3mjZuKGCTg7UPiBUD7ydVPvSLtg9hjp7MQTYsW67rZHAXeccqYqrsx8LcXnyd",
+    },
+    TestVector {
+        entropy: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        seed: [
+            0x03, 0x58, 0x95, 0xf2, 0xf4, 0x81, 0xb1, 0xb0, 0xf0, 0x1f, 0xcf, 0x8c, 0x28, 0x9c,
+            0x79, 0x46, 0x60, 0xb2, 0x89, 0x98, 0x1a, 0x78, 0xf8, 0x10, 0x64, 0x47, 0x70, 0x7f,
+            0xdd, 0x96, 0x66, 0xca, 0x06, 0xda, 0x5a, 0x9a, 0x56, 0x51, 0x81, 0x59, 0x9b, 0x79,
+            0xf5, 0x3b, 0x84, 0x4d, 0x8a, 0x71, 0xdd, 0x9f, 0x43, 0x9c, 0x52, 0xa3, 0xd7, 0xb3,
+            0xe8, 0xa7, 0x9c, 0x90, 0x6a, 0xc8, 0x45, 0xfa,
+        ],
+        xprv: "xprv9s21ZrQH143K3mEDrypcZ2usWqFgzKB6jBBx9B6GfC7fu26X6hPRzVjzkqkPvDqp6g5eypdk6cyhGnBngbjeHTe4LsuLG1cCmKJka5SMkmU",
+    },
+    TestVector {
+        entropy: &[
+            0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f,
+            0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f,
+        ],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+        seed: [
+            0xf2, 0xb9, 0x45, 0x08, 0x73, 0x2b, 0xcb, 0xac, 0xbc, 0xc0, 0x20, 0xfa, 0xef, 0xec,
+            0xfc, 0x89, 0xfe, 0xaf, 0xa6, 0x64, 0x9a, 0x54, 0x91, 0xb8, 0xc9, 0x52, 0xce, 0xde,
+            0x49, 0x6c, 0x21, 0x4a, 0x0c, 0x7b, 0x3c, 0x39, 0x2d, 0x16, 0x87, 0x48, 0xf2, 0xd4,
+            0xa6, 0x12, 0xba, 0xda, 0x07, 0x53, 0xb5, 0x2a, 0x1c, 0x7a, 0xc5, 0x3c, 0x1e, 0x93,
+            0xab, 0xd5, 0xc6, 0x32, 0x0b, 0x9e, 0x95, 0xdd,
+        ],
+        xprv: "xprv9s21ZrQH143K3Lv9MZLj16np5GzLe7tDKQfVusBni7toqJGcnKRtHSxUwbKUyUWiwpK55g1DUSsw76TF1T93VT4gz4wt5RM23pkaQLnvBh7",
+    },
+    TestVector {
+        entropy: &[
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        ],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+        seed: [
+            0x10, 0x7d, 0x7c, 0x02, 0xa5, 0xaa, 0x6f, 0x38, 0xc5, 0x80, 0x83, 0xff, 0x74, 0xf0,
+            0x4c, 0x60, 0x7c, 0x2d, 0x2c, 0x0e, 0xcc, 0x55, 0x50, 0x1d, 0xad, 0xd7, 0x2d, 0x02,
+            0x5b, 0x75, 0x1b, 0xc2, 0x7f, 0xe9, 0x13, 0xff, 0xb7, 0x96, 0xf8, 0x41, 0xc4, 0x9b,
+            0x1d, 0x33, 0xb6, 0x10, 0xcf, 0x0e, 0x91, 0xd3, 0xaa, 0x23, 0x90, 0x27, 0xf5, 0xe9,
+            0x9f, 0xe4, 0xce, 0x9e, 0x50, 0x88, 0xcd, 0x65,
+        ],
+        xprv: "xprv9s21ZrQH143K3VPCbxbUtpkh9pRG371UCLDz3BjceqP1jz7XZsQ5EnNkYAEkfeZp62cDNj13ZTEVG1TEro9sZ9grfRmcYWLBhCocViKEJae",
+    },
+    TestVector {
+        entropy: &[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+        seed: [
+            0x0c, 0xd6, 0xe5, 0xd8, 0x27, 0xbb, 0x62, 0xeb, 0x8f, 0xc1, 0xe2, 0x62, 0x25, 0x42,
+            0x23, 0x81, 0x7f, 0xd0, 0x68, 0xa7, 0x4b, 0x5b, 0x44, 0x9c, 0xc2, 0xf6, 0x67, 0xc3,
+            0xf1, 0xf9, 0x85, 0xa7, 0x63, 0x79, 0xb4, 0x33, 0x48, 0xd9, 0x52, 0xe2, 0x26, 0x5b,
+            0x4c, 0xd1, 0x29, 0x09, 0x07, 0x58, 0xb3, 0xe3, 0xc2, 0xc4, 0x91, 0x03, 0xb5, 0x05,
+            0x1a, 0xac, 0x2e, 0xae, 0xb8, 0x90, 0xa5, 0x28,
+        ],
+        xprv: "xprv9s21ZrQH143K36Ao5jHRVhFGDbLP6FCx8BEEmpru77ef3bmA928BxsqvVM27WnvvyfWywiFN8K6yToqMaGYfzS6Db1EHAXT5TuyCLBXUfdm",
+    },
+    TestVector {
+        entropy: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        seed: [
+            0xbd, 0xa8, 0x54, 0x46, 0xc6, 0x84, 0x13, 0x70, 0x70, 0x90, 0xa5, 0x20, 0x22, 0xed,
+            0xd2, 0x6a, 0x1c, 0x94, 0x62, 0x29, 0x50, 0x29, 0xf2, 0xe6, 0x0c, 0xd7, 0xc4, 0xf2,
+            0xbb, 0xd3, 0x09, 0x71, 0x70, 0xaf, 0x7a, 0x4d, 0x73, 0x24, 0x5c, 0xaf, 0xa9, 0xc3,
+            0xcc, 0xa8, 0xd5, 0x61, 0xa7, 0xc3, 0xde, 0x6f, 0x5d, 0x4a, 0x10, 0xbe, 0x8e, 0xd2,
+            0xa5, 0xe6, 0x08, 0xd6, 0x8f, 0x92, 0xfc, 0xc8,
+        ],
+        xprv: "xprv9s21ZrQH143K32qBagUJAMU2LsHg3ka7jqMcV98Y7gVeVyNStwYS3U7yVVoDZ4btbRNf4h6ibWpY22iRmXq35qgLs79f312g2kj5539ebPM",
+    },
+    TestVector {
+        entropy: &[
+            0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f,
+            0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f,
+            0x7f, 0x7f, 0x7f, 0x7f,
+        ],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        seed: [
+            0xbc, 0x09, 0xfc, 0xa1, 0x80, 0x4f, 0x7e, 0x69, 0xda, 0x93, 0xc2, 0xf2, 0x02, 0x8e,
+            0xb2, 0x38, 0xc2, 0x27, 0xf2, 0xe9, 0xdd, 0xa3, 0x0c, 0xd6, 0x36, 0x99, 0x23, 0x25,
+            0x78, 0x48, 0x0a, 0x40, 0x21, 0xb1, 0x46, 0xad, 0x71, 0x7f, 0xbb, 0x7e, 0x45, 0x1c,
+            0xe9, 0xeb, 0x83, 0x5f, 0x43, 0x62, 0x0b, 0xf5, 0xc5, 0x14, 0xdb, 0x0f, 0x8a, 0xdd,
+            0x49, 0xf5, 0xd1, 0x21, 0x44, 0x9d, 0x3e, 0x87,
+        ],
+        xprv: "xprv9s21ZrQH143K3Y1sd2XVu9wtqxJRvybCfAetjUrMMco6r3v9qZTBeXiBZkS8JxWbcGJZyio8TrZtm6pkbzG8SYt1sxwNLh3Wx7to5pgiVFU",
+    },
+    TestVector {
+        entropy: &[
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+            0x80, 0x80, 0x80, 0x80,
+        ],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        seed: [
+            0xc0, 0xc5, 0x19, 0xbd, 0x0e, 0x91, 0xa2, 0xed, 0x54, 0x35, 0x7d, 0x9d, 0x1e, 0xbe,
+            0xf6, 0xf5, 0xaf, 0x21, 0x8a, 0x15, 0x36, 0x24, 0xcf, 0x4f, 0x2d, 0xa9, 0x11, 0xa0,
+            0xed, 0x8f, 0x7a, 0x09, 0xe2, 0xef, 0x61, 0xaf, 0x0a, 0xca, 0x00, 0x70, 0x96, 0xdf,
+            0x43, 0x00, 0x22, 0xf7, 0xa2, 0xb6, 0xfb, 0x91, 0x66, 0x1a, 0x95, 0x89, 0x09, 0x70,
+            0x69, 0x72, 0x0d, 0x01, 0x5e, 0x4e, 0x98, 0x2f,
+        ],
+        xprv: "xprv9s21ZrQH143K3CSnQNYC3MqAAqHwxeTLhDbhF43A4ss4ciWNmCY9zQGvAKUSqVUf2vPHBTSE1rB2pg4avopqSiLVzXEU8KziNnVPauTqLRo",
+    },
+    TestVector {
+        entropy: &[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff,
+        ],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        seed: [
+            0xdd, 0x48, 0xc1, 0x04, 0x69, 0x8c, 0x30, 0xcf, 0xe2, 0xb6, 0x14, 0x21, 0x03, 0x24,
+            0x86, 0x22, 0xfb, 0x7b, 0xb0, 0xff, 0x69, 0x2e, 0xeb, 0xb0, 0x00, 0x89, 0xb3, 0x2d,
+            0x22, 0x48, 0x4e, 0x16, 0x13, 0x91, 0x2f, 0x0a, 0x5b, 0x69, 0x44, 0x07, 0xbe, 0x89,
+            0x9f, 0xfd, 0x31, 0xed, 0x39, 0x92, 0xc4, 0x56, 0xcd, 0xf6, 0x0f, 0x5d, 0x45, 0x64,
+            0xb8, 0xba, 0x3f, 0x05, 0xa6, 0x98, 0x90, 0xad,
+        ],
+        xprv: "xprv9s21ZrQH143K2WFF16X85T2QCpndrGwx6GueB72Zf3AHwHJaknRXNF37ZmDrtHrrLSHvbuRejXcnYxoZKvRquTPyp2JiNG3XcjQyzSEgqCB",
+    },
+    TestVector {
+        entropy: &[
+            0x9e, 0x88, 0x5d, 0x95, 0x2a, 0xd3, 0x62, 0xca, 0xeb, 0x4e, 0xfe, 0x34, 0xa8, 0xe9,
+            0x1b, 0xd2,
+        ],
+        phrase: "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+        seed: [
+            0x27, 0x4d, 0xdc, 0x52, 0x58, 0x02, 0xf7, 0xc8, 0x28, 0xd8, 0xef, 0x7d, 0xdb, 0xcd,
+            0xc5, 0x30, 0x4e, 0x87, 0xac, 0x35, 0x35, 0x91, 0x36, 0x11, 0xfb, 0xbf, 0xa9, 0x86,
+            0xd0, 0xc9, 0xe5, 0x47, 0x6c, 0x91, 0x68, 0x9f, 0x9c, 0x8a, 0x54, 0xfd, 0x55, 0xbd,
+            0x38, 0x60, 0x6a, 0xa6, 0xa8, 0x59, 0x5a, 0xd2, 0x13, 0xd4, 0xc9, 0xc9, 0xf9, 0xac,
+            0xa3, 0xfb, 0x21, 0x70, 0x69, 0xa4, 0x10, 0x28,
+        ],
+        xprv: "xprv9s21ZrQH143K2oZ9stBYpoaZ2ktHj7jLz7iMqpgg1En8kKFTXJHsjxry1JbKH19YrDTicVwKPehFKTbmaxgVEc5TpHdS1aYhB2s9aFJBeJH",
+    },
+    TestVector {
+        entropy: &[
+            0x66, 0x10, 0xb2, 0x59, 0x67, 0xcd, 0xcc, 0xa9, 0xd5, 0x98, 0x75, 0xf5, 0xcb, 0x50,
+            0xb0, 0xea, 0x75, 0x43, 0x33, 0x11, 0x86, 0x9e, 0x93, 0x0b,
+        ],
+        phrase: "gravity machine north sort system female filter attitude volume fold club stay feature office ecology stable narrow fog",
+        seed: [
+            0x62, 0x8c, 0x38, 0x27, 0xa8, 0x82, 0x32, 0x98, 0xee, 0x68, 0x5d, 0xb8, 0x4f, 0x55,
+            0xca, 0xa3, 0x4b, 0x5c, 0xc1, 0x95, 0xa7, 0x78, 0xe5, 0x2d, 0x45, 0xf5, 0x9b, 0xcf,
+            0x75, 0xab, 0xa6, 0x8e, 0x4d, 0x75, 0x90, 0xe1, 0x01, 0xdc, 0x41, 0x4b, 0xc1, 0xbb,
+            0xd5, 0x73, 0x76, 0x66, 0xfb, 0xbe, 0xf3, 0x5d, 0x1f, 0x19, 0x03, 0x95, 0x3b, 0x66,
+            0x62, 0x4f, 0x91, 0x0f, 0xee, 0xf2, 0x45, 0xac,
+        ],
+        xprv: "xprv9s21ZrQH143K3uT8eQowUjsxrmsA9YUuQQK1RLqFufzybxD6DH6gPY7NjJ5G3EPHjsWDrs9iivSbmvjc9DQJbJGatfa9pv4MZ3wjr8qWPAK",
+    },
+    TestVector {
+        entropy: &[
+            0x68, 0xa7, 0x9e, 0xac, 0xa2, 0x32, 0x48, 0x73, 0xea, 0xcc, 0x50, 0xcb, 0x9c, 0x6e,
+            0xca, 0x8c, 0xc6, 0x8e, 0xa5, 0xd9, 0x36, 0xf9, 0x87, 0x87, 0xc6, 0x0c, 0x7e, 0xbc,
+            0x74, 0xe6, 0xce, 0x7c,
+        ],
+        phrase: "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        seed: [
+            0x64, 0xc8, 0x7c, 0xde, 0x7e, 0x12, 0xec, 0xf6, 0x70, 0x4a, 0xb9, 0x5b, 0xb1, 0x40,
+            0x8b, 0xef, 0x04, 0x7c, 0x22, 0xdb, 0x4c, 0xc7, 0x49, 0x1c, 0x42, 0x71, 0xd1, 0x70,
+            0xa1, 0xb2, 0x13, 0xd2, 0x0b, 0x38, 0x5b, 0xc1, 0x58, 0x8d, 0x9c, 0x7b, 0x38, 0xf1,
+            0xb3, 0x9d, 0x41, 0x56, 0x65, 0xb8, 0xa9, 0x03, 0x0c, 0x9e, 0xc6, 0x53, 0xd7, 0x5e,
+            0x65, 0xf8, 0x47, 0xd8, 0xfc, 0x1f, 0xc4, 0x40,
+        ],
+        xprv: "xprv9s21ZrQH143K2XTAhys3pMNcGn261Fi5Ta2Pw8PwaVPhg3D8DWkzWQwjTJfskj8ofb81i9NP2cUNKxwjueJHHMQAnxtivTA75uUFqPFeWzk",
+    },
+    TestVector {
+        entropy: &[
+            0xc0, 0xba, 0x5a, 0x8e, 0x91, 0x41, 0x11, 0x21, 0x0f, 0x2b, 0xd1, 0x31, 0xf3, 0xd5,
+            0xe0, 0x8d,
+        ],
+        phrase: "scheme spot photo card baby mountain device kick cradle pact join borrow",
+        seed: [
+            0xea, 0x72, 0x58, 0x95, 0xaa, 0xae, 0x8d, 0x4c, 0x1c, 0xf6, 0x82, 0xc1, 0xbf, 0xd2,
+            0xd3, 0x58, 0xd5, 0x2e, 0xd9, 0xf0, 0xf0, 0x59, 0x11, 0x31, 0xb5, 0x59, 0xe2, 0x72,
+            0x4b, 0xb2, 0x34, 0xfc, 0xa0, 0x5a, 0xa9, 0xc0, 0x2c, 0x57, 0x40, 0x7e, 0x04, 0xee,
+            0x9d, 0xc3, 0xb4, 0x54, 0xaa, 0x63, 0xfb, 0xff, 0x48, 0x3a, 0x8b, 0x11, 0xde, 0x94,
+            0x96, 0x24, 0xb9, 0xf1, 0x83, 0x1a, 0x96, 0x12,
+        ],
+        xprv: "xprv9s21ZrQH143K3FperxDp8vFsFycKCRcJGAFmcV7umQmcnMZaLtZRt13QJDsoS5F6oYT6BB4sS6zmTmyQAEkJKxJ7yByDNtRe5asP2jFGhT6",
+    },
+    TestVector {
+        entropy: &[
+            0x6d, 0x9b, 0xe1, 0xee, 0x6e, 0xbd, 0x27, 0xa2, 0x58, 0x11, 0x5a, 0xad, 0x99, 0xb7,
+            0x31, 0x7b, 0x9c, 0x8d, 0x28, 0xb6, 0xd7, 0x64, 0x31, 0xc3,
+        ],
+        phrase: "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap uncle crack brave",
+        seed: [
+            0xfd, 0x57, 0x98, 0x28, 0xaf, 0x3d, 0xa1, 0xd3, 0x25, 0x44, 0xce, 0x4d, 0xb5, 0xc7,
+            0x3d, 0x53, 0xfc, 0x8a, 0xcc, 0x4d, 0xdb, 0x1e, 0x3b, 0x25, 0x1a, 0x31, 0x17, 0x9c,
+            0xdb, 0x71, 0xe8, 0x53, 0xc5, 0x6d, 0x2f, 0xcb, 0x11, 0xae, 0xd3, 0x98, 0x98, 0xce,
+            0x6c, 0x34, 0xb1, 0x0b, 0x53, 0x82, 0x77, 0x2d, 0xb8, 0x79, 0x6e, 0x52, 0x83, 0x7b,
+            0x54, 0x46, 0x8a, 0xeb, 0x31, 0x2c, 0xfc, 0x3d,
+        ],
+        xprv: "xprv9s21ZrQH143K3R1SfVZZLtVbXEB9ryVxmVtVMsMwmEyEvgXN6Q84LKkLRmf4ST6QrLeBm3jQsb9gx1uo23TS7vo3vAkZGZz71uuLCcywUkt",
+    },
+    TestVector {
+        entropy: &[
+            0x9f, 0x6a, 0x28, 0x78, 0xb2, 0x52, 0x07, 0x99, 0xa4, 0x4e, 0xf1, 0x8b, 0xc7, 0xdf,
+            0x39, 0x4e, 0x70, 0x61, 0xa2, 0x24, 0xd2, 0xc3, 0x3c, 0xd0, 0x15, 0xb1, 0x57, 0xd7,
+            0x46, 0x86, 0x98, 0x63,
+        ],
+        phrase: "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+        seed: [
+            0x72, 0xbe, 0x8e, 0x05, 0x2f, 0xc4, 0x91, 0x9d, 0x2a, 0xdf, 0x28, 0xd5, 0x30, 0x6b,
+            0x54, 0x74, 0xb0, 0x06, 0x9d, 0xf3, 0x5b, 0x02, 0x30, 0x3d, 0xe8, 0xc1, 0x72, 0x9c,
+            0x95, 0x38, 0xdb, 0xb6, 0xfc, 0x2d, 0x73, 0x1d, 0x5f, 0x83, 0x21, 0x93, 0xcd, 0x9f,
+            0xb6, 0xae, 0xec, 0xbc, 0x46, 0x95, 0x94, 0xa7, 0x0e, 0x3d, 0xd5, 0x08, 0x11, 0xb5,
+            0x06, 0x7f, 0x3b, 0x88, 0xb2, 0x8c, 0x3e, 0x8d,
+        ],
+        xprv: "xprv9s21ZrQH143K2WNnKmssvZYM96VAr47iHUQUTUyUXH3sAGNjhJANddnhw3i3y3pBbRAVk5M5qUGFr4rHbEWwXgX4qrvrceifCYQJbbFDems",
+    },
+    TestVector {
+        entropy: &[
+            0x23, 0xdb, 0x81, 0x60, 0xa3, 0x1d, 0x3e, 0x0d, 0xca, 0x36, 0x88, 0xed, 0x94, 0x1a,
+            0xdb, 0xf3,
+        ],
+        phrase: "cat swing flag economy stadium alone churn speed unique patch report train",
+        seed: [
+            0xde, 0xb5, 0xf4, 0x54, 0x49, 0xe6, 0x15, 0xfe, 0xff, 0x56, 0x40, 0xf2, 0xe4, 0x9f,
+            0x93, 0x3f, 0xf5, 0x18, 0x95, 0xde, 0x3b, 0x43, 0x81, 0x83, 0x2b, 0x31, 0x39, 0x94,
+            0x1c, 0x57, 0xb5, 0x92, 0x05, 0xa4, 0x24, 0x80, 0xc5, 0x21, 0x75, 0xb6, 0xef, 0xcf,
+            0xfa, 0xa5, 0x8a, 0x25, 0x03, 0x88, 0x7c, 0x1e, 0x8b, 0x36, 0x3a, 0x70, 0x72, 0x56,
+            0xbd, 0xd2, 0xb5, 0x87, 0xb4, 0x65, 0x41, 0xf5,
+        ],
+        xprv: "xprv9s21ZrQH143K4G28omGMogEoYgDQuigBo8AFHAGDaJdqQ99QKMQ5J6fYTMfANTJy6xBmhvsNZ1CJzRZ64PWbnTFUn6CDV2FxoMDLXdk95DQ",
+    },
+    TestVector {
+        entropy: &[
+            0x81, 0x97, 0xa4, 0xa4, 0x7f, 0x04, 0x25, 0xfa, 0xea, 0xa6, 0x9d, 0xee, 0xbc, 0x05,
+            0xca, 0x29, 0xc0, 0xa5, 0xb5, 0xcc, 0x76, 0xce, 0xac, 0xc0,
+        ],
+        phrase: "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart sustain crack supply proud access",
+        seed: [
+            0x4c, 0xbd, 0xff, 0x1c, 0xa2, 0xdb, 0x80, 0x0f, 0xd6, 0x1c, 0xae, 0x72, 0xa5, 0x74,
+            0x75, 0xfd, 0xc6, 0xba, 0xb0, 0x3e, 0x44, 0x1f, 0xd6, 0x3f, 0x96, 0xda, 0xbd, 0x1f,
+            0x18, 0x3e, 0xf5, 0xb7, 0x82, 0x92, 0x5f, 0x00, 0x10, 0x5f, 0x31, 0x83, 0x09, 0xa7,
+            0xe9, 0xc3, 0xea, 0x69, 0x67, 0xc7, 0x80, 0x1e, 0x46, 0xc8, 0xa5, 0x80, 0x82, 0x67,
+            0x4c, 0x86, 0x0a, 0x37, 0xb9, 0x3e, 0xda, 0x02,
+        ],
+        xprv: "xprv9s21ZrQH143K3wtsvY8L2aZyxkiWULZH4vyQE5XkHTXkmx8gHo6RUEfH3Jyr6NwkJhvano7Xb2o6UqFKWHVo5scE31SGDCAUsgVhiUuUDyh",
+    },
+    TestVector {
+        entropy: &[
+            0x06, 0x6d, 0xca, 0x1a, 0x2b, 0xb7, 0xe8, 0xa1, 0xdb, 0x28, 0x32, 0x14, 0x8c, 0xe9,
+            0x93, 0x3e, 0xea, 0x0f, 0x3a, 0xc9, 0x54, 0x8d, 0x79, 0x31, 0x12, 0xd9, 0xa9, 0x5c,
+            0x94, 0x07, 0xef, 0xad,
+        ],
+        phrase: "all hour make first leader extend hole alien behind guard gospel lava path output census museum junior mass reopen famous sing advance salt reform",
+        seed: [
+            0x26, 0xe9, 0x75, 0xec, 0x64, 0x44, 0x23, 0xf4, 0xa4, 0xc4, 0xf4, 0x21, 0x5e, 0xf0,
+            0x9b, 0x4b, 0xd7, 0xef, 0x92, 0x4e, 0x85, 0xd1, 0xd1, 0x7c, 0x4c, 0xf3, 0xf1, 0x36,
+            0xc2, 0x86, 0x3c, 0xf6, 0xdf, 0x0a, 0x47, 0x50, 0x45, 0x65, 0x2c, 0x57, 0xeb, 0x5f,
+            0xb4, 0x15, 0x13, 0xca, 0x2a, 0x2d, 0x67, 0x72, 0x2b, 0x77, 0xe9, 0x54, 0xb4, 0xb3,
+            0xfc, 0x11, 0xf7, 0x59, 0x04, 0x49, 0x19, 0x1d,
+        ],
+        xprv: "xprv9s21ZrQH143K3rEfqSM4QZRVmiMuSWY9wugscmaCjYja3SbUD3KPEB1a7QXJoajyR2T1SiXU7rFVRXMV9XdYVSZe7JoUXdP4SRHTxsT1nzm",
+    },
+    TestVector {
+        entropy: &[
+            0xf3, 0x0f, 0x8c, 0x1d, 0xa6, 0x65, 0x47, 0x8f, 0x49, 0xb0, 0x01, 0xd9, 0x4c, 0x5f,
+            0xc4, 0x52,
+        ],
+        phrase: "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+        seed: [
+            0x2a, 0xaa, 0x92, 0x42, 0xda, 0xaf, 0xce, 0xe6, 0xaa, 0x9d, 0x72, 0x69, 0xf1, 0x7d,
+            0x4e, 0xfe, 0x27, 0x1e, 0x1b, 0x9a, 0x52, 0x91, 0x78, 0xd7, 0xdc, 0x13, 0x9c, 0xd1,
+            0x87, 0x47, 0x09, 0x0b, 0xf9, 0xd6, 0x02, 0x95, 0xd0, 0xce, 0x74, 0x30, 0x9a, 0x78,
+            0x85, 0x2a, 0x9c, 0xaa, 0xdf, 0x0a, 0xf4, 0x8a, 0xae, 0x1c, 0x62, 0x53, 0x83, 0x96,
+            0x24, 0x07, 0x62, 0x24, 0x37, 0x4b, 0xc6, 0x3f,
+        ],
+        xprv: "xprv9s21ZrQH143K2QWV9Wn8Vvs6jbqfF1YbTCdURQW9dLFKDovpKaKrqS3SEWsXCu6ZNky9PSAENg6c9AQYHcg4PjopRGGKmdD313ZHszymnps",
+    },
+    TestVector {
+        entropy: &[
+            0xc1, 0x0e, 0xc2, 0x0d, 0xc3, 0xcd, 0x9f, 0x65, 0x2c, 0x7f, 0xac, 0x2f, 0x12, 0x30,
+            0xf7, 0xa3, 0xc8, 0x28, 0x38, 0x9a, 0x14, 0x39, 0x2f, 0x05,
+        ],
+        phrase: "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little brisk hair mango congress clump",
+        seed: [
+            0x7b, 0x4a, 0x10, 0xbe, 0x9d, 0x98, 0xe6, 0xcb, 0xa2, 0x65, 0x56, 0x6d, 0xb7, 0xf1,
+            0x36, 0x71, 0x8e, 0x13, 0x98, 0xc7, 0x1c, 0xb5, 0x81, 0xe1, 0xb2, 0xf4, 0x64, 0xca,
+            0xc1, 0xce, 0xed, 0xf4, 0xf3, 0xe2, 0x74, 0xdc, 0x27, 0x00, 0x03, 0xc6, 0x70, 0xad,
+            0x8d, 0x02, 0xc4, 0x55, 0x8b, 0x2f, 0x8e, 0x39, 0xed, 0xea, 0x27, 0x75, 0xc9, 0xe2,
+            0x32, 0xc7, 0xcb, 0x79, 0x8b, 0x06, 0x9e, 0x88,
+        ],
+        xprv: "xprv9s21ZrQH143K4aERa2bq7559eMCCEs2QmmqVjUuzfy5eAeDX4mqZffkYwpzGQRE2YEEeLVRoH4CSHxianrFaVnMN2RYaPUZJhJx8S5j6puX",
+    },
+    TestVector {
+        entropy: &[
+            0xf5, 0x85, 0xc1, 0x1a, 0xec, 0x52, 0x0d, 0xb5, 0x7d, 0xd3, 0x53, 0xc6, 0x95, 0x54,
+            0xb2, 0x1a, 0x89, 0xb2, 0x0f, 0xb0, 0x65, 0x09, 0x66, 0xfa, 0x0a, 0x9d, 0x6f, 0x74,
+            0xfd, 0x98, 0x9d, 0x8f,
+        ],
+        phrase: "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+        seed: [
+            0x01, 0xf5, 0xbc, 0xed, 0x59, 0xde, 0xc4, 0x8e, 0x36, 0x2f, 0x2c, 0x45, 0xb5, 0xde,
+            0x68, 0xb9, 0xfd, 0x6c, 0x92, 0xc6, 0x63, 0x4f, 0x44, 0xd6, 0xd4, 0x0a, 0xab, 0x69,
+            0x05, 0x65, 0x06, 0xf0, 0xe3, 0x55, 0x24, 0xa5, 0x18, 0x03, 0x4d, 0xdc, 0x11, 0x92,
+            0xe1, 0xda, 0xcd, 0x32, 0xc1, 0xed, 0x3e, 0xaa, 0x3c, 0x3b, 0x13, 0x1c, 0x88, 0xed,
+            0x8e, 0x7e, 0x54, 0xc4, 0x9a, 0x5d, 0x09, 0x98,
+        ],
+        xprv: "xprv9s21ZrQH143K39rnQJknpH1WEPFJrzmAqqasiDcVrNuk926oizzJDDQkdiTvNPr2FYDYzWgiMiC63YmfPAa2oPyNB23r2g7d1yiK6WpqaQS",
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seed::Seed;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn vectors() {
+        assert_eq!(TREZOR.len(), 24);
+
+        for vector in TREZOR {
+            let mnemonic = Mnemonic::from_entropy(vector.entropy, Language::English).unwrap();
+
+            assert_eq!(mnemonic.phrase(), vector.phrase);
+            assert_eq!(
+                Seed::new(&mnemonic, TREZOR_PASSPHRASE).as_bytes(),
+                &vector.seed[..]
+            );
+        }
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "bip32")]
+    fn root_keys() {
+        use crate::bip32::Network;
+
+        for vector in TREZOR {
+            let seed = Seed::new(&vector.mnemonic(), TREZOR_PASSPHRASE);
+
+            assert_eq!(
+                &seed.to_root_xprv(Network::Mainnet).unwrap()[..],
+                vector.xprv
+            );
+        }
+    }
+}
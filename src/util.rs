@@ -1,4 +1,9 @@
-use unicode_normalization::Decompositions;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{Decompositions, UnicodeNormalization};
+use zeroize::{Zeroize, Zeroizing};
 
 pub(crate) trait IterExt: Iterator {
     fn join<R>(&mut self, glue: &str) -> R
@@ -18,7 +23,7 @@ pub(crate) trait IterExt: Iterator {
         first.write_into(&mut buffer);
 
         for item in self {
-            buffer.push_str(glue);
+            glue.write_into(&mut buffer);
             item.write_into(&mut buffer);
         }
 
@@ -42,6 +47,7 @@ pub(crate) trait Joinable {
 /// Allow iterator joining on str slices
 impl Joinable for &str {
     fn write_into(self, buf: &mut String) {
+        reserve_zeroizing(buf, self.len());
         buf.push_str(self);
     }
 }
@@ -49,8 +55,37 @@ impl Joinable for &str {
 /// Allow iterator joining on unicode_normalization iterators
 impl<I: Iterator<Item = char>> Joinable for Decompositions<I> {
     fn write_into(self, buf: &mut String) {
-        buf.extend(self);
+        for ch in self {
+            reserve_zeroizing(buf, ch.len_utf8());
+            buf.push(ch);
+        }
+    }
+}
+
+/// Make room for `additional` bytes in `buf`.
+///
+/// Unlike `String::reserve`, the old allocation is wiped before it's freed, so growing a buffer
+/// that holds a phrase or passphrase never leaves a stray copy of it on the heap.
+pub(crate) fn reserve_zeroizing(buf: &mut String, additional: usize) {
+    if buf.capacity() - buf.len() >= additional {
+        return;
     }
+
+    let mut grown = String::with_capacity(cmp::max(buf.capacity() * 2, buf.len() + additional));
+
+    grown.push_str(buf);
+    buf.zeroize();
+
+    *buf = grown;
+}
+
+/// NFKD normalize a secret string into a buffer that is zeroed when dropped
+pub(crate) fn nfkd_zeroizing(source: &str) -> Zeroizing<String> {
+    let mut normalized = Zeroizing::new(String::new());
+
+    source.nfkd().write_into(&mut normalized);
+
+    normalized
 }
 
 impl<I: Iterator> IterExt for I {}
@@ -69,7 +104,7 @@ impl Bits for u8 {
     }
 }
 
-impl<'a> Bits for &'a u8 {
+impl Bits for &u8 {
     const SIZE: usize = 8;
 
     fn bits(self) -> u32 {
@@ -108,11 +143,7 @@ pub(crate) struct BitWriter {
 
 impl BitWriter {
     pub fn with_capacity(capacity: usize) -> Self {
-        let mut bytes = capacity / 8;
-
-        if capacity % 8 != 0 {
-            bytes += 1;
-        }
+        let bytes = capacity.div_ceil(8);
 
         Self {
             offset: 0,
@@ -148,7 +179,7 @@ impl BitWriter {
 }
 
 pub(crate) struct BitIter<In: Bits, Out: Bits, I: Iterator<Item = In> + Sized> {
-    _phantom: ::std::marker::PhantomData<Out>,
+    _phantom: ::core::marker::PhantomData<Out>,
     source: I,
     read: usize,
     buffer: u64,
@@ -164,7 +195,7 @@ where
         let source = source.into_iter();
 
         BitIter {
-            _phantom: ::std::marker::PhantomData,
+            _phantom: ::core::marker::PhantomData,
             source,
             read: 0,
             buffer: 0,
@@ -206,9 +237,102 @@ where
     }
 }
 
+/// Words in a phrase can be separated by an ASCII space, or the ideographic space used by Japanese
+pub(crate) fn is_word_separator(c: char) -> bool {
+    c == ' ' || c == '\u{3000}'
+}
+
+#[cfg(any(
+    test,
+    feature = "monero",
+    feature = "slip39",
+    feature = "compressed-wordlists"
+))]
+pub(crate) use self::lazy::Lazy;
+
+#[cfg(any(
+    test,
+    feature = "monero",
+    feature = "slip39",
+    feature = "compressed-wordlists"
+))]
+mod lazy {
+    use alloc::boxed::Box;
+    use core::ops::Deref;
+    use once_cell::race::OnceBox;
+
+    /// A value computed on first access, like `once_cell::sync::Lazy`, but also available without
+    /// `std`
+    ///
+    /// If several threads get there at the same time, more than one of them may compute the value,
+    /// but only one of the results is kept.
+    pub(crate) struct Lazy<T> {
+        cell: OnceBox<T>,
+        init: fn() -> T,
+    }
+
+    impl<T> Lazy<T> {
+        pub const fn new(init: fn() -> T) -> Self {
+            Lazy {
+                cell: OnceBox::new(),
+                init,
+            }
+        }
+    }
+
+    impl<T> Deref for Lazy<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.cell.get_or_init(|| Box::new((self.init)()))
+        }
+    }
+}
+
+/// The characters of an NFKD normalized `word` without its combining marks, i.e. its accents
+pub(crate) fn unaccented(word: &str) -> impl Iterator<Item = char> + '_ {
+    word.chars().filter(|&c| canonical_combining_class(c) == 0)
+}
+
+/// Levenshtein distance between two strings, counted in `char`s
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+
+            diagonal = row[j + 1];
+            row[j + 1] = cmp::min(substitution, cmp::min(row[j], row[j + 1]) + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
 /// Extract the first `bits` from the `source` byte
 pub(crate) fn checksum(source: u8, bits: u8) -> u8 {
     debug_assert!(bits <= 8, "Can operate on 8-bit integers only");
 
     source >> (8 - bits)
 }
+
+/// CRC-32 with the reflected polynomial `poly`, computed bit by bit as checksummed inputs are tiny
+#[cfg(any(feature = "monero", feature = "aezeed", feature = "sskr"))]
+pub(crate) fn crc32(poly: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (poly & (!(crc & 1)).wrapping_add(1));
+        }
+    }
+
+    !crc
+}
@@ -0,0 +1,235 @@
+use crate::error::SecretWord;
+use crate::language::Language;
+use alloc::vec::Vec;
+
+/// Controls how strictly [`Mnemonic::validate_with()`][Mnemonic::validate_with()] checks a phrase
+///
+/// The [`Default`][Default] matches [`Mnemonic::validate()`][Mnemonic::validate()]: words must be
+/// spelled exactly as in the wordlist, any amount of whitespace is allowed between them, and the
+/// checksum is verified.
+///
+/// Hardware-wallet firmware will want [`ValidationOptions::strict()`][ValidationOptions::strict()],
+/// while a recovery tool dealing with hand-typed backups may prefer
+/// [`ValidationOptions::permissive()`][ValidationOptions::permissive()]. Individual options can be
+/// changed on top of any of these.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, Language, ValidationOptions};
+///
+/// let phrase = "Park remain person kitchen mule spell knee armed position rail grid ankle";
+///
+/// assert!(Mnemonic::validate_with(phrase, Language::English, &ValidationOptions::default()).is_err());
+///
+/// let options = ValidationOptions::default().case_sensitive(false);
+///
+/// assert!(Mnemonic::validate_with(phrase, Language::English, &options).is_ok());
+/// ```
+///
+/// [Mnemonic::validate()]: ./mnemonic/struct.Mnemonic.html#method.validate
+/// [Mnemonic::validate_with()]: ./mnemonic/struct.Mnemonic.html#method.validate_with
+/// [ValidationOptions::strict()]: ./validation/struct.ValidationOptions.html#method.strict
+/// [ValidationOptions::permissive()]: ./validation/struct.ValidationOptions.html#method.permissive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationOptions {
+    pub(crate) case_sensitive: bool,
+    pub(crate) whitespace_tolerant: bool,
+    pub(crate) verify_checksum: bool,
+    pub(crate) expand_prefixes: bool,
+    pub(crate) accent_insensitive: bool,
+}
+
+impl ValidationOptions {
+    /// Only accept a phrase exactly as this crate would produce it: words as spelled in the
+    /// wordlist, separated by single spaces, with a valid checksum.
+    pub fn strict() -> ValidationOptions {
+        ValidationOptions {
+            case_sensitive: true,
+            whitespace_tolerant: false,
+            verify_checksum: true,
+            expand_prefixes: false,
+            accent_insensitive: false,
+        }
+    }
+
+    /// Accept words in any case and with or without accents, separated by any whitespace, expand
+    /// abbreviated words, and don't verify the checksum.
+    ///
+    /// **Note:** a phrase that passes this isn't necessarily a valid BIP39 mnemonic, it only
+    /// consists of known words in a standard number.
+    pub fn permissive() -> ValidationOptions {
+        ValidationOptions {
+            case_sensitive: false,
+            whitespace_tolerant: true,
+            verify_checksum: false,
+            expand_prefixes: true,
+            accent_insensitive: true,
+        }
+    }
+
+    /// Whether words must match the case used by the wordlist (`true` by default)
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> ValidationOptions {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Whether leading, trailing or repeated whitespace of any kind is allowed between words
+    /// (`true` by default)
+    ///
+    /// When `false`, only single ASCII spaces (or ideographic spaces) may separate the words.
+    pub fn whitespace_tolerant(mut self, whitespace_tolerant: bool) -> ValidationOptions {
+        self.whitespace_tolerant = whitespace_tolerant;
+        self
+    }
+
+    /// Whether the checksum must be valid (`true` by default)
+    ///
+    /// This only applies to [`Mnemonic::validate_with()`][Mnemonic::validate_with()], a
+    /// [`Mnemonic`][Mnemonic] can never be created from a phrase with an invalid checksum.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::validate_with()]: ./mnemonic/struct.Mnemonic.html#method.validate_with
+    pub fn verify_checksum(mut self, verify_checksum: bool) -> ValidationOptions {
+        self.verify_checksum = verify_checksum;
+        self
+    }
+
+    /// Whether abbreviated words are expanded to the full word they are a prefix of (`false` by
+    /// default)
+    ///
+    /// BIP39 wordlists are designed so that the first four letters identify a word, which is why
+    /// many backups only store those. An abbreviation is only expanded if it's at least four
+    /// characters long and matches exactly one word, e.g. `"aban"` becomes `"abandon"`.
+    pub fn expand_prefixes(mut self, expand_prefixes: bool) -> ValidationOptions {
+        self.expand_prefixes = expand_prefixes;
+        self
+    }
+
+    /// Whether words may be typed without their accents (`false` by default)
+    ///
+    /// The Spanish and French wordlists are designed so that no two words differ only by their
+    /// accents, so `"arbol"` can only be `"árbol"`. Words that aren't in the wordlist as typed are
+    /// looked up again with the accents removed from both, together with
+    /// [`ValidationOptions::expand_prefixes()`][ValidationOptions::expand_prefixes()] this also
+    /// expands unaccented abbreviations.
+    ///
+    /// [ValidationOptions::expand_prefixes()]: ./validation/struct.ValidationOptions.html#method.expand_prefixes
+    pub fn accent_insensitive(mut self, accent_insensitive: bool) -> ValidationOptions {
+        self.accent_insensitive = accent_insensitive;
+        self
+    }
+}
+
+impl Default for ValidationOptions {
+    fn default() -> ValidationOptions {
+        ValidationOptions {
+            case_sensitive: true,
+            whitespace_tolerant: true,
+            verify_checksum: true,
+            expand_prefixes: false,
+            accent_insensitive: false,
+        }
+    }
+}
+
+/// A single problem found by [`Mnemonic::diagnose()`][Mnemonic::diagnose()]
+///
+/// [Mnemonic::diagnose()]: ./mnemonic/struct.Mnemonic.html#method.diagnose
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    /// The word at `index` isn't in the wordlist of any supported language, its
+    /// [`SecretWord::suggestions()`][suggestions] are the closest words from the expected wordlist
+    ///
+    /// [suggestions]: ./struct.SecretWord.html#method.suggestions
+    InvalidWord { word: SecretWord, index: usize },
+    /// The word at `index` isn't in the expected wordlist, but it is in the one of `language`
+    ForeignWord {
+        word: SecretWord,
+        index: usize,
+        language: Language,
+    },
+    /// The phrase doesn't have 12, 15, 18, 21 or 24 words
+    InvalidWordCount(usize),
+    /// All words are valid, but the checksum doesn't match
+    InvalidChecksum,
+    /// The phrase is longer than [`Mnemonic::MAX_PHRASE_LEN`][MAX_PHRASE_LEN] bytes, given with
+    /// its length, in which case nothing else is checked
+    ///
+    /// [MAX_PHRASE_LEN]: ./mnemonic/struct.Mnemonic.html#associatedconstant.MAX_PHRASE_LEN
+    PhraseTooLong(usize),
+}
+
+/// Every problem found in a phrase by [`Mnemonic::diagnose()`][Mnemonic::diagnose()]
+///
+/// Problems with individual words are listed first, in the order the words appear in the phrase.
+/// The checksum is only verified if all words are valid and there is a standard number of them,
+/// as it's meaningless otherwise.
+///
+/// [Mnemonic::diagnose()]: ./mnemonic/struct.Mnemonic.html#method.diagnose
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    pub(crate) issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Whether the phrase is a valid mnemonic, i.e. no problems were found
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// All problems found, see [`ValidationReport`][ValidationReport] for the order
+    ///
+    /// [ValidationReport]: ./validation/struct.ValidationReport.html
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+}
+
+/// The outcome of [`Mnemonic::confirm_backup()`][Mnemonic::confirm_backup()], telling only as
+/// much about the mnemonic as a user re-typing it needs to know
+///
+/// [Mnemonic::confirm_backup()]: ./mnemonic/struct.Mnemonic.html#method.confirm_backup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupCheck {
+    /// The backup is the same phrase as the mnemonic
+    Match,
+    /// The backup doesn't have as many words as the mnemonic
+    WordCountMismatch,
+    /// The backup has as many words as the mnemonic, but some of them are different
+    Mismatch,
+    /// The same as `Mismatch`, with the index of the first word that's different, only reported
+    /// by [`Mnemonic::confirm_backup_with_position()`][Mnemonic::confirm_backup_with_position()]
+    ///
+    /// [Mnemonic::confirm_backup_with_position()]: ./mnemonic/struct.Mnemonic.html#method.confirm_backup_with_position
+    MismatchAt(usize),
+}
+
+/// A known-weak pattern found in a valid phrase by [`Mnemonic::audit()`][Mnemonic::audit()]
+///
+/// [Mnemonic::audit()]: ./mnemonic/struct.Mnemonic.html#method.audit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditWarning {
+    /// Every word but the last, which carries the checksum, is `word`
+    RepeatedWord(&'static str),
+    /// The words before the last one have consecutive wordlist indices, counting up or down
+    SequentialWords,
+    /// Every byte of the entropy is the same, as in all zeros or all ones
+    ConstantEntropy(u8),
+}
+
+/// A word of a mnemonic that is in the wordlist of another language too, at another index, found
+/// by [`Mnemonic::homographs()`][Mnemonic::homographs()]
+///
+/// [Mnemonic::homographs()]: ./mnemonic/struct.Mnemonic.html#method.homographs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Homograph {
+    /// The position of the word in the phrase
+    pub index: usize,
+    /// The word, as spelled in both wordlists
+    pub word: &'static str,
+    /// The other language
+    pub language: Language,
+    /// The index of the word in the wordlist of `language`
+    pub other_index: u16,
+}
@@ -0,0 +1,53 @@
+//! `wasm_bindgen` bindings, so the crate can be used from JavaScript without writing a shim
+//!
+//! Only available on `wasm32` with the `wasm` feature enabled. Languages are passed as the
+//! language codes accepted by [`Language::from_language_code()`][from_language_code], and all
+//! errors are thrown as JavaScript `Error`s with the message of the [`Error`][Error].
+//!
+//! ```js
+//! import { generateMnemonic, validateMnemonic, mnemonicToSeed } from "tiny-bip39";
+//!
+//! const phrase = generateMnemonic(24, "en");
+//!
+//! if (validateMnemonic(phrase, "en")) {
+//!     const seed = mnemonicToSeed(phrase, "password", "en");
+//! }
+//! ```
+//!
+//! [from_language_code]: ../language/enum.Language.html#method.from_language_code
+//! [Error]: ../error/enum.Error.html
+
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+use alloc::string::String;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+fn language(code: &str) -> Result<Language, JsError> {
+    Language::from_language_code(code)
+        .ok_or_else(|| JsError::new(&format!("unknown language code: {}", code)))
+}
+
+/// Generate a new mnemonic phrase with `words` words, using entropy from `crypto.getRandomValues`
+#[wasm_bindgen(js_name = generateMnemonic)]
+pub fn generate_mnemonic(words: usize, lang: &str) -> Result<String, JsError> {
+    let mtype = MnemonicType::for_word_count(words)?;
+
+    Ok(Mnemonic::try_new(mtype, language(lang)?)?.into_phrase())
+}
+
+/// Check whether `phrase` is a valid mnemonic phrase, checksum included
+#[wasm_bindgen(js_name = validateMnemonic)]
+pub fn validate_mnemonic(phrase: &str, lang: &str) -> Result<bool, JsError> {
+    Ok(Mnemonic::validate(phrase, language(lang)?).is_ok())
+}
+
+/// Derive the 64 byte seed of a mnemonic phrase, returned as a `Uint8Array`
+#[wasm_bindgen(js_name = mnemonicToSeed)]
+pub fn mnemonic_to_seed(phrase: &str, password: &str, lang: &str) -> Result<Vec<u8>, JsError> {
+    let mnemonic = Mnemonic::from_phrase(phrase, language(lang)?)?;
+
+    Ok(Seed::new(&mnemonic, password).as_bytes().to_vec())
+}
@@ -0,0 +1,169 @@
+//! A backend passed to the `_with_backend` functions is used for the checksum, the seed and the
+//! HMACs of key derivation
+#![cfg(feature = "std")]
+
+use bip39::{
+    fixed, DefaultCrypto, Digest, Kdf, Language, Mnemonic, Seed, SeedDerivationParams, SeedHmac,
+};
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const PHRASE: &str = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+/// Counts the calls into it, and hands them over to the implementations of the crate
+#[derive(Default)]
+struct Counting {
+    digests: AtomicUsize,
+    derivations: AtomicUsize,
+    hmacs: AtomicUsize,
+}
+
+impl Counting {
+    fn count(counter: &AtomicUsize) -> usize {
+        counter.load(Ordering::SeqCst)
+    }
+}
+
+impl Digest for Counting {
+    fn sha256(&self, input: &[u8]) -> [u8; 32] {
+        self.digests.fetch_add(1, Ordering::SeqCst);
+        DefaultCrypto.sha256(input)
+    }
+}
+
+impl Kdf for Counting {
+    fn pbkdf2(
+        &self,
+        hmac: SeedHmac,
+        password: &[u8],
+        salt: &[u8],
+        rounds: NonZeroU32,
+        out: &mut [u8],
+    ) {
+        self.derivations.fetch_add(1, Ordering::SeqCst);
+        DefaultCrypto.pbkdf2(hmac, password, salt, rounds, out)
+    }
+
+    fn hmac_sha512(&self, key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+        self.hmacs.fetch_add(1, Ordering::SeqCst);
+        DefaultCrypto.hmac_sha512(key, data)
+    }
+}
+
+#[test]
+fn checksum_and_seed() {
+    let backend = Counting::default();
+    let expected = Seed::new(
+        &Mnemonic::from_phrase(PHRASE, Language::English).unwrap(),
+        "",
+    );
+
+    let mnemonic = Mnemonic::from_phrase_with_backend(PHRASE, Language::English, &backend).unwrap();
+    assert_eq!(Counting::count(&backend.digests), 1);
+
+    Mnemonic::validate_with_backend(PHRASE, Language::English, &backend).unwrap();
+    Mnemonic::from_entropy_with_backend(mnemonic.entropy(), Language::English, &backend).unwrap();
+    assert_eq!(Counting::count(&backend.digests), 3);
+
+    let params = SeedDerivationParams::bip39();
+    let seed = Seed::new_with_backend(&mnemonic, "", &params, &backend);
+    assert_eq!(Counting::count(&backend.derivations), 1);
+    assert_eq!(seed, expected);
+
+    // Nothing else goes through the backend
+    Seed::new(&mnemonic, "");
+    Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+    assert_eq!(Counting::count(&backend.derivations), 1);
+    assert_eq!(Counting::count(&backend.digests), 3);
+}
+
+#[test]
+fn dyn_backend() {
+    let backend: &dyn bip39::CryptoBackend = &Counting::default();
+
+    let mnemonic = Mnemonic::from_phrase_with_backend(PHRASE, Language::English, backend).unwrap();
+    let params = SeedDerivationParams::bip39();
+
+    assert_eq!(
+        Seed::new_with_backend(&mnemonic, "", &params, backend),
+        Seed::new(&mnemonic, "")
+    );
+}
+
+#[test]
+fn fixed() {
+    let backend = Counting::default();
+
+    let mut indices = [0; fixed::MAX_WORDS];
+    let mut entropy = [0; fixed::MAX_ENTROPY_BYTES];
+    fixed::phrase_to_entropy_with_backend(PHRASE, Language::English, &mut entropy, &backend)
+        .unwrap();
+    fixed::entropy_to_indices_with_backend(&entropy[..16], &mut indices, &backend).unwrap();
+    assert_eq!(Counting::count(&backend.digests), 2);
+
+    // Every round goes through the HMAC of the backend
+    let mut seed = [0; fixed::SEED_BYTES];
+    fixed::seed_with_backend(PHRASE, "", &mut seed, &backend);
+    assert_eq!(Counting::count(&backend.hmacs), 2048);
+
+    let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+    assert_eq!(&seed[..], Seed::new(&mnemonic, "").as_bytes());
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn bip32() {
+    let backend = Counting::default();
+    let seed = Mnemonic::from_phrase(PHRASE, Language::English)
+        .unwrap()
+        .to_seed("");
+
+    let key = seed
+        .derive_path_with_backend("m/44'/0'/0'/0/0", &backend)
+        .unwrap();
+    assert_eq!(Counting::count(&backend.hmacs), 6);
+    assert_eq!(
+        key.private_key(),
+        seed.derive_path("m/44'/0'/0'/0/0").unwrap().private_key()
+    );
+
+    let key = seed
+        .derive_ed25519_with_backend("m/44'/501'/0'/0'", &backend)
+        .unwrap();
+    assert_eq!(Counting::count(&backend.hmacs), 11);
+    assert_eq!(
+        key.private_key(),
+        seed.derive_ed25519("m/44'/501'/0'/0'")
+            .unwrap()
+            .private_key()
+    );
+}
+
+#[cfg(feature = "slip21")]
+#[test]
+fn slip21() {
+    let backend = Counting::default();
+    let seed = Mnemonic::from_phrase(PHRASE, Language::English)
+        .unwrap()
+        .to_seed("");
+    let path = ["SLIP-0021", "Master encryption key"];
+
+    let node = seed.slip21_node_with_backend(&path, &backend);
+    assert_eq!(Counting::count(&backend.hmacs), 3);
+    assert_eq!(node.key(), seed.slip21_node(&path).key());
+}
+
+#[cfg(feature = "bip85")]
+#[test]
+fn bip85() {
+    use bip39::bip85::Bip85;
+
+    let seed = Mnemonic::from_phrase(PHRASE, Language::English)
+        .unwrap()
+        .to_seed("");
+
+    let bip85 = Bip85::new_with_backend(&seed, Counting::default()).unwrap();
+    let entropy = bip85.entropy(32, 0).unwrap();
+
+    assert_eq!(entropy, Bip85::new(&seed).unwrap().entropy(32, 0).unwrap());
+}
@@ -0,0 +1,85 @@
+//! The public entry points return errors on any input, they never panic
+#![cfg(feature = "proptest")]
+
+use bip39::strategies::{any_language, any_mnemonic, any_mnemonic_type};
+use bip39::{entropy, fixed, recovery, seedqr};
+use bip39::{Language, Mnemonic, MnemonicType, Seed, ValidationOptions};
+use core::convert::TryFrom;
+use proptest::prelude::*;
+
+/// Arbitrary text, mostly words of the English list, so parsing gets past the first word
+fn phrase() -> impl Strategy<Value = String> {
+    let wordlist = &Language::English.wordlist().words()[..];
+    let word = prop_oneof![
+        3 => proptest::sample::select(wordlist).prop_map(String::from),
+        1 => any::<String>(),
+    ];
+
+    proptest::collection::vec(word, 0..30).prop_map(|words| words.join(" "))
+}
+
+proptest! {
+    #[test]
+    fn parsing(phrase in prop_oneof![phrase(), any::<String>()], lang in any_language()) {
+        let _ = Mnemonic::from_phrase(&phrase, lang);
+        let _ = Mnemonic::from_phrase_lenient(&phrase, lang);
+        let _ = Mnemonic::from_phrase_with(&phrase, lang, &ValidationOptions::permissive());
+        let _ = Mnemonic::validate(&phrase, lang);
+        let _ = Mnemonic::diagnose(&phrase, lang);
+        let _ = phrase.parse::<Mnemonic>();
+        let _ = Mnemonic::try_from(phrase.as_str());
+        let _ = Mnemonic::to_entropy_into(&phrase, lang, &mut [0; 32]);
+        let _ = Mnemonic::from_index_string(&phrase, lang);
+        let _ = Language::detect_all(&phrase);
+        let _ = Language::from_language_code(&phrase);
+        let _ = MnemonicType::for_phrase(&phrase);
+        let _ = recovery::missing_words(&phrase, lang);
+        let _ = seedqr::from_digits(&phrase, lang);
+        let _ = Mnemonic::from_entropy_base58check(&phrase, &[0x80], lang);
+        let _ = Mnemonic::from_entropy_bech32(&phrase, "bip39", lang);
+        let _ = Seed::from_base58check(&phrase, &[0x80]);
+        let _ = Seed::from_bech32(&phrase, "bip39");
+    }
+
+    #[test]
+    fn bytes(bytes in any::<Vec<u8>>(), lang in any_language(), mtype in any_mnemonic_type()) {
+        let _ = Mnemonic::from_entropy(&bytes, lang);
+        let _ = Mnemonic::try_from(&bytes[..]);
+        let _ = seedqr::from_compact(&bytes, lang);
+        let _ = entropy::from_dice_rolls(&bytes, mtype);
+        let _ = entropy::from_reader(&bytes[..], mtype);
+        let _ = fixed::phrase_to_entropy(&String::from_utf8_lossy(&bytes), lang, &mut [0; 32]);
+    }
+
+    #[test]
+    fn numbers(
+        size in any::<usize>(),
+        indices in any::<Vec<u16>>(),
+        lang in any_language(),
+        mtype in any_mnemonic_type(),
+    ) {
+        let _ = MnemonicType::for_word_count(size);
+        let _ = MnemonicType::for_key_size(size);
+        let _ = MnemonicType::for_entropy_len(size);
+        let _ = Mnemonic::from_indices(&indices, lang);
+        let _ = fixed::write_phrase(&indices, lang, &mut [0; 64]);
+        let _ = entropy::from_coin_flips(&vec![true; size % 512], mtype);
+    }
+
+    #[test]
+    fn mnemonics(
+        mnemonic in any_mnemonic(),
+        other in any_mnemonic(),
+        len in 0..64usize,
+        n in 0..8usize,
+    ) {
+        let _ = mnemonic.entropy_into(&mut vec![0; len]);
+        let _ = mnemonic.entropy_array::<16>();
+        let _ = mnemonic.entropy_bech32("");
+        let _ = mnemonic.confirm_backup(other.phrase());
+        let _ = mnemonic.confirm_backup_with_position(other.phrase());
+        let _ = Mnemonic::combine_xor(&[mnemonic.clone(), other]);
+        let _ = Mnemonic::combine_xor(&[]);
+        let _ = mnemonic.split_xor(n);
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "slip39")]
+
+use bip39::slip39::{combine, Share};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+// Test vectors from Trezor's `python-shamir-mnemonic`, all with the passphrase "TREZOR". An empty
+// master secret means the shares must be rejected.
+const VECTORS: &str = include_str!("fixtures/slip39-vectors.json");
+
+#[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_all() {
+    let vectors: Vec<(String, Vec<String>, String)> = serde_json::from_str(VECTORS).unwrap();
+
+    for (description, phrases, master_secret) in vectors {
+        let recovered = phrases
+            .iter()
+            .map(|phrase| Share::from_phrase(phrase))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|shares| combine(&shares, "TREZOR"));
+
+        if master_secret.is_empty() {
+            assert!(recovered.is_err(), "{}", description);
+        } else {
+            assert_eq!(
+                hex::encode(&recovered.unwrap()[..]),
+                master_secret,
+                "{}",
+                description
+            );
+
+            for phrase in &phrases {
+                assert_eq!(&Share::from_phrase(phrase).unwrap().to_phrase()[..], phrase);
+            }
+        }
+    }
+}
@@ -26,6 +26,16 @@ fn test_seed(phrase: &str, password: &str, expected_seed_hex: &str) {
     );
 }
 
+#[cfg(feature = "bip32")]
+fn test_xprv(phrase: &str, password: &str, expected_xprv: &str) {
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    let seed = Seed::new(&mnemonic, password);
+
+    let xprv = seed.to_root_xprv(bip39::bip32::Network::Mainnet).unwrap();
+
+    assert_eq!(&xprv[..], expected_xprv, "Wrong xprv for '{}'", phrase);
+}
+
 macro_rules! tests {
     ($([$entropy_hex:expr, $phrase:expr, $seed_hex:expr, $xprv:expr]),*) => {
         mod mnemonic_tests {
@@ -51,6 +61,19 @@ macro_rules! tests {
                 )*
             }
         }
+
+        #[cfg(feature = "bip32")]
+        mod xprv_tests {
+            #[cfg(target_arch = "wasm32")]
+            use wasm_bindgen_test::*;
+            #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+            #[cfg_attr(not(target_arch = "wasm32"), test)]
+            fn test_all() {
+                $(
+                    super::test_xprv($phrase, "TREZOR", $xprv);
+                )*
+            }
+        }
     };
 }
 
@@ -0,0 +1,94 @@
+//! The spans of the `tracing` feature carry word counts and languages, never secrets
+#![cfg(all(feature = "tracing", feature = "rand"))]
+
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+const PHRASE: &str = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+/// Writes down the name and fields of every span and event
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<String>>);
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let mut log = self.0.lock().unwrap();
+        log.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.0
+            .lock()
+            .unwrap()
+            .push_str(&format!("\n{}", span.metadata().name()));
+        span.record(&mut self.clone());
+
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        self.0.lock().unwrap().push_str("\nevent");
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn spans_leave_out_secrets() {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        Seed::new(&mnemonic, "hunter2");
+
+        let typo = PHRASE.replace("kitchen", "kitchn");
+        assert!(Mnemonic::validate(&typo, Language::English).is_err());
+
+        Mnemonic::new(MnemonicType::Words24, Language::English);
+        Mnemonic::new_with_rng(
+            &mut rand::thread_rng(),
+            MnemonicType::Words15,
+            Language::English,
+        );
+    });
+
+    let log = recorder.0.lock().unwrap().clone();
+
+    assert!(log.contains("from_phrase words=12 lang=English"), "{}", log);
+    assert!(log.contains("validate words=12 lang=English"), "{}", log);
+    assert!(log.contains("new_with_kdf words=12 kdf="), "{}", log);
+    assert!(log.contains("try_new words=24 lang=English"), "{}", log);
+    assert!(
+        log.contains("try_new_with_rng words=15 lang=English"),
+        "{}",
+        log
+    );
+    assert!(
+        log.contains("error=invalid word in phrase with index 3"),
+        "{}",
+        log
+    );
+
+    for secret in PHRASE.split(' ').chain(["kitchn", "hunter2"]) {
+        assert!(!log.contains(secret), "{:?} is in {}", secret, log);
+    }
+}
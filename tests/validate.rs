@@ -1,4 +1,4 @@
-use bip39::{Language, Mnemonic, MnemonicType};
+use bip39::{Error, Language, Mnemonic, MnemonicType, ValidationIssue, ValidationOptions};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
 
@@ -13,7 +13,7 @@ fn validate_language(lang: Language) {
 
     for mtype in types {
         for _ in 0..1000 {
-            let m1 = Mnemonic::new(*mtype, lang);
+            let m1 = Mnemonic::new_with_rng(&mut rand::thread_rng(), *mtype, lang);
             let m2 = Mnemonic::from_phrase(m1.phrase(), lang).expect("Can create a Mnemonic");
 
             assert_eq!(m1.entropy(), m2.entropy());
@@ -21,6 +21,23 @@ fn validate_language(lang: Language) {
     }
 }
 
+/// Check that a phrase typed with precomposed (NFC) characters decodes like the NFKD wordlist form
+#[cfg(any(feature = "korean", feature = "spanish", feature = "french"))]
+fn validate_precomposed(lang: Language, entropy: &[u8]) {
+    use unicode_normalization::UnicodeNormalization;
+
+    let mnemonic = Mnemonic::from_entropy(entropy, lang).unwrap();
+    let typed: String = mnemonic.phrase().nfc().collect();
+
+    assert_ne!(typed, mnemonic.phrase());
+    assert!(Mnemonic::validate(&typed, lang).is_ok());
+
+    let typed_mnemonic = Mnemonic::from_phrase(&typed, lang).expect("Can create a Mnemonic");
+
+    assert_eq!(typed_mnemonic.entropy(), entropy);
+    assert_eq!(typed_mnemonic.phrase(), mnemonic.phrase());
+}
+
 macro_rules! test_maybe_wasm {
     ($name:ident, $(#[$attr:meta])+, $body:expr) => {
         #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
@@ -87,6 +104,178 @@ test_maybe_wasm!(validate_12_english_uppercase, {
     assert!(Mnemonic::from_phrase(invalid_phrase, Language::English).is_err());
 });
 
+test_maybe_wasm!(validate_12_english_lenient, {
+    let phrase = "\tPark Remain PERSON kitchen mule spell knee armed\nposition rail grid ankle\r\n";
+    let clean_phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    let mnemonic =
+        Mnemonic::from_phrase_lenient(phrase, Language::English).expect("Can create a Mnemonic");
+
+    assert_eq!(mnemonic.phrase(), clean_phrase);
+    assert!(Mnemonic::from_phrase(phrase, Language::English).is_err());
+});
+
+test_maybe_wasm!(validate_with_options, {
+    let clean_phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let messy_phrase =
+        " park remain  person kitchen mule spell knee armed position rail grid ankle";
+    let upper_phrase = "PARK REMAIN PERSON KITCHEN MULE SPELL KNEE ARMED POSITION RAIL GRID ANKLE";
+    let bad_checksum = "park remain person kitchen mule spell knee armed position rail grid park";
+
+    let strict = ValidationOptions::strict();
+    let default = ValidationOptions::default();
+    let permissive = ValidationOptions::permissive();
+
+    for options in &[strict, default, permissive] {
+        assert!(Mnemonic::validate_with(clean_phrase, Language::English, options).is_ok());
+    }
+
+    assert!(Mnemonic::validate_with(messy_phrase, Language::English, &strict).is_err());
+    assert!(Mnemonic::validate_with(messy_phrase, Language::English, &default).is_ok());
+
+    assert!(Mnemonic::validate_with(upper_phrase, Language::English, &default).is_err());
+    assert!(Mnemonic::validate_with(upper_phrase, Language::English, &permissive).is_ok());
+
+    assert!(Mnemonic::validate_with(bad_checksum, Language::English, &default).is_err());
+    assert!(Mnemonic::validate_with(bad_checksum, Language::English, &permissive).is_ok());
+    assert!(Mnemonic::validate_with(
+        bad_checksum,
+        Language::English,
+        &default.verify_checksum(false)
+    )
+    .is_ok());
+
+    // Permissive still requires known words and a standard length
+    assert!(Mnemonic::validate_with("park remain person", Language::English, &permissive).is_err());
+    assert!(Mnemonic::validate_with(
+        "parks remain person kitchen mule spell knee armed position rail grid ankle",
+        Language::English,
+        &permissive
+    )
+    .is_err());
+});
+
+test_maybe_wasm!(validate_abbreviated_words, {
+    let phrase = "park rema pers kitc mule spel knee arme posi rail grid ankl";
+    let clean_phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let options = ValidationOptions::default().expand_prefixes(true);
+
+    assert!(Mnemonic::validate(phrase, Language::English).is_err());
+    assert!(Mnemonic::validate_with(phrase, Language::English, &options).is_ok());
+
+    let mnemonic = Mnemonic::from_phrase_with(phrase, Language::English, &options)
+        .expect("Can create a Mnemonic");
+
+    assert_eq!(mnemonic.phrase(), clean_phrase);
+
+    // Shorter abbreviations are ambiguous, "rem" could be "remain", "remember", "remind"...
+    let too_short = "park rem pers kitc mule spel knee arme posi rail grid ankl";
+
+    assert!(Mnemonic::validate_with(too_short, Language::English, &options).is_err());
+});
+
+test_maybe_wasm!(validate_unaccented_words, #[cfg(feature = "spanish")], {
+    use unicode_normalization::char::canonical_combining_class;
+
+    let mnemonic = Mnemonic::from_entropy(&[0x42; 32], Language::Spanish).unwrap();
+    let typed: String = mnemonic
+        .phrase()
+        .chars()
+        .filter(|&c| canonical_combining_class(c) == 0)
+        .collect();
+    let options = ValidationOptions::default().accent_insensitive(true);
+
+    assert_ne!(typed, mnemonic.phrase());
+    assert!(Mnemonic::validate(&typed, Language::Spanish).is_err());
+    assert!(Mnemonic::validate_with(&typed, Language::Spanish, &options).is_ok());
+
+    let typed_mnemonic = Mnemonic::from_phrase_with(&typed, Language::Spanish, &options)
+        .expect("Can create a Mnemonic");
+
+    assert_eq!(typed_mnemonic.phrase(), mnemonic.phrase());
+    assert_eq!(Mnemonic::from_phrase_lenient(&typed.to_uppercase(), Language::Spanish).unwrap(), mnemonic);
+
+    // Unaccented abbreviations are expanded too
+    let options = options.expand_prefixes(true);
+    let words: Vec<String> = typed.split(' ').map(|w| w.chars().take(4).collect()).collect();
+
+    assert!(Mnemonic::validate_with(&words.join(" "), Language::Spanish, &options).is_ok());
+});
+
+test_maybe_wasm!(validate_typo_suggestions, {
+    let phrase = "park remain person kitchn mule spell knee armed position rail grid ankle";
+
+    let err = Mnemonic::from_phrase(phrase, Language::English).unwrap_err();
+
+    match err {
+        Error::InvalidWord {
+            ref word, index, ..
+        } => {
+            assert_eq!(word.as_str(), "kitchn");
+            assert_eq!(index, 3);
+            assert_eq!(word.suggestions(), ["kitchen", "kitten", "pitch"]);
+            assert_eq!(err.suggestions(), word.suggestions());
+            assert!(!format!("{:?}", err).contains("kitchn"));
+        }
+        other => panic!("Unexpected error {:?}", other),
+    }
+});
+
+test_maybe_wasm!(diagnose_valid_phrase, {
+    let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    let report = Mnemonic::diagnose(phrase, Language::English);
+
+    assert!(report.is_valid());
+    assert!(report.issues().is_empty());
+});
+
+test_maybe_wasm!(diagnose_all_issues, {
+    let phrase = "park remain person kitchn mule spell knee armed position rail grdi";
+
+    let report = Mnemonic::diagnose(phrase, Language::English);
+
+    let expected: [(&str, usize, &[&str]); 2] = [
+        ("kitchn", 3, &["kitchen", "kitten", "pitch"]),
+        ("grdi", 10, &["grab", "grain", "grid", "grit", "grow"]),
+    ];
+
+    assert_eq!(report.issues().len(), 3);
+    for (issue, (expected, at, suggestions)) in report.issues().iter().zip(expected) {
+        match issue {
+            ValidationIssue::InvalidWord { word, index } => {
+                assert_eq!((word.as_str(), *index), (expected, at));
+                assert_eq!(word.suggestions(), suggestions);
+            }
+            other => panic!("Unexpected issue {:?}", other),
+        }
+    }
+    assert_eq!(report.issues()[2], ValidationIssue::InvalidWordCount(11));
+});
+
+test_maybe_wasm!(diagnose_checksum, {
+    let phrase = "park remain person kitchen mule spell knee armed position rail grid abandon";
+
+    let report = Mnemonic::diagnose(phrase, Language::English);
+
+    assert_eq!(report.issues(), &[ValidationIssue::InvalidChecksum]);
+});
+
+test_maybe_wasm!(diagnose_foreign_word, #[cfg(feature = "spanish")], {
+    let phrase = "park remain person kitchen mule spell knee armed position rail grid ábaco";
+
+    let report = Mnemonic::diagnose(phrase, Language::English);
+
+    match report.issues() {
+        [ValidationIssue::ForeignWord { word, index, language }] => {
+            assert_eq!(word.as_str(), "a\u{301}baco");
+            assert_eq!(*index, 11);
+            assert_eq!(*language, Language::Spanish);
+        }
+        other => panic!("Unexpected issues {:?}", other),
+    }
+});
+
 test_maybe_wasm!(validate_english, {
     validate_language(Language::English);
 });
@@ -99,6 +288,10 @@ test_maybe_wasm!(validate_chinese_traditional, #[cfg(feature = "chinese-traditio
     validate_language(Language::ChineseTraditional);
 });
 
+test_maybe_wasm!(validate_czech, #[cfg(feature = "czech")], {
+    validate_language(Language::Czech);
+});
+
 test_maybe_wasm!(validate_french, #[cfg(feature = "french")], {
     validate_language(Language::French);
 });
@@ -115,6 +308,84 @@ test_maybe_wasm!(validate_korean, #[cfg(feature = "korean")], {
     validate_language(Language::Korean);
 });
 
+test_maybe_wasm!(validate_portuguese, #[cfg(feature = "portuguese")], {
+    validate_language(Language::Portuguese);
+});
+
 test_maybe_wasm!(validate_spanish, #[cfg(feature = "spanish")], {
     validate_language(Language::Spanish);
 });
+
+test_maybe_wasm!(chinese_simplified_is_not_traditional, #[cfg(all(feature = "chinese-simplified", feature = "chinese-traditional"))], {
+    // The first word is "这" (index 9), which only exists in the simplified list
+    let mut entropy = [0u8; 16];
+    entropy[0] = 0x01;
+    entropy[1] = 0x20;
+
+    let simplified = Mnemonic::from_entropy(&entropy, Language::ChineseSimplified).unwrap();
+    let traditional = Mnemonic::from_entropy(&entropy, Language::ChineseTraditional).unwrap();
+
+    assert_ne!(simplified.phrase(), traditional.phrase());
+    assert!(Mnemonic::validate(simplified.phrase(), Language::ChineseTraditional).is_err());
+    assert!(Mnemonic::validate(traditional.phrase(), Language::ChineseSimplified).is_err());
+});
+
+test_maybe_wasm!(chinese_shared_characters_decode_identically, #[cfg(all(feature = "chinese-simplified", feature = "chinese-traditional"))], {
+    // Characters shared by both lists sit at the same index, so a phrase made only of those
+    // is valid in both languages, but always decodes to the same entropy.
+    let entropy = [0u8; 16];
+
+    let simplified = Mnemonic::from_entropy(&entropy, Language::ChineseSimplified).unwrap();
+    let traditional = Mnemonic::from_phrase(simplified.phrase(), Language::ChineseTraditional)
+        .expect("Can create a Mnemonic");
+
+    assert_eq!(simplified.entropy(), traditional.entropy());
+});
+
+test_maybe_wasm!(validate_korean_precomposed, #[cfg(feature = "korean")], {
+    // The wordlist is stored decomposed into jamo, but users type precomposed syllables
+    validate_precomposed(Language::Korean, &[0x5A; 16]);
+});
+
+test_maybe_wasm!(validate_spanish_precomposed, #[cfg(feature = "spanish")], {
+    // "lástima" is the first word
+    validate_precomposed(Language::Spanish, &[
+        0x7B, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ]);
+});
+
+test_maybe_wasm!(validate_french_precomposed, #[cfg(feature = "french")], {
+    // "académie" is the first word
+    validate_precomposed(Language::French, &[
+        0x02, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ]);
+});
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn validate_from_threads() {
+    use bip39::Seed;
+
+    let mnemonics: Vec<Mnemonic> = Language::all()
+        .map(|lang| Mnemonic::from_entropy(&[0x5A; 16], lang).unwrap())
+        .collect();
+    let seed = Seed::new(&mnemonics[0], "");
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for mnemonic in &mnemonics {
+                    let lang = mnemonic.language();
+
+                    assert!(Mnemonic::validate(mnemonic.phrase(), lang).is_ok());
+                    let parsed = Mnemonic::from_phrase(mnemonic.phrase(), lang).unwrap();
+                    assert_eq!(parsed.entropy(), mnemonic.entropy());
+                }
+
+                assert_eq!(Seed::new(&mnemonics[0], ""), seed);
+            });
+        }
+    });
+}